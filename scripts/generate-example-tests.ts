@@ -94,7 +94,10 @@ import init, {
     TextAlign, Dimension, AvailableSpace, Size, GridPlacement, Rect, 
     LengthPercentage, LengthPercentageAuto,
     DetailedLayoutInfo, DetailedGridInfo, DetailedGridTracksInfo, DetailedGridItemsInfo,
-    TrackSizingFunction, Point, TaffyError, Layout, MeasureFunction
+    TrackSizingFunction, Point, TaffyError, Layout, MeasureFunction,
+    enumName, getEnums, TreeSpec, px, percent, auto, interpolateLayout,
+    setViewport, clearViewport,
+    BoxModel
 } from 'taffy-js';
 
 // Global init for the suite
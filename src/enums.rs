@@ -16,7 +16,15 @@
 //! - `From<JsEnum> for taffy::style::Enum` - Convert from JS to Taffy
 //! - `From<taffy::style::Enum> for JsEnum` - Convert from Taffy to JS
 //! - `TryFrom<u32> for JsEnum` - Convert from raw number (for setter handling)
+//!
+//! ## Enum Names
+//!
+//! `enumName(kind, value)` maps a numeric enum value back to its variant name
+//! (e.g. `enumName("JustifyContent", 6)` is `"SpaceBetween"`) for debugging and tooling.
+//! `getEnums()` returns every name-to-value map at once, for tooling that builds
+//! pickers without hardcoding numeric values.
 
+use crate::types::JsEnumMaps;
 use wasm_bindgen::prelude::*;
 
 // =============================================================================
@@ -28,6 +36,10 @@ use wasm_bindgen::prelude::*;
 /// Controls the layout algorithm type for an element. This corresponds to the CSS `display` property
 /// and determines how an element and its children are laid out.
 ///
+/// There is no `Contents` variant: the Taffy version this binding is built on
+/// (0.9) has no `display: contents`. To get its effect, attach the children
+/// of such an element directly to its parent.
+///
 /// @example
 /// ```typescript
 /// import { Style, Display } from 'taffy-js';
@@ -318,17 +330,20 @@ pub enum JsAlignSelf {
     Stretch = 7,
 }
 
-impl From<JsAlignSelf> for taffy::style::AlignSelf {
+/// Taffy has no `auto` variant: an unset (`None`) `align_self` or
+/// `justify_self` defers to the parent's `align_items` or `justify_items`,
+/// so `Auto` maps to `None`.
+impl From<JsAlignSelf> for Option<taffy::style::AlignSelf> {
     fn from(val: JsAlignSelf) -> Self {
         match val {
-            JsAlignSelf::Auto => taffy::style::AlignSelf::Stretch,
-            JsAlignSelf::Start => taffy::style::AlignSelf::Start,
-            JsAlignSelf::End => taffy::style::AlignSelf::End,
-            JsAlignSelf::FlexStart => taffy::style::AlignSelf::FlexStart,
-            JsAlignSelf::FlexEnd => taffy::style::AlignSelf::FlexEnd,
-            JsAlignSelf::Center => taffy::style::AlignSelf::Center,
-            JsAlignSelf::Baseline => taffy::style::AlignSelf::Baseline,
-            JsAlignSelf::Stretch => taffy::style::AlignSelf::Stretch,
+            JsAlignSelf::Auto => None,
+            JsAlignSelf::Start => Some(taffy::style::AlignSelf::Start),
+            JsAlignSelf::End => Some(taffy::style::AlignSelf::End),
+            JsAlignSelf::FlexStart => Some(taffy::style::AlignSelf::FlexStart),
+            JsAlignSelf::FlexEnd => Some(taffy::style::AlignSelf::FlexEnd),
+            JsAlignSelf::Center => Some(taffy::style::AlignSelf::Center),
+            JsAlignSelf::Baseline => Some(taffy::style::AlignSelf::Baseline),
+            JsAlignSelf::Stretch => Some(taffy::style::AlignSelf::Stretch),
         }
     }
 }
@@ -500,7 +515,10 @@ impl From<taffy::style::JustifyContent> for JsJustifyContent {
 /// Overflow handling enumeration
 ///
 /// Defines how content that exceeds the container boundaries is handled.
-/// This corresponds to the CSS `overflow` property.
+/// This corresponds to the CSS `overflow` property. Every variant reads back
+/// from `style.overflow` exactly as it was set. CSS `overflow: auto` has no
+/// variant of its own: use `Overflow.Scroll` for it, which is also what it
+/// reads back as.
 ///
 /// @example
 /// ```typescript
@@ -691,3 +709,120 @@ impl From<taffy::style::GridAutoFlow> for JsGridAutoFlow {
         }
     }
 }
+
+// =============================================================================
+// Enum Names
+// =============================================================================
+
+/// Generates the name lookup tables for the exported enums
+///
+/// Each entry lists the JavaScript enum name, the Rust enum and its variants.
+/// The generated `name()` methods match exhaustively, so adding a variant to
+/// an enum without listing it here is a compile error.
+macro_rules! enum_names {
+    ($($kind:literal => $enum:ident { $($variant:ident),+ $(,)? }),+ $(,)?) => {
+        $(
+            impl $enum {
+                /// All variants, in discriminant order
+                pub(crate) const VARIANTS: &'static [$enum] = &[$($enum::$variant),+];
+
+                /// Gets the variant name as exposed to JavaScript
+                pub(crate) fn name(self) -> &'static str {
+                    match self {
+                        $($enum::$variant => stringify!($variant)),+
+                    }
+                }
+            }
+        )+
+
+        /// The JavaScript names of all enums with name lookup tables
+        pub(crate) const ENUM_KINDS: &[&str] = &[$($kind),+];
+
+        /// Gets the `(name, value)` pairs of the enum called `kind` in JavaScript
+        pub(crate) fn enum_entries(kind: &str) -> Option<Vec<(&'static str, u32)>> {
+            match kind {
+                $($kind => Some(
+                    $enum::VARIANTS.iter().map(|v| (v.name(), *v as u32)).collect(),
+                ),)+
+                _ => None,
+            }
+        }
+    };
+}
+
+enum_names! {
+    "Display" => JsDisplay { Block, Flex, Grid, None },
+    "Position" => JsPosition { Relative, Absolute },
+    "FlexDirection" => JsFlexDirection { Row, Column, RowReverse, ColumnReverse },
+    "FlexWrap" => JsFlexWrap { NoWrap, Wrap, WrapReverse },
+    "AlignItems" => JsAlignItems { Start, End, FlexStart, FlexEnd, Center, Baseline, Stretch },
+    "AlignSelf" => JsAlignSelf { Auto, Start, End, FlexStart, FlexEnd, Center, Baseline, Stretch },
+    "AlignContent" => JsAlignContent {
+        Start, End, FlexStart, FlexEnd, Center, Stretch, SpaceBetween, SpaceAround, SpaceEvenly,
+    },
+    "JustifyContent" => JsJustifyContent {
+        Start, End, FlexStart, FlexEnd, Center, Stretch, SpaceBetween, SpaceAround, SpaceEvenly,
+    },
+    "Overflow" => JsOverflow { Visible, Clip, Hidden, Scroll },
+    "BoxSizing" => JsBoxSizing { BorderBox, ContentBox },
+    "TextAlign" => JsTextAlign { Auto, LegacyLeft, LegacyRight, LegacyCenter },
+    "GridAutoFlow" => JsGridAutoFlow { Row, Column, RowDense, ColumnDense },
+}
+
+/// Gets the name of an enum value, for display in inspectors and serialized style viewers
+///
+/// @param kind - The enum name as exported to JavaScript (e.g. `"JustifyContent"`)
+/// @param value - The numeric enum value
+///
+/// @returns - The variant name (e.g. `"SpaceBetween"`), or `undefined` if the
+/// enum or value is unknown
+///
+/// @example
+/// ```typescript
+/// import { enumName, JustifyContent } from 'taffy-js';
+///
+/// console.log(enumName("JustifyContent", JustifyContent.SpaceBetween)); // "SpaceBetween"
+/// console.log(enumName("Display", 42)); // undefined
+/// ```
+#[wasm_bindgen(js_name = enumName)]
+pub fn enum_name(kind: &str, value: u32) -> Option<String> {
+    enum_entries(kind)?
+        .into_iter()
+        .find(|&(_, v)| v == value)
+        .map(|(name, _)| name.to_string())
+}
+
+/// Gets the name-to-value maps of all exported enums
+///
+/// Returns one object keyed by enum name (`"Display"`, `"FlexDirection"`, ...),
+/// each mapping variant names to their numeric values. The maps are generated
+/// from the same tables as `enumName()`, so they always match the exported
+/// enums. The returned objects are frozen.
+///
+/// @returns - An object of `{ [variant]: value }` maps keyed by enum name
+///
+/// @example
+/// ```typescript
+/// import { getEnums } from 'taffy-js';
+///
+/// const enums = getEnums();
+/// console.log(enums.Display.Grid); // 2
+///
+/// // Build dropdown options for every JustifyContent value
+/// const options = Object.entries(enums.JustifyContent).map(([label, value]) => ({
+///   label,
+///   value,
+/// }));
+/// ```
+#[wasm_bindgen(js_name = getEnums)]
+pub fn get_enums() -> JsEnumMaps {
+    let enums = js_sys::Object::new();
+    for &kind in ENUM_KINDS {
+        let entries = js_sys::Object::new();
+        for (name, value) in enum_entries(kind).unwrap_or_default() {
+            let _ = js_sys::Reflect::set(&entries, &name.into(), &value.into());
+        }
+        let _ = js_sys::Reflect::set(&enums, &kind.into(), &js_sys::Object::freeze(&entries));
+    }
+    JsValue::from(js_sys::Object::freeze(&enums)).unchecked_into()
+}
@@ -30,6 +30,7 @@
 //! }
 //! ```
 
+use std::fmt;
 use taffy::TaffyError;
 use taffy::prelude::NodeId;
 use wasm_bindgen::prelude::*;
@@ -61,10 +62,63 @@ use wasm_bindgen::prelude::*;
 /// - `InvalidInputNode`: Node ID doesn't exist in the tree
 /// - `InvalidParentNode`: Specified parent node doesn't exist
 /// - `ChildIndexOutOfBounds`: Child index exceeds available children
+///
+/// In addition, the bindings raise their own errors, e.g. when a mutating
+/// method is called on a frozen tree.
 #[wasm_bindgen(js_name = TaffyError)]
 pub struct JsTaffyError {
-    /// The wrapped native Taffy error
-    inner: TaffyError,
+    /// The wrapped error
+    inner: ErrorKind,
+}
+
+/// The kinds of error that can be thrown as a `TaffyError`
+#[derive(Debug)]
+pub(crate) enum ErrorKind {
+    /// An error reported by the native Taffy tree
+    Taffy(TaffyError),
+    /// A mutating method was called while the tree is frozen
+    Frozen,
+    /// The tree was to be frozen while a batch is open
+    BatchOpen,
+    /// A node was expected to be an ancestor of another node but is not
+    NotAnAncestor { node: NodeId, ancestor: NodeId },
+    /// A property name passed to `setStyleProperty()` is not a `Style` property
+    UnknownStyleProperty(String),
+    /// A `buildTree()` spec is malformed; `path` locates the offending node
+    InvalidTreeSpec { path: String, reason: String },
+    /// `aspectSizedFor()` could not derive a missing dimension for a node
+    UnresolvableSize(NodeId),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Taffy(e) => write!(f, "{e}"),
+            ErrorKind::Frozen => write!(
+                f,
+                "The tree is frozen and cannot be modified; call unfreeze() first"
+            ),
+            ErrorKind::BatchOpen => write!(
+                f,
+                "A batch is open and the tree cannot be frozen; call endBatch() first"
+            ),
+            ErrorKind::NotAnAncestor { node, ancestor } => write!(
+                f,
+                "Node {} is not an ancestor of node {}",
+                u64::from(*ancestor),
+                u64::from(*node)
+            ),
+            ErrorKind::UnknownStyleProperty(key) => write!(f, "Unknown style property: {key}"),
+            ErrorKind::InvalidTreeSpec { path, reason } => {
+                write!(f, "Invalid tree spec at {path}: {reason}")
+            }
+            ErrorKind::UnresolvableSize(node) => write!(
+                f,
+                "Node {} has no aspect ratio or content size to derive its size from",
+                u64::from(*node)
+            ),
+        }
+    }
 }
 
 #[wasm_bindgen(js_class = "TaffyError")]
@@ -85,6 +139,14 @@ impl JsTaffyError {
 
 impl From<TaffyError> for JsTaffyError {
     fn from(inner: TaffyError) -> Self {
+        JsTaffyError {
+            inner: ErrorKind::Taffy(inner),
+        }
+    }
+}
+
+impl From<ErrorKind> for JsTaffyError {
+    fn from(inner: ErrorKind) -> Self {
         JsTaffyError { inner }
     }
 }
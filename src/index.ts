@@ -22,8 +22,82 @@ export * from "../pkg/taffy_wasm.js";
 export type { InitOutput } from "../pkg/taffy_wasm.js";
 
 // Import the init functions for the loader
-import init, { initSync } from "../pkg/taffy_wasm.js";
-import type { InitOutput } from "../pkg/taffy_wasm.js";
+import init, { initSync, TaffyTree } from "../pkg/taffy_wasm.js";
+import type { InitOutput, Style } from "../pkg/taffy_wasm.js";
+
+declare module "../pkg/taffy_wasm.js" {
+  interface TaffyTree {
+    /**
+     * Runs `callback` inside a batch of style changes
+     *
+     * Wraps `callback` in `beginBatch()` / `endBatch()`, so the style changes
+     * it makes are applied together when it returns, or when it throws. See
+     * `beginBatch()` for what is deferred. Calls may nest; the changes are
+     * applied when the outermost batch ends.
+     *
+     * This lives on the JavaScript side because the tree cannot be called
+     * back into while one of its WASM methods is running.
+     *
+     * @param callback - Function making the changes
+     *
+     * @returns - The value returned by `callback`
+     *
+     * @throws `TaffyError` if the tree is frozen, or whatever `callback` throws
+     *
+     * @example
+     * ```typescript
+     * tree.batchUpdate(() => {
+     *   for (const node of tree.children(root)) {
+     *     tree.setStyleProperty(node, "flexGrow", 1);
+     *   }
+     * });
+     * ```
+     */
+    batchUpdate<T>(callback: () => T): T;
+
+    /**
+     * Creates a new leaf node with the default style template
+     *
+     * Same as `newLeafDefault()`. The style argument is optional on the
+     * JavaScript side because the bindings can only borrow a `Style` that is
+     * always passed.
+     *
+     * @returns - The node ID (`bigint`)
+     *
+     * @throws `TaffyError` if the tree is frozen
+     *
+     * @example
+     * ```typescript
+     * tree.setDefaultStyle(template);
+     * const node = tree.newLeaf();
+     * ```
+     */
+    newLeaf(): bigint;
+  }
+}
+
+TaffyTree.prototype.batchUpdate = function <T>(
+  this: TaffyTree,
+  callback: () => T,
+): T {
+  this.beginBatch();
+  try {
+    return callback();
+  } finally {
+    this.endBatch();
+  }
+};
+
+const newLeafWithStyle = TaffyTree.prototype.newLeaf;
+
+TaffyTree.prototype.newLeaf = function (
+  this: TaffyTree,
+  style?: Style,
+): bigint {
+  return style === undefined
+    ? this.newLeafDefault()
+    : newLeafWithStyle.call(this, style);
+};
 
 /**
  * Detects if running in a Node.js environment
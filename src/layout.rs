@@ -32,6 +32,11 @@
 //! - Positive `x` is to the right
 //! - Positive `y` is downward
 //! - For the root node, `x` and `y` are always 0
+//!
+//! ## Interpolation
+//!
+//! `interpolateLayout(a, b, t)` blends two layouts, e.g. a `pinLayout()`
+//! snapshot and the freshly computed layout, for animated transitions.
 
 use taffy;
 use wasm_bindgen::prelude::*;
@@ -306,9 +311,7 @@ impl JsLayout {
 
 impl From<&taffy::Layout> for JsLayout {
     fn from(layout: &taffy::Layout) -> Self {
-        JsLayout {
-            inner: layout.clone(),
-        }
+        JsLayout { inner: *layout }
     }
 }
 
@@ -317,3 +320,72 @@ impl From<taffy::Layout> for JsLayout {
         JsLayout { inner: layout }
     }
 }
+
+// =============================================================================
+// Interpolation
+// =============================================================================
+
+/// Linearly interpolates between two layouts
+///
+/// Blends position, size, content size, scrollbar size and the margin,
+/// border and padding widths, which is what FLIP-style layout transitions
+/// need each frame. `t` is clamped to `[0, 1]`: `0` returns `a` and `1`
+/// returns `b`. The integer `order` cannot be blended and switches from
+/// `a` to `b` at `t = 0.5`.
+///
+/// @param a - The layout at `t = 0`, e.g. a snapshot from `pinLayout()`
+/// @param b - The layout at `t = 1`
+/// @param t - The progress between the two layouts
+///
+/// @returns - A new `Layout`
+///
+/// @example
+/// ```typescript
+/// const tree = new TaffyTree();
+/// const style = new Style();
+/// style.size = { width: 100, height: 100 };
+/// const node = tree.newLeaf(style);
+/// const rootStyle = new Style();
+/// rootStyle.justifyContent = JustifyContent.Center;
+/// const root = tree.newWithChildren(rootStyle, BigUint64Array.from([node]));
+/// tree.computeLayout(root, { width: 800, height: 600 });
+///
+/// tree.pinLayout(node);
+/// tree.computeLayout(root, { width: 400, height: 600 });
+/// const from = tree.pinnedLayout(node)!;
+/// const to = tree.getLayout(node);
+///
+/// const frame = interpolateLayout(from, to, 0.25);
+/// const transform = `translate(${frame.x}px, ${frame.y}px)`; // "translate(300px, 0px)"
+/// ```
+#[wasm_bindgen(js_name = interpolateLayout)]
+pub fn interpolate_layout(a: &JsLayout, b: &JsLayout, t: f32) -> JsLayout {
+    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    let lerp_size = |a: taffy::Size<f32>, b: taffy::Size<f32>| taffy::Size {
+        width: lerp(a.width, b.width),
+        height: lerp(a.height, b.height),
+    };
+    let lerp_rect = |a: taffy::Rect<f32>, b: taffy::Rect<f32>| taffy::Rect {
+        left: lerp(a.left, b.left),
+        right: lerp(a.right, b.right),
+        top: lerp(a.top, b.top),
+        bottom: lerp(a.bottom, b.bottom),
+    };
+    let (a, b) = (&a.inner, &b.inner);
+    JsLayout {
+        inner: taffy::Layout {
+            order: if t < 0.5 { a.order } else { b.order },
+            location: taffy::Point {
+                x: lerp(a.location.x, b.location.x),
+                y: lerp(a.location.y, b.location.y),
+            },
+            size: lerp_size(a.size, b.size),
+            content_size: lerp_size(a.content_size, b.content_size),
+            scrollbar_size: lerp_size(a.scrollbar_size, b.scrollbar_size),
+            border: lerp_rect(a.border, b.border),
+            padding: lerp_rect(a.padding, b.padding),
+            margin: lerp_rect(a.margin, b.margin),
+        },
+    }
+}
@@ -101,9 +101,12 @@ use wasm_bindgen::prelude::*;
 /// - All spacing: `0`
 ///
 #[wasm_bindgen(js_name = Style)]
+#[derive(Clone)]
 pub struct JsStyle {
     /// Internal Taffy style object (crate-internal access for tree operations)
     pub(crate) inner: TaffyStyle::Style,
+    /// Bitset of explicitly set properties, indexed by `StyleProperty`
+    presence: u64,
 }
 
 #[wasm_bindgen(js_class = "Style")]
@@ -125,9 +128,32 @@ impl JsStyle {
     pub fn new() -> JsStyle {
         JsStyle {
             inner: TaffyStyle::Style::default(),
+            presence: 0,
         }
     }
 
+    /// Creates an independent copy of this style
+    ///
+    /// The copy has the same property values and the same set properties (see
+    /// `setProperties()`). Changing either style afterwards leaves the other
+    /// untouched, which makes this a cheap way to derive variants of a shared
+    /// base style.
+    ///
+    /// @returns - A new `Style` with the same values
+    ///
+    /// @example
+    /// ```typescript
+    /// const base = new Style();
+    /// base.display = Display.Flex;
+    /// const grow = base.clone();
+    /// grow.flexGrow = 2;
+    /// console.log(base.flexGrow); // 0
+    /// ```
+    #[wasm_bindgen(js_name = clone)]
+    pub fn clone_style(&self) -> JsStyle {
+        self.clone()
+    }
+
     // =========================================================================
     // Layout Mode Properties
     // =========================================================================
@@ -157,6 +183,7 @@ impl JsStyle {
     #[wasm_bindgen(setter)]
     pub fn set_display(&mut self, val: JsDisplay) {
         self.inner.display = val.into();
+        self.mark(StyleProperty::Display, true);
     }
 
     /// Gets the position mode
@@ -185,6 +212,7 @@ impl JsStyle {
     #[wasm_bindgen(setter)]
     pub fn set_position(&mut self, val: JsPosition) {
         self.inner.position = val.into();
+        self.mark(StyleProperty::Position, true);
     }
 
     // =========================================================================
@@ -216,6 +244,7 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = flexDirection)]
     pub fn set_flex_direction(&mut self, val: JsFlexDirection) {
         self.inner.flex_direction = val.into();
+        self.mark(StyleProperty::FlexDirection, true);
     }
 
     /// Gets the flex wrap mode
@@ -243,6 +272,7 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = flexWrap)]
     pub fn set_flex_wrap(&mut self, val: JsFlexWrap) {
         self.inner.flex_wrap = val.into();
+        self.mark(StyleProperty::FlexWrap, true);
     }
 
     /// Gets the flex grow factor
@@ -268,6 +298,7 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = flexGrow)]
     pub fn set_flex_grow(&mut self, val: f32) {
         self.inner.flex_grow = val;
+        self.mark(StyleProperty::FlexGrow, true);
     }
 
     /// Gets the flex shrink factor
@@ -293,6 +324,7 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = flexShrink)]
     pub fn set_flex_shrink(&mut self, val: f32) {
         self.inner.flex_shrink = val;
+        self.mark(StyleProperty::FlexShrink, true);
     }
 
     // =========================================================================
@@ -323,11 +355,11 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         self.inner.align_items = if val.is_undefined() {
             None
-        } else if let Some(n) = val.as_f64() {
-            Some(unsafe { std::mem::transmute::<u8, JsAlignItems>(n as u8) }.into())
         } else {
-            None
+            val.as_f64()
+                .map(|n| unsafe { std::mem::transmute::<u8, JsAlignItems>(n as u8) }.into())
         };
+        self.mark(StyleProperty::AlignItems, self.inner.align_items.is_some());
     }
 
     /// Gets the align-self property
@@ -357,15 +389,11 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         self.inner.align_self = if val.is_undefined() {
             None
-        } else if let Some(n) = val.as_f64() {
-            let js_val = unsafe { std::mem::transmute::<u8, JsAlignSelf>(n as u8) };
-            match js_val {
-                JsAlignSelf::Auto => None,
-                _ => Some(js_val.into()),
-            }
         } else {
-            None
+            val.as_f64()
+                .and_then(|n| unsafe { std::mem::transmute::<u8, JsAlignSelf>(n as u8) }.into())
         };
+        self.mark(StyleProperty::AlignSelf, self.inner.align_self.is_some());
     }
 
     /// Gets the align-content property
@@ -392,11 +420,14 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         self.inner.align_content = if val.is_undefined() {
             None
-        } else if let Some(n) = val.as_f64() {
-            Some(unsafe { std::mem::transmute::<u8, JsAlignContent>(n as u8) }.into())
         } else {
-            None
+            val.as_f64()
+                .map(|n| unsafe { std::mem::transmute::<u8, JsAlignContent>(n as u8) }.into())
         };
+        self.mark(
+            StyleProperty::AlignContent,
+            self.inner.align_content.is_some(),
+        );
     }
 
     /// Gets the justify-content property
@@ -423,11 +454,14 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         self.inner.justify_content = if val.is_undefined() {
             None
-        } else if let Some(n) = val.as_f64() {
-            Some(unsafe { std::mem::transmute::<u8, JsJustifyContent>(n as u8) }.into())
         } else {
-            None
+            val.as_f64()
+                .map(|n| unsafe { std::mem::transmute::<u8, JsJustifyContent>(n as u8) }.into())
         };
+        self.mark(
+            StyleProperty::JustifyContent,
+            self.inner.justify_content.is_some(),
+        );
     }
 
     // =========================================================================
@@ -446,6 +480,10 @@ impl JsStyle {
 
     /// Sets the aspect ratio
     ///
+    /// Both `undefined` and `null` clear the ratio (see also
+    /// `clearAspectRatio()`). Values that are not positive finite numbers are
+    /// ignored.
+    ///
     /// @param val - The new aspect ratio (width/height), or `undefined` to clear
     ///
     /// @example
@@ -456,11 +494,15 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = aspectRatio)]
     pub fn set_aspect_ratio(&mut self, val: JsOptionNumber) {
         let val: JsValue = val.unchecked_into();
-        self.inner.aspect_ratio = if val.is_undefined() || val.is_null() {
-            None
-        } else {
-            val.as_f64().map(|v| v as f32)
-        };
+        if val.is_undefined() || val.is_null() {
+            self.inner.aspect_ratio = None;
+        } else if let Some(ratio) = val.as_f64().filter(|v| v.is_finite() && *v > 0.0) {
+            self.inner.aspect_ratio = Some(ratio as f32);
+        }
+        self.mark(
+            StyleProperty::AspectRatio,
+            self.inner.aspect_ratio.is_some(),
+        );
     }
 
     /// Gets the overflow behavior
@@ -471,8 +513,8 @@ impl JsStyle {
     #[wasm_bindgen(getter)]
     pub fn overflow(&self) -> JsPointOverflow {
         let s = PointOverflowDto {
-            x: self.inner.overflow.x as u8,
-            y: self.inner.overflow.y as u8,
+            x: JsOverflow::from(self.inner.overflow.x) as u8,
+            y: JsOverflow::from(self.inner.overflow.y) as u8,
         };
         serialize(&s).unchecked_into()
     }
@@ -491,6 +533,7 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(s) = serde_wasm_bindgen::from_value::<PointOverflowDto>(val) {
             self.inner.overflow = s.into();
+            self.mark(StyleProperty::Overflow, true);
         }
     }
 
@@ -518,11 +561,14 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = boxSizing)]
     pub fn set_box_sizing(&mut self, val: JsBoxSizing) {
         self.inner.box_sizing = val.into();
+        self.mark(StyleProperty::BoxSizing, true);
     }
 
     /// Gets the flex-basis
     ///
     /// The initial size of a flex item before growing/shrinking.
+    /// `"auto"` (use the item's size) and `0` (ignore it) are reported distinctly,
+    /// as they distribute free space very differently.
     ///
     /// @returns - A `Dimension` value (`number`, `\"{number}%\"`, or `\"auto\"`)
     #[wasm_bindgen(getter, js_name = flexBasis)]
@@ -545,6 +591,7 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(d) = serde_wasm_bindgen::from_value::<DimensionDto>(val) {
             self.inner.flex_basis = d.into();
+            self.mark(StyleProperty::FlexBasis, true);
         }
     }
 
@@ -579,6 +626,7 @@ impl JsStyle {
         match serde_wasm_bindgen::from_value::<SizeDto<DimensionDto>>(val.clone()) {
             Ok(s) => {
                 self.inner.size = s.into();
+                self.mark(StyleProperty::Size, true);
             }
             Err(e) => {
                 let json = js_sys::JSON::stringify(&val)
@@ -590,6 +638,27 @@ impl JsStyle {
         }
     }
 
+    /// Gets the raw internal representation of the width
+    ///
+    /// Returns the tag and value of the underlying `CompactLength` without any
+    /// conversion. The `size` getter reports values it cannot represent as
+    /// `"auto"`; this low-level escape hatch lets tooling distinguish them.
+    ///
+    /// @returns - A `RawCompactLength` with `tag` and `value`; see its docs
+    /// for the tag constants
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.size = { width: "50%", height: "auto" };
+    /// console.log(style.rawWidth()); // { tag: 2, value: 0.5 }
+    /// ```
+    #[wasm_bindgen(js_name = rawWidth)]
+    pub fn raw_width(&self) -> JsRawCompactLength {
+        let raw = RawCompactLengthDto::from(self.inner.size.width.into_raw());
+        serialize(&raw).unchecked_into()
+    }
+
     /// Gets the minimum size constraints
     ///
     /// @returns - A `Size<Dimension>` object with minimum width and height
@@ -616,6 +685,7 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(s) = serde_wasm_bindgen::from_value::<SizeDto<DimensionDto>>(val) {
             self.inner.min_size = s.into();
+            self.mark(StyleProperty::MinSize, true);
         }
     }
 
@@ -645,6 +715,7 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(s) = serde_wasm_bindgen::from_value::<SizeDto<DimensionDto>>(val) {
             self.inner.max_size = s.into();
+            self.mark(StyleProperty::MaxSize, true);
         }
     }
 
@@ -682,6 +753,7 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(m) = serde_wasm_bindgen::from_value::<RectDto<LengthPercentageAutoDto>>(val) {
             self.inner.margin = m.into();
+            self.mark(StyleProperty::Margin, true);
         }
     }
 
@@ -715,6 +787,7 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(p) = serde_wasm_bindgen::from_value::<RectDto<LengthPercentageDto>>(val) {
             self.inner.padding = p.into();
+            self.mark(StyleProperty::Padding, true);
         }
     }
 
@@ -736,6 +809,9 @@ impl JsStyle {
 
     /// Sets the border width
     ///
+    /// Percentage borders (e.g. `"10%"`) resolve against the width of the
+    /// containing block on all four sides, like percentage padding.
+    ///
     /// @param val - A Rect object with LengthPercentage values
     ///
     /// @example
@@ -748,6 +824,7 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(b) = serde_wasm_bindgen::from_value::<RectDto<LengthPercentageDto>>(val) {
             self.inner.border = b.into();
+            self.mark(StyleProperty::Border, true);
         }
     }
 
@@ -755,6 +832,13 @@ impl JsStyle {
     ///
     /// Spacing between flex/grid items.
     ///
+    /// The mapping is the same for flexbox and grid: `width` is the column gap
+    /// (CSS `column-gap`, space between columns) and `height` is the row gap
+    /// (CSS `row-gap`, space between rows). In a flex row container the column
+    /// gap separates items and the row gap separates wrapped lines; in a flex
+    /// column container the roles swap. Percentages resolve against the
+    /// container's inner width for `width` and inner height for `height`.
+    ///
     /// @returns - A `Size<LengthPercentage>` with column (width) and row (height) gaps
     #[wasm_bindgen(getter)]
     pub fn gap(&self) -> JsSizeLengthPercentage {
@@ -767,19 +851,76 @@ impl JsStyle {
 
     /// Sets the gap
     ///
-    /// @param val - A Size object with LengthPercentage gap values
+    /// @param val - A Size object with the column gap as `width` and the row gap as `height`
     ///
     /// @example
     /// ```typescript
     /// const style = new Style();
     /// style.gap = { width: 10, height: 10 };
+    /// // 16px between columns, 5% of the container height between rows
+    /// style.gap = { width: 16, height: "5%" };
     /// ```
     #[wasm_bindgen(setter)]
     pub fn set_gap(&mut self, val: JsSizeLengthPercentage) {
         let val: JsValue = val.unchecked_into();
         if let Ok(g) = serde_wasm_bindgen::from_value::<SizeDto<LengthPercentageDto>>(val) {
             self.inner.gap = g.into();
+            self.mark(StyleProperty::Gap, true);
+        }
+    }
+
+    /// Sets the gap in CSS shorthand order: row gap first, then column gap
+    ///
+    /// `gap` is a `Size` whose `width` is the column gap and `height` the row
+    /// gap, which is easy to get backwards when thinking in CSS terms. This
+    /// follows `gap: <row-gap> <column-gap>` instead: `setGridGap(10, 20)`
+    /// is equivalent to `style.gap = { width: 20, height: 10 }`. When the
+    /// column gap is omitted, both gaps are set to the row gap, as in CSS.
+    /// Read the gaps back with `gap.height` (row) and `gap.width` (column).
+    ///
+    /// If either value is invalid, an error is logged and the gap is left unchanged.
+    ///
+    /// @param rowGap - The space between rows
+    /// @param columnGap - The optional space between columns
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.display = Display.Grid;
+    /// style.setGridGap(8, "2%"); // CSS: gap: 8px 2%
+    /// console.log(style.gap); // { width: "2%", height: 8 }
+    /// ```
+    #[wasm_bindgen(js_name = setGridGap)]
+    pub fn set_grid_gap(
+        &mut self,
+        #[wasm_bindgen(js_name = "rowGap")] row_gap: JsLengthPercentage,
+        #[wasm_bindgen(js_name = "columnGap")] column_gap: Option<JsLengthPercentage>,
+    ) {
+        let parse = |val: JsLengthPercentage| {
+            let val: JsValue = val.unchecked_into();
+            serde_wasm_bindgen::from_value::<LengthPercentageDto>(val)
+        };
+        let row = match parse(row_gap) {
+            Ok(row) => row,
+            Err(e) => {
+                log(&format!("set_grid_gap Error: {}", e));
+                return;
+            }
+        };
+        let column = match column_gap.map(parse) {
+            Some(Ok(column)) => column,
+            Some(Err(e)) => {
+                log(&format!("set_grid_gap Error: {}", e));
+                return;
+            }
+            None => row.clone(),
+        };
+        self.inner.gap = SizeDto {
+            width: column,
+            height: row,
         }
+        .into();
+        self.mark(StyleProperty::Gap, true);
     }
 
     /// Gets the inset
@@ -813,7 +954,68 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(i) = serde_wasm_bindgen::from_value::<RectDto<LengthPercentageAutoDto>>(val) {
             self.inner.inset = i.into();
+            self.mark(StyleProperty::Inset, true);
+        }
+    }
+
+    /// Sets the inset using CSS shorthand notation
+    ///
+    /// Accepts one to four values, like the CSS `inset` shorthand:
+    /// - one value: all four edges
+    /// - two values: `top`/`bottom`, then `left`/`right`
+    /// - three values: `top`, then `left`/`right`, then `bottom`
+    /// - four values: `top`, `right`, `bottom`, `left` (clockwise)
+    ///
+    /// If any value is invalid, an error is logged and the inset is left unchanged.
+    ///
+    /// @param top - The first value (all edges when given alone)
+    /// @param right - The optional second value
+    /// @param bottom - The optional third value
+    /// @param left - The optional fourth value
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.position = Position.Absolute;
+    /// style.setInset(0); // all edges 0
+    /// style.setInset(10, "5%"); // top/bottom 10, left/right 5%
+    /// style.setInset(0, "auto", "auto", 0); // pinned to the top-left corner
+    /// ```
+    #[wasm_bindgen(js_name = setInset)]
+    pub fn set_inset_shorthand(
+        &mut self,
+        top: JsLengthPercentageAuto,
+        right: Option<JsLengthPercentageAuto>,
+        bottom: Option<JsLengthPercentageAuto>,
+        left: Option<JsLengthPercentageAuto>,
+    ) {
+        let mut values = Vec::with_capacity(4);
+        for val in std::iter::once(top).chain([right, bottom, left].into_iter().flatten()) {
+            let val: JsValue = val.unchecked_into();
+            match serde_wasm_bindgen::from_value::<LengthPercentageAutoDto>(val) {
+                Ok(v) => values.push(v),
+                Err(e) => {
+                    log(&format!("set_inset Error: {}", e));
+                    return;
+                }
+            }
         }
+
+        let (top, right, bottom, left) = match values.as_slice() {
+            [all] => (all, all, all, all),
+            [vertical, horizontal] => (vertical, horizontal, vertical, horizontal),
+            [top, horizontal, bottom] => (top, horizontal, bottom, horizontal),
+            [top, right, bottom, left] => (top, right, bottom, left),
+            _ => unreachable!("between one and four inset values"),
+        };
+        let rect = RectDto {
+            left: left.clone(),
+            right: right.clone(),
+            top: top.clone(),
+            bottom: bottom.clone(),
+        };
+        self.inner.inset = rect.into();
+        self.mark(StyleProperty::Inset, true);
     }
 
     // =========================================================================
@@ -838,6 +1040,7 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = itemIsTable)]
     pub fn set_item_is_table(&mut self, val: bool) {
         self.inner.item_is_table = val;
+        self.mark(StyleProperty::ItemIsTable, true);
     }
 
     /// Gets whether this item is a replaced element
@@ -858,6 +1061,7 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = itemIsReplaced)]
     pub fn set_item_is_replaced(&mut self, val: bool) {
         self.inner.item_is_replaced = val;
+        self.mark(StyleProperty::ItemIsReplaced, true);
     }
 
     /// Gets the scrollbar width
@@ -885,6 +1089,7 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = scrollbarWidth)]
     pub fn set_scrollbar_width(&mut self, val: f32) {
         self.inner.scrollbar_width = val;
+        self.mark(StyleProperty::ScrollbarWidth, true);
     }
 
     /// Gets the text-align property
@@ -911,6 +1116,7 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = textAlign)]
     pub fn set_text_align(&mut self, val: JsTextAlign) {
         self.inner.text_align = val.into();
+        self.mark(StyleProperty::TextAlign, true);
     }
 
     // =========================================================================
@@ -943,11 +1149,14 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         self.inner.justify_items = if val.is_undefined() {
             None
-        } else if let Some(n) = val.as_f64() {
-            Some(unsafe { std::mem::transmute::<u8, JsAlignItems>(n as u8) }.into())
         } else {
-            None
+            val.as_f64()
+                .map(|n| unsafe { std::mem::transmute::<u8, JsAlignItems>(n as u8) }.into())
         };
+        self.mark(
+            StyleProperty::JustifyItems,
+            self.inner.justify_items.is_some(),
+        );
     }
 
     /// Gets the justify-self property
@@ -977,15 +1186,14 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         self.inner.justify_self = if val.is_undefined() {
             None
-        } else if let Some(n) = val.as_f64() {
-            let js_val = unsafe { std::mem::transmute::<u8, JsAlignSelf>(n as u8) };
-            match js_val {
-                JsAlignSelf::Auto => None,
-                _ => Some(js_val.into()),
-            }
         } else {
-            None
+            val.as_f64()
+                .and_then(|n| unsafe { std::mem::transmute::<u8, JsAlignSelf>(n as u8) }.into())
         };
+        self.mark(
+            StyleProperty::JustifySelf,
+            self.inner.justify_self.is_some(),
+        );
     }
 
     // =========================================================================
@@ -1017,6 +1225,7 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = gridAutoFlow)]
     pub fn set_grid_auto_flow(&mut self, val: JsGridAutoFlow) {
         self.inner.grid_auto_flow = val.into();
+        self.mark(StyleProperty::GridAutoFlow, true);
     }
 
     /// Gets the grid-row property
@@ -1049,6 +1258,7 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(dto) = serde_wasm_bindgen::from_value::<LineGridPlacementDto>(val) {
             self.inner.grid_row = dto.into();
+            self.mark(StyleProperty::GridRow, true);
         }
     }
 
@@ -1082,9 +1292,54 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(dto) = serde_wasm_bindgen::from_value::<LineGridPlacementDto>(val) {
             self.inner.grid_column = dto.into();
+            self.mark(StyleProperty::GridColumn, true);
         }
     }
 
+    /// Places the item into a named grid area
+    ///
+    /// Equivalent to CSS `grid-area: <name>`: sets both `gridRow` and
+    /// `gridColumn` to span from the `<name>-start` line to the `<name>-end`
+    /// line. A grid container's `gridTemplateAreas` defines these lines for
+    /// each of its areas, so the item covers the area with that name.
+    ///
+    /// The name must be a CSS identifier: non-empty, without whitespace, not
+    /// starting with a digit, and not a keyword (`auto` or `span`). If it is
+    /// not, an error is logged and the placement is left unchanged. Whether
+    /// the area exists is only known to the container; as in CSS, an item
+    /// naming an unknown area is placed in the implicit grid.
+    ///
+    /// @param name - The name of the grid area
+    ///
+    /// @example
+    /// ```typescript
+    /// const header = new Style();
+    /// header.setGridArea("header"); // CSS: grid-area: header
+    /// console.log(header.gridRow.start); // { line: 1, ident: "header-start" }
+    /// ```
+    #[wasm_bindgen(js_name = setGridArea)]
+    pub fn set_grid_area(&mut self, name: &str) {
+        let is_identifier = !name.is_empty()
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && !name.contains(char::is_whitespace)
+            && !matches!(name, "auto" | "span");
+        if !is_identifier {
+            log(&format!(
+                "set_grid_area Error: invalid area name {:?}",
+                name
+            ));
+            return;
+        }
+        let lines = LineGridPlacementDto {
+            start: GridPlacementDto::NamedLine(1, format!("{name}-start")),
+            end: GridPlacementDto::NamedLine(1, format!("{name}-end")),
+        };
+        self.inner.grid_row = lines.clone().into();
+        self.inner.grid_column = lines.into();
+        self.mark(StyleProperty::GridRow, true);
+        self.mark(StyleProperty::GridColumn, true);
+    }
+
     /// Gets the grid-template-rows property
     ///
     /// Defines the track sizing functions (heights) of the grid rows.
@@ -1104,12 +1359,36 @@ impl JsStyle {
 
     /// Sets the grid-template-rows property
     ///
+    /// Repetitions can be given as objects or in CSS `repeat()` notation.
+    /// Invalid input, including an invalid `repeat()` string, is logged and
+    /// leaves the property unchanged.
+    ///
     /// @param val - An array of GridTrack objects
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.display = Display.Grid;
+    /// style.gridTemplateRows = ["repeat(auto-fill, minmax(100px, 1fr))"];
+    /// ```
     #[wasm_bindgen(setter, js_name = gridTemplateRows)]
     pub fn set_grid_template_rows(&mut self, val: JsGridTemplateComponents) {
         let val: JsValue = val.unchecked_into();
-        if let Ok(tracks) = serde_wasm_bindgen::from_value::<Vec<GridTemplateComponentDto>>(val) {
-            self.inner.grid_template_rows = tracks.into_iter().map(|t| t.into()).collect();
+        match serde_wasm_bindgen::from_value::<Vec<GridTemplateComponentDto>>(val.clone()) {
+            Ok(tracks) => {
+                self.inner.grid_template_rows = tracks.into_iter().map(|t| t.into()).collect();
+                self.mark(StyleProperty::GridTemplateRows, true);
+            }
+            Err(e) => {
+                let json = js_sys::JSON::stringify(&val)
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .unwrap_or("?".to_string());
+                log(&format!(
+                    "set_grid_template_rows Error: {} | Input: {}",
+                    e, json
+                ));
+            }
         }
     }
 
@@ -1132,12 +1411,23 @@ impl JsStyle {
 
     /// Sets the grid-template-columns property
     ///
+    /// Repetitions can be given as objects or in CSS `repeat()` notation.
+    /// Invalid input, including an invalid `repeat()` string, is logged and
+    /// leaves the property unchanged.
+    ///
     /// @param val - An array of GridTrack objects
     ///
     /// @example
     /// ```typescript
     /// const style = new Style();
     /// style.display = Display.Grid;
+    /// style.gridTemplateColumns = ["repeat(auto-fill, minmax(100px, 1fr))"];
+    /// ```
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.display = Display.Grid;
     /// style.gridTemplateColumns = [
     ///   { min: 200, max: 200 },
     ///   { min: "auto", max: "1fr" },
@@ -1147,8 +1437,89 @@ impl JsStyle {
     #[wasm_bindgen(setter, js_name = gridTemplateColumns)]
     pub fn set_grid_template_columns(&mut self, val: JsGridTemplateComponents) {
         let val: JsValue = val.unchecked_into();
-        if let Ok(tracks) = serde_wasm_bindgen::from_value::<Vec<GridTemplateComponentDto>>(val) {
-            self.inner.grid_template_columns = tracks.into_iter().map(|t| t.into()).collect();
+        match serde_wasm_bindgen::from_value::<Vec<GridTemplateComponentDto>>(val.clone()) {
+            Ok(tracks) => {
+                self.inner.grid_template_columns = tracks.into_iter().map(|t| t.into()).collect();
+                self.mark(StyleProperty::GridTemplateColumns, true);
+            }
+            Err(e) => {
+                let json = js_sys::JSON::stringify(&val)
+                    .ok()
+                    .and_then(|s| s.as_string())
+                    .unwrap_or("?".to_string());
+                log(&format!(
+                    "set_grid_template_columns Error: {} | Input: {}",
+                    e, json
+                ));
+            }
+        }
+    }
+
+    /// Gets the grid-template-rows property as structured tracks
+    ///
+    /// Returns the same template as `gridTemplateRows`, with each track in the
+    /// `kind`-tagged `GridTrack` shape.
+    ///
+    /// @returns - An array of `GridTrack` objects
+    #[wasm_bindgen(getter, js_name = gridTemplateRowTracks)]
+    pub fn grid_template_row_tracks(&self) -> JsGridTracks {
+        serialize(&GridTrackDto::from_template(&self.inner.grid_template_rows)).unchecked_into()
+    }
+
+    /// Sets the grid-template-rows property from structured tracks
+    ///
+    /// Invalid input is logged and leaves the property unchanged.
+    ///
+    /// @param val - An array of `GridTrack` objects
+    #[wasm_bindgen(setter, js_name = gridTemplateRowTracks)]
+    pub fn set_grid_template_row_tracks(&mut self, val: JsGridTracks) {
+        match Self::template_tracks_from_js(val) {
+            Ok(template) => {
+                self.inner.grid_template_rows = template;
+                self.mark(StyleProperty::GridTemplateRows, true);
+            }
+            Err(e) => log(&format!("set_grid_template_row_tracks Error: {}", e)),
+        }
+    }
+
+    /// Gets the grid-template-columns property as structured tracks
+    ///
+    /// Returns the same template as `gridTemplateColumns`, with each track in
+    /// the `kind`-tagged `GridTrack` shape.
+    ///
+    /// @returns - An array of `GridTrack` objects
+    #[wasm_bindgen(getter, js_name = gridTemplateColumnTracks)]
+    pub fn grid_template_column_tracks(&self) -> JsGridTracks {
+        serialize(&GridTrackDto::from_template(
+            &self.inner.grid_template_columns,
+        ))
+        .unchecked_into()
+    }
+
+    /// Sets the grid-template-columns property from structured tracks
+    ///
+    /// Invalid input is logged and leaves the property unchanged.
+    ///
+    /// @param val - An array of `GridTrack` objects
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.display = Display.Grid;
+    /// style.gridTemplateColumnTracks = [
+    ///   { kind: "px", value: 200 },
+    ///   { kind: "minmax", min: { kind: "px", value: 100 }, max: { kind: "fr", value: 1 } },
+    ///   { kind: "repeat", count: 2, tracks: [{ kind: "fr", value: 1 }] }
+    /// ];
+    /// ```
+    #[wasm_bindgen(setter, js_name = gridTemplateColumnTracks)]
+    pub fn set_grid_template_column_tracks(&mut self, val: JsGridTracks) {
+        match Self::template_tracks_from_js(val) {
+            Ok(template) => {
+                self.inner.grid_template_columns = template;
+                self.mark(StyleProperty::GridTemplateColumns, true);
+            }
+            Err(e) => log(&format!("set_grid_template_column_tracks Error: {}", e)),
         }
     }
 
@@ -1184,6 +1555,7 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(tracks) = serde_wasm_bindgen::from_value::<Vec<TrackSizingFunctionDto>>(val) {
             self.inner.grid_auto_rows = tracks.into_iter().map(|t| t.into()).collect();
+            self.mark(StyleProperty::GridAutoRows, true);
         }
     }
 
@@ -1212,6 +1584,73 @@ impl JsStyle {
         let val: JsValue = val.unchecked_into();
         if let Ok(tracks) = serde_wasm_bindgen::from_value::<Vec<TrackSizingFunctionDto>>(val) {
             self.inner.grid_auto_columns = tracks.into_iter().map(|t| t.into()).collect();
+            self.mark(StyleProperty::GridAutoColumns, true);
+        }
+    }
+
+    /// Gets the grid-auto-rows property as structured tracks
+    ///
+    /// Returns the same tracks as `gridAutoRows`, in the `kind`-tagged
+    /// `GridTrack` shape.
+    ///
+    /// @returns - An array of `GridTrack` objects
+    #[wasm_bindgen(getter, js_name = gridAutoRowTracks)]
+    pub fn grid_auto_row_tracks(&self) -> JsGridTracks {
+        serialize(&GridTrackDto::from_auto_tracks(&self.inner.grid_auto_rows)).unchecked_into()
+    }
+
+    /// Sets the grid-auto-rows property from structured tracks
+    ///
+    /// Implicit tracks cannot repeat: input containing a `repeat` track, or
+    /// any other invalid input, is logged and leaves the property unchanged.
+    ///
+    /// @param val - An array of non-`repeat` `GridTrack` objects
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.display = Display.Grid;
+    /// style.gridAutoRowTracks = [{ kind: "min-content" }, { kind: "fr", value: 1 }];
+    /// ```
+    #[wasm_bindgen(setter, js_name = gridAutoRowTracks)]
+    pub fn set_grid_auto_row_tracks(&mut self, val: JsGridTracks) {
+        match Self::auto_tracks_from_js(val) {
+            Ok(tracks) => {
+                self.inner.grid_auto_rows = tracks;
+                self.mark(StyleProperty::GridAutoRows, true);
+            }
+            Err(e) => log(&format!("set_grid_auto_row_tracks Error: {}", e)),
+        }
+    }
+
+    /// Gets the grid-auto-columns property as structured tracks
+    ///
+    /// Returns the same tracks as `gridAutoColumns`, in the `kind`-tagged
+    /// `GridTrack` shape.
+    ///
+    /// @returns - An array of `GridTrack` objects
+    #[wasm_bindgen(getter, js_name = gridAutoColumnTracks)]
+    pub fn grid_auto_column_tracks(&self) -> JsGridTracks {
+        serialize(&GridTrackDto::from_auto_tracks(
+            &self.inner.grid_auto_columns,
+        ))
+        .unchecked_into()
+    }
+
+    /// Sets the grid-auto-columns property from structured tracks
+    ///
+    /// Implicit tracks cannot repeat: input containing a `repeat` track, or
+    /// any other invalid input, is logged and leaves the property unchanged.
+    ///
+    /// @param val - An array of non-`repeat` `GridTrack` objects
+    #[wasm_bindgen(setter, js_name = gridAutoColumnTracks)]
+    pub fn set_grid_auto_column_tracks(&mut self, val: JsGridTracks) {
+        match Self::auto_tracks_from_js(val) {
+            Ok(tracks) => {
+                self.inner.grid_auto_columns = tracks;
+                self.mark(StyleProperty::GridAutoColumns, true);
+            }
+            Err(e) => log(&format!("set_grid_auto_column_tracks Error: {}", e)),
         }
     }
 
@@ -1252,6 +1691,7 @@ impl JsStyle {
             serde_wasm_bindgen::from_value::<Vec<crate::types::GridTemplateAreaDto>>(val)
         {
             self.inner.grid_template_areas = areas.into_iter().map(|a| a.into()).collect();
+            self.mark(StyleProperty::GridTemplateAreas, true);
         }
     }
 
@@ -1288,10 +1728,8 @@ impl JsStyle {
     pub fn set_grid_template_row_names(&mut self, val: JsGridLineNames) {
         let val: JsValue = val.unchecked_into();
         if let Ok(names) = serde_wasm_bindgen::from_value::<Vec<Vec<String>>>(val) {
-            self.inner.grid_template_row_names = names
-                .into_iter()
-                .map(|v| v.into_iter().map(|s| s.into()).collect())
-                .collect();
+            self.inner.grid_template_row_names = names;
+            self.mark(StyleProperty::GridTemplateRowNames, true);
         }
     }
 
@@ -1328,10 +1766,453 @@ impl JsStyle {
     pub fn set_grid_template_column_names(&mut self, val: JsGridLineNames) {
         let val: JsValue = val.unchecked_into();
         if let Ok(names) = serde_wasm_bindgen::from_value::<Vec<Vec<String>>>(val) {
-            self.inner.grid_template_column_names = names
-                .into_iter()
-                .map(|v| v.into_iter().map(|s| s.into()).collect())
-                .collect();
+            self.inner.grid_template_column_names = names;
+            self.mark(StyleProperty::GridTemplateColumnNames, true);
+        }
+    }
+
+    // =========================================================================
+    // Property Presence
+    // =========================================================================
+
+    /// Gets the names of the properties that have been explicitly set
+    ///
+    /// Taffy styles have no notion of "unset", so the `Style` object records
+    /// which properties were assigned through their setters. This lets callers
+    /// serialize only the properties that were touched (e.g. for CSS export or
+    /// partial style patches) instead of every default value.
+    ///
+    /// A property counts as set after any successful assignment, even if the
+    /// value equals its default. Optional properties are unset again when their
+    /// value is cleared (e.g. by assigning `undefined`), and values that are
+    /// rejected by a setter do not mark the property. For styles read back
+    /// with `TaffyTree.getStyle()`, the properties that differ from their
+    /// defaults are reported as set.
+    ///
+    /// @returns - The set property names, in declaration order
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.display = Display.Flex;
+    /// style.gap = { width: 8, height: 8 };
+    /// console.log(style.setProperties()); // ["display", "gap"]
+    /// ```
+    #[wasm_bindgen(js_name = setProperties)]
+    pub fn set_properties(&self) -> Vec<String> {
+        StyleProperty::ALL
+            .iter()
+            .filter(|p| self.presence & p.bit() != 0)
+            .map(|p| p.name().to_string())
+            .collect()
+    }
+
+    // =========================================================================
+    // Serialization
+    // =========================================================================
+
+    /// Serializes every property to a plain object
+    ///
+    /// The object is keyed by property name, with each value as its getter
+    /// returns it, and holds only plain data, so it can be stored as JSON or
+    /// posted to a worker. Unlike `setProperties()`, it covers every
+    /// property, defaults included; unset optional properties (such as
+    /// `alignItems`) are left out. Pass it to `Style.fromObject()` to get the
+    /// style back.
+    ///
+    /// @returns - The properties keyed by name
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.flexGrow = 1;
+    /// const json = JSON.stringify(style.toObject());
+    /// const copy = Style.fromObject(JSON.parse(json));
+    /// console.log(copy.flexGrow); // 1
+    /// ```
+    #[wasm_bindgen(js_name = toObject)]
+    pub fn to_object(&self) -> JsStyleObject {
+        let object = js_sys::Object::new();
+        for property in StyleProperty::ALL {
+            if let Some(value) = self.get_property(property.name()) {
+                if !value.is_undefined() {
+                    let _ = js_sys::Reflect::set(&object, &property.name().into(), &value);
+                }
+            }
         }
+        object.unchecked_into()
     }
+
+    /// Creates a style from a plain object of properties
+    ///
+    /// Accepts the objects produced by `toObject()`, as well as partial ones:
+    /// properties missing from the object keep their defaults. Values are
+    /// applied through the property setters, so invalid values are ignored
+    /// like they are there, and unknown keys are ignored too. Only the
+    /// properties present in the object count as set (see `setProperties()`).
+    ///
+    /// @param object - The properties keyed by name
+    ///
+    /// @returns - A new `Style` with those properties
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = Style.fromObject({
+    ///   display: Display.Grid,
+    ///   gap: { width: 8, height: 8 },
+    /// });
+    /// console.log(style.setProperties()); // ["display", "gap"]
+    /// ```
+    #[wasm_bindgen(js_name = fromObject)]
+    pub fn from_object(object: JsStyleObject) -> JsStyle {
+        let object: JsValue = object.unchecked_into();
+        let mut style = JsStyle::new();
+        if !object.is_object() {
+            return style;
+        }
+        for property in StyleProperty::ALL {
+            let value = js_sys::Reflect::get(&object, &property.name().into())
+                .unwrap_or(JsValue::UNDEFINED);
+            if !value.is_undefined() {
+                style.set_property(property.name(), value);
+            }
+        }
+        style
+    }
+
+    // =========================================================================
+    // Convenience Methods
+    // =========================================================================
+
+    /// Makes the element a full-bleed overlay of its parent
+    ///
+    /// Sets `position` to `Position.Absolute` and `inset` to zero on all four
+    /// sides, the canonical "cover the parent" pattern (CSS: `position: absolute; inset: 0`).
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.makeFullOverlay();
+    /// console.log(style.position); // Position.Absolute
+    /// console.log(style.inset); // { left: 0, right: 0, top: 0, bottom: 0 }
+    /// ```
+    #[wasm_bindgen(js_name = makeFullOverlay)]
+    pub fn make_full_overlay(&mut self) {
+        self.inner.position = TaffyStyle::Position::Absolute;
+        self.inner.inset = taffy::geometry::Rect::zero();
+        self.mark(StyleProperty::Position, true);
+        self.mark(StyleProperty::Inset, true);
+    }
+
+    /// Removes the aspect ratio
+    ///
+    /// Equivalent to `style.aspectRatio = undefined`, without relying on how
+    /// `undefined` is passed through the optional setter.
+    ///
+    /// @example
+    /// ```typescript
+    /// const style = new Style();
+    /// style.aspectRatio = 16 / 9;
+    /// style.clearAspectRatio();
+    /// console.log(style.aspectRatio); // undefined
+    /// ```
+    #[wasm_bindgen(js_name = clearAspectRatio)]
+    pub fn clear_aspect_ratio(&mut self) {
+        self.inner.aspect_ratio = None;
+        self.mark(StyleProperty::AspectRatio, false);
+    }
+}
+
+// =============================================================================
+// Trait Implementations
+// =============================================================================
+
+impl Default for JsStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<TaffyStyle::Style> for JsStyle {
+    /// Wraps a native style, reporting the properties that differ from their
+    /// defaults as set
+    fn from(inner: TaffyStyle::Style) -> Self {
+        let presence = StyleProperty::changed_from_default(&inner);
+        JsStyle { inner, presence }
+    }
+}
+
+// =============================================================================
+// Internal Helpers
+// =============================================================================
+
+impl JsStyle {
+    /// Records whether a property is explicitly set
+    fn mark(&mut self, property: StyleProperty, is_set: bool) {
+        if is_set {
+            self.presence |= property.bit();
+        } else {
+            self.presence &= !property.bit();
+        }
+    }
+
+    /// Parses structured tracks for a `grid-template-*` property
+    fn template_tracks_from_js(
+        val: JsGridTracks,
+    ) -> Result<Vec<TaffyStyle::GridTemplateComponent<String>>, String> {
+        let tracks = serde_wasm_bindgen::from_value::<Vec<GridTrackDto>>(val.unchecked_into())
+            .map_err(|e| e.to_string())?;
+        GridTrackDto::to_template(tracks)
+    }
+
+    /// Parses structured tracks for a `grid-auto-*` property
+    fn auto_tracks_from_js(
+        val: JsGridTracks,
+    ) -> Result<Vec<TaffyStyle::TrackSizingFunction>, String> {
+        let tracks = serde_wasm_bindgen::from_value::<Vec<GridTrackDto>>(val.unchecked_into())
+            .map_err(|e| e.to_string())?;
+        GridTrackDto::to_auto_tracks(tracks)
+    }
+
+    /// Sets a single property by its JavaScript name, using the same
+    /// conversion as the property's setter
+    ///
+    /// Invalid values are ignored, as they are by the setters. Returns `false`
+    /// if `key` is not a `Style` property.
+    pub(crate) fn set_property(&mut self, key: &str, value: JsValue) -> bool {
+        // Enum properties arrive as plain numbers; ignore unknown discriminants
+        macro_rules! enum_value {
+            ($enum:ident) => {
+                match value.as_f64().and_then(|n| {
+                    $enum::VARIANTS
+                        .iter()
+                        .copied()
+                        .find(|v| *v as u32 as f64 == n)
+                }) {
+                    Some(v) => v,
+                    None => return true,
+                }
+            };
+        }
+
+        match key {
+            "display" => self.set_display(enum_value!(JsDisplay)),
+            "position" => self.set_position(enum_value!(JsPosition)),
+            "flexDirection" => self.set_flex_direction(enum_value!(JsFlexDirection)),
+            "flexWrap" => self.set_flex_wrap(enum_value!(JsFlexWrap)),
+            "boxSizing" => self.set_box_sizing(enum_value!(JsBoxSizing)),
+            "textAlign" => self.set_text_align(enum_value!(JsTextAlign)),
+            "gridAutoFlow" => self.set_grid_auto_flow(enum_value!(JsGridAutoFlow)),
+            "flexGrow" | "flexShrink" | "scrollbarWidth" => {
+                let Some(n) = value.as_f64() else {
+                    return true;
+                };
+                match key {
+                    "flexGrow" => self.set_flex_grow(n as f32),
+                    "flexShrink" => self.set_flex_shrink(n as f32),
+                    _ => self.set_scrollbar_width(n as f32),
+                }
+            }
+            "itemIsTable" | "itemIsReplaced" => {
+                let Some(b) = value.as_bool() else {
+                    return true;
+                };
+                match key {
+                    "itemIsTable" => self.set_item_is_table(b),
+                    _ => self.set_item_is_replaced(b),
+                }
+            }
+            "alignItems" => self.set_align_items(value.unchecked_into()),
+            "alignSelf" => self.set_align_self(value.unchecked_into()),
+            "alignContent" => self.set_align_content(value.unchecked_into()),
+            "justifyContent" => self.set_justify_content(value.unchecked_into()),
+            "justifyItems" => self.set_justify_items(value.unchecked_into()),
+            "justifySelf" => self.set_justify_self(value.unchecked_into()),
+            "aspectRatio" => self.set_aspect_ratio(value.unchecked_into()),
+            "overflow" => self.set_overflow(value.unchecked_into()),
+            "flexBasis" => self.set_flex_basis(value.unchecked_into()),
+            "size" => self.set_size(value.unchecked_into()),
+            "minSize" => self.set_min_size(value.unchecked_into()),
+            "maxSize" => self.set_max_size(value.unchecked_into()),
+            "margin" => self.set_margin(value.unchecked_into()),
+            "padding" => self.set_padding(value.unchecked_into()),
+            "border" => self.set_border(value.unchecked_into()),
+            "gap" => self.set_gap(value.unchecked_into()),
+            "inset" => self.set_inset(value.unchecked_into()),
+            "gridRow" => self.set_grid_row(value.unchecked_into()),
+            "gridColumn" => self.set_grid_column(value.unchecked_into()),
+            "gridTemplateRows" => self.set_grid_template_rows(value.unchecked_into()),
+            "gridTemplateColumns" => self.set_grid_template_columns(value.unchecked_into()),
+            "gridTemplateRowTracks" => self.set_grid_template_row_tracks(value.unchecked_into()),
+            "gridTemplateColumnTracks" => {
+                self.set_grid_template_column_tracks(value.unchecked_into())
+            }
+            "gridAutoRows" => self.set_grid_auto_rows(value.unchecked_into()),
+            "gridAutoColumns" => self.set_grid_auto_columns(value.unchecked_into()),
+            "gridAutoRowTracks" => self.set_grid_auto_row_tracks(value.unchecked_into()),
+            "gridAutoColumnTracks" => self.set_grid_auto_column_tracks(value.unchecked_into()),
+            "gridTemplateAreas" => self.set_grid_template_areas(value.unchecked_into()),
+            "gridTemplateRowNames" => self.set_grid_template_row_names(value.unchecked_into()),
+            "gridTemplateColumnNames" => {
+                self.set_grid_template_column_names(value.unchecked_into())
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Gets a single property by its JavaScript name, as its getter returns it
+    ///
+    /// Returns `None` if `key` is not a `Style` property.
+    pub(crate) fn get_property(&self, key: &str) -> Option<JsValue> {
+        fn optional<T: Into<JsValue>>(value: Option<T>) -> JsValue {
+            value.map_or(JsValue::UNDEFINED, Into::into)
+        }
+
+        Some(match key {
+            "display" => self.display().into(),
+            "position" => self.position().into(),
+            "flexDirection" => self.flex_direction().into(),
+            "flexWrap" => self.flex_wrap().into(),
+            "flexGrow" => self.flex_grow().into(),
+            "flexShrink" => self.flex_shrink().into(),
+            "alignItems" => optional(self.align_items()),
+            "alignSelf" => optional(self.align_self()),
+            "alignContent" => optional(self.align_content()),
+            "justifyContent" => optional(self.justify_content()),
+            "aspectRatio" => optional(self.aspect_ratio()),
+            "overflow" => self.overflow().into(),
+            "boxSizing" => self.box_sizing().into(),
+            "flexBasis" => self.flex_basis().into(),
+            "size" => self.size().into(),
+            "minSize" => self.min_size().into(),
+            "maxSize" => self.max_size().into(),
+            "margin" => self.margin().into(),
+            "padding" => self.padding().into(),
+            "border" => self.border().into(),
+            "gap" => self.gap().into(),
+            "inset" => self.inset().into(),
+            "itemIsTable" => self.item_is_table().into(),
+            "itemIsReplaced" => self.item_is_replaced().into(),
+            "scrollbarWidth" => self.scrollbar_width().into(),
+            "textAlign" => self.text_align().into(),
+            "justifyItems" => optional(self.justify_items()),
+            "justifySelf" => optional(self.justify_self()),
+            "gridAutoFlow" => self.grid_auto_flow().into(),
+            "gridRow" => self.grid_row().into(),
+            "gridColumn" => self.grid_column().into(),
+            "gridTemplateRows" => self.grid_template_rows().into(),
+            "gridTemplateColumns" => self.grid_template_columns().into(),
+            "gridTemplateRowTracks" => self.grid_template_row_tracks().into(),
+            "gridTemplateColumnTracks" => self.grid_template_column_tracks().into(),
+            "gridAutoRows" => self.grid_auto_rows().into(),
+            "gridAutoColumns" => self.grid_auto_columns().into(),
+            "gridAutoRowTracks" => self.grid_auto_row_tracks().into(),
+            "gridAutoColumnTracks" => self.grid_auto_column_tracks().into(),
+            "gridTemplateAreas" => self.grid_template_areas().into(),
+            "gridTemplateRowNames" => self.grid_template_row_names().into(),
+            "gridTemplateColumnNames" => self.grid_template_column_names().into(),
+            _ => return None,
+        })
+    }
+
+    /// Builds a plain object of the set properties (see `setProperties()`),
+    /// keyed by name, in the shape `TaffyTree.setStyleProperty()` accepts
+    pub(crate) fn to_plain_object(&self) -> js_sys::Object {
+        let object = js_sys::Object::new();
+        for property in StyleProperty::ALL {
+            if self.presence & property.bit() == 0 {
+                continue;
+            }
+            if let Some(value) = self.get_property(property.name()) {
+                let _ = js_sys::Reflect::set(&object, &property.name().into(), &value);
+            }
+        }
+        object
+    }
+}
+
+// =============================================================================
+// Property Presence Tracking
+// =============================================================================
+
+/// Generates the [`StyleProperty`] enum from `Variant => "jsName", field` entries
+macro_rules! style_properties {
+    ($($variant:ident => $name:literal, $field:ident),+ $(,)?) => {
+        /// A `Style` property whose presence is tracked (see `setProperties()`)
+        #[derive(Clone, Copy)]
+        enum StyleProperty {
+            $($variant),+
+        }
+
+        impl StyleProperty {
+            /// All tracked properties, in declaration order
+            const ALL: &'static [StyleProperty] = &[$(StyleProperty::$variant),+];
+
+            /// Gets the property name as exposed to JavaScript
+            fn name(self) -> &'static str {
+                match self {
+                    $(StyleProperty::$variant => $name),+
+                }
+            }
+
+            /// Gets the bit of this property in the presence bitset
+            fn bit(self) -> u64 {
+                1 << self as u32
+            }
+
+            /// Builds a presence bitset of the fields that differ from the default style
+            fn changed_from_default(style: &TaffyStyle::Style) -> u64 {
+                let default = TaffyStyle::Style::default();
+                let mut bits = 0;
+                $(
+                    if style.$field != default.$field {
+                        bits |= StyleProperty::$variant.bit();
+                    }
+                )+
+                bits
+            }
+        }
+    };
+}
+
+style_properties! {
+    Display => "display", display,
+    Position => "position", position,
+    FlexDirection => "flexDirection", flex_direction,
+    FlexWrap => "flexWrap", flex_wrap,
+    FlexGrow => "flexGrow", flex_grow,
+    FlexShrink => "flexShrink", flex_shrink,
+    AlignItems => "alignItems", align_items,
+    AlignSelf => "alignSelf", align_self,
+    AlignContent => "alignContent", align_content,
+    JustifyContent => "justifyContent", justify_content,
+    AspectRatio => "aspectRatio", aspect_ratio,
+    Overflow => "overflow", overflow,
+    BoxSizing => "boxSizing", box_sizing,
+    FlexBasis => "flexBasis", flex_basis,
+    Size => "size", size,
+    MinSize => "minSize", min_size,
+    MaxSize => "maxSize", max_size,
+    Margin => "margin", margin,
+    Padding => "padding", padding,
+    Border => "border", border,
+    Gap => "gap", gap,
+    Inset => "inset", inset,
+    ItemIsTable => "itemIsTable", item_is_table,
+    ItemIsReplaced => "itemIsReplaced", item_is_replaced,
+    ScrollbarWidth => "scrollbarWidth", scrollbar_width,
+    TextAlign => "textAlign", text_align,
+    JustifyItems => "justifyItems", justify_items,
+    JustifySelf => "justifySelf", justify_self,
+    GridAutoFlow => "gridAutoFlow", grid_auto_flow,
+    GridRow => "gridRow", grid_row,
+    GridColumn => "gridColumn", grid_column,
+    GridTemplateRows => "gridTemplateRows", grid_template_rows,
+    GridTemplateColumns => "gridTemplateColumns", grid_template_columns,
+    GridAutoRows => "gridAutoRows", grid_auto_rows,
+    GridAutoColumns => "gridAutoColumns", grid_auto_columns,
+    GridTemplateAreas => "gridTemplateAreas", grid_template_areas,
+    GridTemplateRowNames => "gridTemplateRowNames", grid_template_row_names,
+    GridTemplateColumnNames => "gridTemplateColumnNames", grid_template_column_names,
 }
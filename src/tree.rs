@@ -96,18 +96,39 @@
 //! }
 //! ```
 
-use crate::error::{JsTaffyError, map_bool_result, map_node_result, map_void_result, to_js_error};
+use crate::enums::{JsAlignSelf, JsDisplay, JsJustifyContent};
+use crate::error::{
+    ErrorKind, JsTaffyError, map_bool_result, map_node_result, map_void_result, to_js_error,
+};
 use crate::layout::JsLayout;
 use crate::style::JsStyle;
-use crate::types::{AvailableSizeDto, JsAvailableSizeArg, JsMeasureFunctionArg};
-use crate::{DetailedGridInfoDto, DetailedGridItemsInfoDto, DetailedGridTracksInfoDto};
+#[cfg(feature = "dom")]
+use crate::types::JsDomElementMap;
+use crate::types::{
+    AutoMarginAppliedDto, AvailableSizeDto, BoxModelDto, DomRectDto, JsAutoMarginApplied,
+    JsAvailableSizeArg, JsAvailableSizeArray, JsBoxModel, JsLayoutProfile, JsLayoutVisitor,
+    JsMeasureFunctionArg, JsNestedNode, JsNodeDomRects, JsPointBool, JsRectNumber, JsResolvedGap,
+    JsResolvedGridAlignment, JsScrollOffsets, JsSizeBool, JsSizeNumber, JsSlowLayoutCallback,
+    JsStyleChangeCallback, JsTreeProblems, JsTreeSpec, LayoutRectDto, MeasuredSizeDto, RectDto,
+    ResolvedGapDto, ResolvedGridAlignmentDto, ScrollOffsetDto, SlowLayoutInfoDto, TreeProblemDto,
+};
+use crate::utils::{now, serialize, serialize_with_node_ids};
+#[cfg(feature = "detailed_layout_info")]
+use crate::{
+    DetailedGridInfoDto, DetailedGridItemsInfoDto, DetailedGridTracksInfoDto, GridAutoPlacementDto,
+    GridDimensionsDto, JsGridAutoPlacement, JsGridDimensions,
+};
 
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use taffy::TaffyError as NativeTaffyError;
 use taffy::TaffyTree;
+use taffy::geometry::Point;
 use taffy::prelude::*;
 use taffy::style::{self as TaffyStyle};
 #[cfg(feature = "detailed_layout_info")]
 use taffy::tree::DetailedLayoutInfo;
+use taffy::util::{MaybeResolve, ResolveOrZero};
 use wasm_bindgen::prelude::*;
 
 // =============================================================================
@@ -123,6 +144,56 @@ use wasm_bindgen::prelude::*;
 pub struct JsTaffyTree {
     /// The underlying Taffy tree with JsValue context type
     tree: TaffyTree<JsValue>,
+    /// Everything tracked alongside the Taffy tree
+    state: TreeState,
+}
+
+/// Per-tree state kept next to the Taffy tree
+///
+/// Grouped in one struct so that the constructors and `clone()` cannot miss
+/// a field when new state is added.
+#[derive(Clone, Default)]
+struct TreeState {
+    /// Whether mutating methods are currently rejected (see `freeze()`)
+    frozen: bool,
+    /// Last seen `context.version` per node (see `computeLayoutWithMeasure()`)
+    context_versions: HashMap<NodeId, JsValue>,
+    /// Layout time budget in milliseconds and its callback (see `setSlowLayoutThreshold()`)
+    slow_layout: Option<(f64, js_sys::Function)>,
+    /// Callback fired when a node's style changes (see `onStyleChange()`)
+    style_change: Option<js_sys::Function>,
+    /// Measure function calls per node during the last layout (see `measureCallCount()`)
+    measure_calls: HashMap<NodeId, u32>,
+    /// Baselines reported by measure functions during the last layout (see `measuredBaseline()`)
+    measured_baselines: HashMap<NodeId, f32>,
+    /// Layout snapshots stored with `pinLayout()`
+    pinned_layouts: HashMap<NodeId, Layout>,
+    /// Root size computed by the last layout of a root node (see `rootSizeChanged()`)
+    last_root_size: Option<Size<f32>>,
+    /// Whether the last root layout changed the root size
+    root_size_changed: bool,
+    /// Available space of the last successful layout (see `lastAvailableSpace()`)
+    last_available_space: Option<Size<AvailableSpace>>,
+    /// Per-node measure functions taken from `context.measure` (see `setNodeContext()`)
+    node_measures: HashMap<NodeId, js_sys::Function>,
+    /// Style template for `newLeafDefault()` and `buildTree()` (see `setDefaultStyle()`)
+    default_style: TaffyStyle::Style,
+    /// Whether layouts record timings (see `enableProfiling()`)
+    profiling: bool,
+    /// Root and duration of the last profiled layout (see `layoutProfile()`)
+    profiled_layout: Option<(NodeId, f64)>,
+    /// Time spent in measure functions per node during the last profiled layout
+    measure_times: HashMap<NodeId, f64>,
+    /// Every live node, in no particular order (see `orphanNodes()`)
+    node_ids: HashSet<NodeId>,
+    /// Nodes that have been laid out at least once (see `hasLayout()`)
+    laid_out: HashSet<NodeId>,
+    /// Nesting depth of open batches (see `beginBatch()`)
+    batch_depth: u32,
+    /// Styles set during the open batch, applied when it ends
+    batched_styles: HashMap<NodeId, TaffyStyle::Style>,
+    /// Nodes marked dirty during the open batch, marked when it ends
+    batched_dirty: HashSet<NodeId>,
 }
 
 #[wasm_bindgen(js_class = "TaffyTree")]
@@ -146,6 +217,7 @@ impl JsTaffyTree {
         console_error_panic_hook::set_once();
         JsTaffyTree {
             tree: TaffyTree::new(),
+            state: TreeState::default(),
         }
     }
 
@@ -166,6 +238,54 @@ impl JsTaffyTree {
         console_error_panic_hook::set_once();
         JsTaffyTree {
             tree: TaffyTree::with_capacity(capacity),
+            state: TreeState::default(),
+        }
+    }
+
+    /// Creates an independent copy of the tree
+    ///
+    /// The copy contains every node with its style, children, cached layout and
+    /// context, so speculative changes ("what if I resize this?") can be laid out
+    /// without touching the live tree. Node IDs are preserved: an ID from this
+    /// tree refers to the same node in the clone.
+    ///
+    /// Contexts are arbitrary JavaScript values and are copied by reference, so
+    /// both trees share the same context objects. The clone keeps the
+    /// slow-layout threshold but always starts unfrozen and outside any batch,
+    /// with the changes of an open batch already applied.
+    ///
+    /// @returns - A new `TaffyTree` with the same nodes
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const root = tree.newLeaf(new Style());
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// const preview = tree.clone();
+    /// preview.computeLayout(root, { width: 400, height: 600 });
+    /// console.log(preview.getLayout(root).width); // 400
+    /// console.log(tree.getLayout(root).width); // 800
+    /// preview.free();
+    /// ```
+    #[wasm_bindgen(js_name = clone)]
+    pub fn clone_tree(&self) -> JsTaffyTree {
+        let mut tree = self.tree.clone();
+        for &node in &self.state.batched_dirty {
+            let _ = tree.mark_dirty(node);
+        }
+        for (&node, style) in &self.state.batched_styles {
+            let _ = tree.set_style(node, style.clone());
+        }
+        JsTaffyTree {
+            tree,
+            state: TreeState {
+                frozen: false,
+                batch_depth: 0,
+                batched_styles: HashMap::new(),
+                batched_dirty: HashSet::new(),
+                ..self.state.clone()
+            },
         }
     }
 
@@ -179,14 +299,18 @@ impl JsTaffyTree {
     /// are rounded to the nearest integer. This prevents sub-pixel rendering
     /// issues in most rendering contexts.
     ///
+    /// @throws `TaffyError` if the tree is frozen
+    ///
     /// @example
     /// ```typescript
     /// const tree = new TaffyTree();
     /// tree.enableRounding();
     /// ```
     #[wasm_bindgen(js_name = enableRounding)]
-    pub fn enable_rounding(&mut self) {
+    pub fn enable_rounding(&mut self) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
         self.tree.enable_rounding();
+        Ok(())
     }
 
     /// Disables rounding of layout values
@@ -195,6 +319,8 @@ impl JsTaffyTree {
     /// Use this when you need sub-pixel accuracy or when performing custom
     /// rounding.
     ///
+    /// @throws `TaffyError` if the tree is frozen
+    ///
     /// @example
     /// ```typescript
     /// const tree = new TaffyTree();
@@ -204,8 +330,309 @@ impl JsTaffyTree {
     /// console.log(layout.x);
     /// ```
     #[wasm_bindgen(js_name = disableRounding)]
-    pub fn disable_rounding(&mut self) {
+    pub fn disable_rounding(&mut self) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
         self.tree.disable_rounding();
+        Ok(())
+    }
+
+    /// Freezes the tree, making it read-only
+    ///
+    /// While frozen, every method that mutates the tree — creating or removing
+    /// nodes, editing children, styles or contexts, marking nodes dirty,
+    /// changing rounding and computing layout — throws a `TaffyError` instead
+    /// of modifying the tree. Read methods such as `getLayout()` and
+    /// `children()` keep working.
+    ///
+    /// Use this to protect a tree that has been handed to rendering code from
+    /// accidental structural edits, e.g. from event handlers.
+    ///
+    /// @throws `TaffyError` if a batch is open (see `beginBatch()`)
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const rootId = tree.newLeaf(new Style());
+    /// tree.computeLayout(rootId, { width: 800, height: 600 });
+    ///
+    /// tree.freeze();
+    /// const layout = tree.getLayout(rootId); // reads still work
+    /// try {
+    ///   tree.newLeaf(new Style());
+    /// } catch (e) {
+    ///   console.error("Tree is frozen");
+    /// }
+    /// tree.unfreeze();
+    /// ```
+    #[wasm_bindgen(js_name = freeze)]
+    pub fn freeze(&mut self) -> Result<(), JsValue> {
+        self.ensure_no_batch()?;
+        self.state.frozen = true;
+        Ok(())
+    }
+
+    /// Unfreezes the tree, allowing mutations again
+    ///
+    /// Calling this on a tree that is not frozen has no effect.
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// tree.freeze();
+    /// tree.unfreeze();
+    /// const nodeId = tree.newLeaf(new Style());
+    /// ```
+    #[wasm_bindgen(js_name = unfreeze)]
+    pub fn unfreeze(&mut self) {
+        self.state.frozen = false;
+    }
+
+    /// Checks whether the tree is frozen
+    ///
+    /// @returns - true if mutating methods are currently rejected
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// tree.freeze();
+    /// console.log(tree.isFrozen()); // true
+    /// ```
+    #[wasm_bindgen(js_name = isFrozen)]
+    pub fn is_frozen(&self) -> bool {
+        self.state.frozen
+    }
+
+    /// Sets a time budget for layout computation
+    ///
+    /// Whenever `computeLayout()` or `computeLayoutWithMeasure()` takes longer
+    /// than `ms` milliseconds, `callback` is called with a `SlowLayoutInfo`
+    /// describing the run. This is purely observational: the layout is never
+    /// aborted. Timing uses `performance.now()`.
+    ///
+    /// @param ms - The threshold in milliseconds
+    /// @param callback - Called with `{ node, durationMs, thresholdMs, nodeCount }`
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// tree.setSlowLayoutThreshold(16, (info) => {
+    ///   console.warn(`Layout of ${info.nodeCount} nodes took ${info.durationMs}ms`);
+    /// });
+    /// ```
+    #[wasm_bindgen(js_name = setSlowLayoutThreshold)]
+    pub fn set_slow_layout_threshold(&mut self, ms: f64, callback: JsSlowLayoutCallback) {
+        self.state.slow_layout = Some((ms, callback.unchecked_into()));
+    }
+
+    /// Removes the time budget set by `setSlowLayoutThreshold()`
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// tree.setSlowLayoutThreshold(16, (info) => console.warn(info));
+    /// tree.clearSlowLayoutThreshold();
+    /// ```
+    #[wasm_bindgen(js_name = clearSlowLayoutThreshold)]
+    pub fn clear_slow_layout_threshold(&mut self) {
+        self.state.slow_layout = None;
+    }
+
+    /// Registers a callback for style changes
+    ///
+    /// `callback` is called with the node ID whenever `setStyle()` or
+    /// `setStyleProperty()` changes a node's style. It fires synchronously,
+    /// before the setter returns, so the new style is already visible through
+    /// `getStyle()`. `setStyleProperty()` calls that leave the style unchanged
+    /// do not fire it, and exceptions thrown by the callback are ignored.
+    ///
+    /// Only one callback is kept: registering another replaces it, and
+    /// passing `undefined` removes it. Editing a `Style` object does not fire
+    /// the callback until the style is applied to a node.
+    ///
+    /// @param callback - Called with the `bigint` ID of the changed node, or
+    /// `undefined` to stop observing
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const node = tree.newLeaf(new Style());
+    /// const changed: bigint[] = [];
+    ///
+    /// tree.onStyleChange((id) => changed.push(id));
+    /// tree.setStyleProperty(node, "flexGrow", 1); // changed: [node]
+    /// tree.onStyleChange(undefined);
+    /// ```
+    #[wasm_bindgen(js_name = onStyleChange)]
+    pub fn on_style_change(&mut self, callback: Option<JsStyleChangeCallback>) {
+        self.state.style_change = callback.map(JsCast::unchecked_into);
+    }
+
+    /// Starts recording layout timings
+    ///
+    /// While profiling is enabled, every `computeLayout()` and
+    /// `computeLayoutWithMeasure()` records its duration and the time spent in
+    /// measure functions per node; read the result with `layoutProfile()`.
+    /// Profiling is off by default and costs nothing while disabled.
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const root = tree.newLeafWithContext(new Style(), { text: "Hello" });
+    /// const measureText: MeasureFunction = (known, available, node, ctx) => ({
+    ///   width: ctx.text.length * 8,
+    ///   height: 16,
+    /// });
+    ///
+    /// tree.enableProfiling();
+    /// tree.computeLayoutWithMeasure(root, { width: 800, height: 600 }, measureText);
+    /// const profile = tree.layoutProfile(root);
+    /// ```
+    #[wasm_bindgen(js_name = enableProfiling)]
+    pub fn enable_profiling(&mut self) {
+        self.state.profiling = true;
+    }
+
+    /// Stops recording layout timings and discards the recorded profile
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const root = tree.newLeaf(new Style());
+    /// tree.enableProfiling();
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// tree.disableProfiling();
+    /// console.log(tree.layoutProfile(root).size); // 0
+    /// ```
+    #[wasm_bindgen(js_name = disableProfiling)]
+    pub fn disable_profiling(&mut self) {
+        self.state.profiling = false;
+        self.state.profiled_layout = None;
+        self.state.measure_times.clear();
+    }
+
+    /// Gets the timings recorded by the last profiled layout
+    ///
+    /// Maps every node in the subtree under `root` to milliseconds spent on
+    /// it. The root of the last profiled layout maps to the layout's total
+    /// duration. Taffy does not expose hooks around individual child
+    /// layouts, so every other node maps to the time spent in measure
+    /// functions for it and its descendants; for trees of measured text this
+    /// is usually where layout time goes.
+    ///
+    /// @param root - The root node ID of the subtree to report
+    ///
+    /// @returns - A `Map` from node ID to milliseconds, empty if no profiled
+    ///   layout has run (see `enableProfiling()`)
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const labels = ["Hello", "World"].map((text) =>
+    ///   tree.newLeafWithContext(new Style(), { text }),
+    /// );
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from(labels));
+    /// const measureText: MeasureFunction = (known, available, node, ctx) => ({
+    ///   width: ctx.text.length * 8,
+    ///   height: 16,
+    /// });
+    ///
+    /// tree.enableProfiling();
+    /// tree.computeLayoutWithMeasure(root, { width: 800, height: 600 }, measureText);
+    /// for (const child of tree.children(root)) {
+    ///   console.log(child, tree.layoutProfile(root).get(child), "ms");
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = layoutProfile)]
+    pub fn layout_profile(&self, root: u64) -> Result<JsLayoutProfile, JsValue> {
+        let root = NodeId::from(root);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let profile = js_sys::Map::new();
+        let Some((layout_root, duration)) = self.state.profiled_layout else {
+            return Ok(profile.unchecked_into());
+        };
+
+        let nodes = self.collect_subtree(root);
+        let totals = self.profile_totals(&nodes)?;
+        for node in nodes {
+            let ms = if node == layout_root {
+                duration
+            } else {
+                totals[&node]
+            };
+            profile.set(&JsValue::from(u64::from(node)), &JsValue::from(ms));
+        }
+        Ok(profile.unchecked_into())
+    }
+
+    /// Exports the last profiled layout as folded stacks for flame graphs
+    ///
+    /// Produces one line per node under `root` that took time, in the folded
+    /// stack format read by `flamegraph.pl`, speedscope and similar tools:
+    /// the node IDs on the path from `root` to the node, joined by `;`,
+    /// followed by the node's own (exclusive) time in microseconds, e.g.
+    /// `1;4;9 1250`. A flame graph built from it shows how layout time
+    /// divides over the tree.
+    ///
+    /// Times are those reported by `layoutProfile()`: a node's own time is
+    /// the time spent in measure functions for it, and the root of the
+    /// profiled layout is also charged the layout time not spent measuring.
+    ///
+    /// @param root - The root node ID of the subtree to export
+    ///
+    /// @returns - The folded stacks, one per line; empty if no profiled
+    ///   layout has run (see `enableProfiling()`)
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const labels = ["Hello", "World"].map((text) =>
+    ///   tree.newLeafWithContext(new Style(), { text }),
+    /// );
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from(labels));
+    /// const measureText: MeasureFunction = (known, available, node, ctx) => ({
+    ///   width: ctx.text.length * 8,
+    ///   height: 16,
+    /// });
+    ///
+    /// tree.enableProfiling();
+    /// tree.computeLayoutWithMeasure(root, { width: 800, height: 600 }, measureText);
+    /// const folded = tree.layoutFlamegraph(root);
+    /// // Save `folded` as layout.folded, then:
+    /// // $ flamegraph.pl --countname=us layout.folded > layout.svg
+    /// ```
+    #[wasm_bindgen(js_name = layoutFlamegraph)]
+    pub fn layout_flamegraph(&self, root: u64) -> Result<String, JsValue> {
+        let root = NodeId::from(root);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let Some((layout_root, duration)) = self.state.profiled_layout else {
+            return Ok(String::new());
+        };
+
+        let totals = self.profile_totals(&self.collect_subtree(root))?;
+        let mut folded = String::new();
+        let mut stack = vec![(root, u64::from(root).to_string())];
+        while let Some((node, path)) = stack.pop() {
+            let children = self.tree.children(node).map_err(to_js_error)?;
+            let own_ms = if node == layout_root {
+                let children_ms: f64 = children.iter().map(|child| totals[child]).sum();
+                (duration - children_ms).max(0.0)
+            } else {
+                self.state.measure_times.get(&node).copied().unwrap_or(0.0)
+            };
+            let own_us = (own_ms * 1000.0).round() as u64;
+            if own_us > 0 {
+                folded.push_str(&format!("{path} {own_us}\n"));
+            }
+            for &child in children.iter().rev() {
+                stack.push((child, format!("{path};{}", u64::from(child))));
+            }
+        }
+        Ok(folded)
     }
 
     // =========================================================================
@@ -215,7 +642,9 @@ impl JsTaffyTree {
     /// Creates a new leaf node with the given style
     ///
     /// A leaf node has no children. Use this for elements that contain
-    /// content (like text) rather than other elements.
+    /// content (like text) rather than other elements. Without a style, the
+    /// node starts from the template set with `setDefaultStyle()`, as with
+    /// `newLeafDefault()`.
     ///
     /// @param style - The style configuration for the node
     /// @returns - The node ID (`bigint`)
@@ -227,10 +656,69 @@ impl JsTaffyTree {
     /// const style = new Style();
     /// style.size = { width: 100, height: 50 };
     /// const nodeId: bigint = tree.newLeaf(style);
+    /// const plainId: bigint = tree.newLeaf();
     /// ```
     #[wasm_bindgen(js_name = newLeaf)]
     pub fn new_leaf(&mut self, style: &JsStyle) -> Result<u64, JsValue> {
-        map_node_result(self.tree.new_leaf(style.inner.clone()))
+        self.ensure_mutable()?;
+        let node = self
+            .tree
+            .new_leaf(style.inner.clone())
+            .map_err(to_js_error)?;
+        self.state.node_ids.insert(node);
+        Ok(node.into())
+    }
+
+    /// Sets the style template used for nodes created without a style
+    ///
+    /// `newLeaf()` without a style, `newLeafDefault()` and `buildTree()` nodes
+    /// start from a copy of this template instead of the `Style` defaults, so
+    /// a house default such as `display: flex` or `boxSizing: ContentBox` is
+    /// written once. The style is copied: later changes to `style` have no effect
+    /// until this is called again. Existing nodes are not changed.
+    ///
+    /// The template survives `clear()` and is copied by `clone()`.
+    ///
+    /// @param style - The template style
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const template = new Style();
+    /// template.display = Display.Block;
+    /// tree.setDefaultStyle(template);
+    ///
+    /// const node = tree.newLeaf();
+    /// console.log(tree.getStyle(node).display); // Display.Block
+    /// ```
+    #[wasm_bindgen(js_name = setDefaultStyle)]
+    pub fn set_default_style(&mut self, style: &JsStyle) {
+        self.state.default_style = style.inner.clone();
+    }
+
+    /// Creates a new leaf node with the default style template
+    ///
+    /// Same as `newLeaf()` without a style: uses the style set with
+    /// `setDefaultStyle()`, or the `Style` defaults if none was set.
+    ///
+    /// @returns - The node ID (`bigint`)
+    ///
+    /// @throws `TaffyError` if the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const nodeId: bigint = tree.newLeafDefault();
+    /// ```
+    #[wasm_bindgen(js_name = newLeafDefault)]
+    pub fn new_leaf_default(&mut self) -> Result<u64, JsValue> {
+        self.ensure_mutable()?;
+        let node = self
+            .tree
+            .new_leaf(self.state.default_style.clone())
+            .map_err(to_js_error)?;
+        self.state.node_ids.insert(node);
+        Ok(node.into())
     }
 
     /// Creates a new leaf node with an attached context value
@@ -259,10 +747,15 @@ impl JsTaffyTree {
         style: &JsStyle,
         context: JsValue,
     ) -> Result<u64, JsValue> {
-        map_node_result(
-            self.tree
-                .new_leaf_with_context(style.inner.clone(), context),
-        )
+        self.ensure_mutable()?;
+        let measure = Self::context_measure(&context);
+        let node = self
+            .tree
+            .new_leaf_with_context(style.inner.clone(), context)
+            .map_err(to_js_error)?;
+        self.state.node_ids.insert(node);
+        self.set_node_measure(node, measure);
+        Ok(node.into())
     }
 
     /// Creates a new node with the given children
@@ -292,16 +785,58 @@ impl JsTaffyTree {
     /// );
     /// ```
     #[wasm_bindgen(js_name = newWithChildren)]
-    pub fn new_with_children(
-        &mut self,
-        style: &JsStyle,
-        children: Box<[u64]>,
-    ) -> Result<u64, JsValue> {
+    pub fn new_with_children(&mut self, style: &JsStyle, children: &[u64]) -> Result<u64, JsValue> {
+        self.ensure_mutable()?;
         let children_ids: Vec<NodeId> = children.iter().map(|&id| NodeId::from(id)).collect();
-        map_node_result(
-            self.tree
-                .new_with_children(style.inner.clone(), &children_ids),
-        )
+        let node = self
+            .tree
+            .new_with_children(style.inner.clone(), &children_ids)
+            .map_err(to_js_error)?;
+        self.state.node_ids.insert(node);
+        Ok(node.into())
+    }
+
+    /// Creates a whole subtree from a declarative spec
+    ///
+    /// Each node in the spec is a `{ style?, context?, children? }` object.
+    /// `style` is a plain object of `Style` properties (not a `Style`
+    /// instance), converted exactly as `setStyleProperty()` converts them, so
+    /// invalid values are ignored. Properties are applied on top of the
+    /// default style template (see `setDefaultStyle()`). `context` is attached as with
+    /// `setNodeContext()`.
+    ///
+    /// The spec is built depth-first. If any node is malformed, every node
+    /// created so far is removed again and the error names the offending
+    /// node by its path, e.g. `spec.children[1].style`.
+    ///
+    /// @param spec - The root node's spec
+    ///
+    /// @returns - The root node ID (`bigint`)
+    ///
+    /// @throws `TaffyError` if the spec is malformed or the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const root = tree.buildTree({
+    ///   style: { size: { width: 400, height: 100 } },
+    ///   children: [{ style: { flexGrow: 1 } }, { style: { flexGrow: 2 } }],
+    /// });
+    /// tree.computeLayout(root, { width: 400, height: 100 });
+    /// ```
+    #[wasm_bindgen(js_name = buildTree)]
+    pub fn build_tree(&mut self, spec: JsTreeSpec) -> Result<u64, JsValue> {
+        self.ensure_mutable()?;
+        let mut created = Vec::new();
+        match self.build_spec(spec.as_ref(), "spec", &mut created) {
+            Ok(root) => Ok(root.into()),
+            Err(error) => {
+                for node in created.into_iter().rev() {
+                    let _ = self.remove(node.into());
+                }
+                Err(error)
+            }
+        }
     }
 
     // =========================================================================
@@ -311,7 +846,10 @@ impl JsTaffyTree {
     /// Removes all nodes from the tree
     ///
     /// This clears the entire tree, removing all nodes and their relationships.
-    /// Use this to reset the tree for reuse.
+    /// Use this to reset the tree for reuse. An open batch is discarded;
+    /// settings such as `setDefaultStyle()` and `onStyleChange()` are kept.
+    ///
+    /// @throws `TaffyError` if the tree is frozen
     ///
     /// @example
     /// ```typescript
@@ -320,14 +858,26 @@ impl JsTaffyTree {
     /// console.log(tree.totalNodeCount());
     /// ```
     #[wasm_bindgen(js_name = clear)]
-    pub fn clear(&mut self) {
+    pub fn clear(&mut self) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
         self.tree.clear();
+        // Settings survive; everything recorded about nodes is dropped
+        let state = std::mem::take(&mut self.state);
+        self.state = TreeState {
+            slow_layout: state.slow_layout,
+            style_change: state.style_change,
+            default_style: state.default_style,
+            profiling: state.profiling,
+            ..TreeState::default()
+        };
+        Ok(())
     }
 
     /// Removes a node from the tree
     ///
-    /// The node and all its descendants are removed. If the node has a parent,
-    /// it is automatically removed from the parent's children.
+    /// If the node has a parent, it is automatically removed from the parent's
+    /// children. Its own children are detached and stay in the tree without a
+    /// parent; use `removeMany()` to remove a whole subtree.
     ///
     /// @param node - The node ID to remove
     ///
@@ -347,7 +897,56 @@ impl JsTaffyTree {
     /// ```
     #[wasm_bindgen(js_name = remove)]
     pub fn remove(&mut self, node: u64) -> Result<u64, JsValue> {
-        map_node_result(self.tree.remove(NodeId::from(node)))
+        self.ensure_mutable()?;
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        self.forget_node(node);
+        map_node_result(self.tree.remove(node))
+    }
+
+    /// Removes several nodes and all their descendants in one call
+    ///
+    /// The list may contain both a node and some of its descendants, in any
+    /// order. IDs that do not exist, including descendants and duplicates
+    /// already removed earlier in the same call, are skipped instead of
+    /// throwing.
+    ///
+    /// @param nodes - The node IDs to remove
+    ///
+    /// @returns - The number of nodes actually removed, descendants included
+    ///
+    /// @throws `TaffyError` if the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const [a, b, c] = [1, 2, 3].map(() => tree.newLeaf(new Style()));
+    /// tree.remove(c);
+    ///
+    /// const selection = BigUint64Array.from([a, b, c]);
+    /// const removed: number = tree.removeMany(selection); // 2, c was already removed
+    /// ```
+    #[wasm_bindgen(js_name = removeMany)]
+    pub fn remove_many(&mut self, nodes: &[u64]) -> Result<u32, JsValue> {
+        self.ensure_mutable()?;
+        let mut removed = 0;
+        for &node in nodes {
+            let node = NodeId::from(node);
+            // Taffy panics on removed IDs instead of returning an error
+            if !self.state.node_ids.contains(&node) {
+                continue;
+            }
+            for id in self.collect_subtree(node) {
+                // A child listed under two parents is reached twice
+                if !self.state.node_ids.contains(&id) {
+                    continue;
+                }
+                self.forget_node(id);
+                self.tree.remove(id).map_err(to_js_error)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
     }
 
     // =========================================================================
@@ -359,6 +958,13 @@ impl JsTaffyTree {
     /// The context can be any JavaScript value and is passed to the measure
     /// function during layout computation.
     ///
+    /// If the context is an object with a `measure` function, that function
+    /// becomes the node's own measurer: `computeLayoutWithMeasure()` calls it
+    /// for this node instead of the measure function passed to it. The
+    /// function is taken when the context is set, so replacing
+    /// `context.measure` later has no effect until `setNodeContext()` is called
+    /// again. `newLeafWithContext()` works the same way.
+    ///
     /// @param node - The node ID
     /// @param context - Any JavaScript value to attach
     ///
@@ -370,13 +976,22 @@ impl JsTaffyTree {
     /// const nodeId = tree.newLeaf(new Style());
     /// interface Context { text: string };
     /// tree.setNodeContext(nodeId, { text: "Updated text" } as Context);
+    ///
+    /// // A node that measures itself
+    /// const measure: MeasureFunction = (known, available, node, ctx) => ({
+    ///   width: ctx.text.length * 8,
+    ///   height: 16,
+    /// });
+    /// tree.setNodeContext(nodeId, { text: "Hello", measure });
     /// ```
     #[wasm_bindgen(js_name = setNodeContext)]
     pub fn set_node_context(&mut self, node: u64, context: JsValue) -> Result<(), JsValue> {
-        map_void_result(
-            self.tree
-                .set_node_context(NodeId::from(node), Some(context)),
-        )
+        self.ensure_mutable()?;
+        let node = NodeId::from(node);
+        let measure = Self::context_measure(&context);
+        map_void_result(self.tree.set_node_context(node, Some(context)))?;
+        self.set_node_measure(node, measure);
+        Ok(())
     }
 
     /// Gets the context value for a node
@@ -439,7 +1054,7 @@ impl JsTaffyTree {
     #[wasm_bindgen(js_name = getDisjointNodeContextMut)]
     pub fn get_disjoint_node_context_mut(
         &mut self,
-        children: Box<[u64]>,
+        children: &[u64],
     ) -> Result<Box<[JsValue]>, JsValue> {
         let mut results = Vec::with_capacity(children.len());
         for id in children.iter() {
@@ -473,6 +1088,7 @@ impl JsTaffyTree {
     /// ```
     #[wasm_bindgen(js_name = addChild)]
     pub fn add_child(&mut self, parent: u64, child: u64) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
         map_void_result(
             self.tree
                 .add_child(NodeId::from(parent), NodeId::from(child)),
@@ -501,6 +1117,7 @@ impl JsTaffyTree {
         index: usize,
         child: u64,
     ) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
         map_void_result(self.tree.insert_child_at_index(
             NodeId::from(parent),
             index,
@@ -528,7 +1145,8 @@ impl JsTaffyTree {
     /// tree.setChildren(parentId, children);
     /// ```
     #[wasm_bindgen(js_name = setChildren)]
-    pub fn set_children(&mut self, parent: u64, children: Box<[u64]>) -> Result<(), JsValue> {
+    pub fn set_children(&mut self, parent: u64, children: &[u64]) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
         let children_ids: Vec<NodeId> = children.iter().map(|&id| NodeId::from(id)).collect();
         map_void_result(self.tree.set_children(NodeId::from(parent), &children_ids))
     }
@@ -552,6 +1170,7 @@ impl JsTaffyTree {
     /// ```
     #[wasm_bindgen(js_name = removeChild)]
     pub fn remove_child(&mut self, parent: u64, child: u64) -> Result<u64, JsValue> {
+        self.ensure_mutable()?;
         map_node_result(
             self.tree
                 .remove_child(NodeId::from(parent), NodeId::from(child)),
@@ -577,6 +1196,7 @@ impl JsTaffyTree {
     /// ```
     #[wasm_bindgen(js_name = removeChildAtIndex)]
     pub fn remove_child_at_index(&mut self, parent: u64, index: usize) -> Result<u64, JsValue> {
+        self.ensure_mutable()?;
         map_node_result(self.tree.remove_child_at_index(NodeId::from(parent), index))
     }
 
@@ -609,6 +1229,7 @@ impl JsTaffyTree {
         index: usize,
         #[wasm_bindgen(js_name = "newChild")] new_child: u64,
     ) -> Result<u64, JsValue> {
+        self.ensure_mutable()?;
         map_node_result(self.tree.replace_child_at_index(
             NodeId::from(parent),
             index,
@@ -638,6 +1259,112 @@ impl JsTaffyTree {
         map_node_result(self.tree.child_at_index(NodeId::from(parent), index))
     }
 
+    /// Gets the index of a child within its parent's children
+    ///
+    /// @param parent - The parent node ID
+    /// @param child - The child node ID to look up
+    ///
+    /// @returns - The 0-based index of `child` among the children of `parent`
+    ///
+    /// @throws `TaffyError` if the parent node does not exist or `child` is not one of its children
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const parentId = tree.newLeaf(new Style());
+    /// const child1 = tree.newLeaf(new Style());
+    /// const child2 = tree.newLeaf(new Style());
+    /// tree.setChildren(parentId, BigUint64Array.from([child1, child2]));
+    /// const index: number = tree.childIndex(parentId, child2); // 1
+    /// ```
+    #[wasm_bindgen(js_name = childIndex)]
+    pub fn child_index(&self, parent: u64, child: u64) -> Result<usize, JsValue> {
+        self.index_of_child(NodeId::from(parent), NodeId::from(child))
+            .map_err(to_js_error)
+    }
+
+    /// Inserts a child immediately before a reference child
+    ///
+    /// Mirrors the DOM `insertBefore()` primitive: `newChild` is inserted at
+    /// the current position of `refChild`, shifting `refChild` and any
+    /// following siblings one position to the right.
+    ///
+    /// @param parent - The parent node ID
+    /// @param newChild - The child node ID to insert
+    /// @param refChild - An existing child of `parent` to insert before
+    ///
+    /// @throws `TaffyError` if either node does not exist or `refChild` is not one of its children
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const parentId = tree.newLeaf(new Style());
+    /// const existing = tree.newLeaf(new Style());
+    /// tree.addChild(parentId, existing);
+    ///
+    /// const inserted = tree.newLeaf(new Style());
+    /// tree.insertBefore(parentId, inserted, existing);
+    /// // children: [inserted, existing]
+    /// ```
+    #[wasm_bindgen(js_name = insertBefore)]
+    pub fn insert_before(
+        &mut self,
+        parent: u64,
+        #[wasm_bindgen(js_name = "newChild")] new_child: u64,
+        #[wasm_bindgen(js_name = "refChild")] ref_child: u64,
+    ) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
+        let parent = NodeId::from(parent);
+        let new_child = NodeId::from(new_child);
+        self.ensure_node(new_child).map_err(to_js_error)?;
+        let index = self
+            .index_of_child(parent, NodeId::from(ref_child))
+            .map_err(to_js_error)?;
+        map_void_result(self.tree.insert_child_at_index(parent, index, new_child))
+    }
+
+    /// Inserts a child immediately after a reference child
+    ///
+    /// The counterpart of `insertBefore()`: `newChild` is inserted directly
+    /// after `refChild`, shifting any following siblings one position to the right.
+    ///
+    /// @param parent - The parent node ID
+    /// @param newChild - The child node ID to insert
+    /// @param refChild - An existing child of `parent` to insert after
+    ///
+    /// @throws `TaffyError` if either node does not exist or `refChild` is not one of its children
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const parentId = tree.newLeaf(new Style());
+    /// const existing = tree.newLeaf(new Style());
+    /// tree.addChild(parentId, existing);
+    ///
+    /// const inserted = tree.newLeaf(new Style());
+    /// tree.insertAfter(parentId, inserted, existing);
+    /// // children: [existing, inserted]
+    /// ```
+    #[wasm_bindgen(js_name = insertAfter)]
+    pub fn insert_after(
+        &mut self,
+        parent: u64,
+        #[wasm_bindgen(js_name = "newChild")] new_child: u64,
+        #[wasm_bindgen(js_name = "refChild")] ref_child: u64,
+    ) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
+        let parent = NodeId::from(parent);
+        let new_child = NodeId::from(new_child);
+        self.ensure_node(new_child).map_err(to_js_error)?;
+        let index = self
+            .index_of_child(parent, NodeId::from(ref_child))
+            .map_err(to_js_error)?;
+        map_void_result(
+            self.tree
+                .insert_child_at_index(parent, index + 1, new_child),
+        )
+    }
+
     /// Removes a range of children
     ///
     /// Removes children from `start_index` (inclusive) to `end_index` (exclusive).
@@ -666,6 +1393,7 @@ impl JsTaffyTree {
         #[wasm_bindgen(js_name = "startIndex")] start_index: usize,
         #[wasm_bindgen(js_name = "endIndex")] end_index: usize,
     ) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
         map_void_result(
             self.tree
                 .remove_children_range(NodeId::from(parent), start_index..end_index),
@@ -686,9 +1414,210 @@ impl JsTaffyTree {
         self.tree.total_node_count()
     }
 
-    /// Gets the number of children of a node
+    /// Lists every node that has no parent
     ///
-    /// @param parent - The parent node ID
+    /// Nodes become parentless when they are created and not yet attached, or
+    /// when their parent is removed: `remove()` detaches a node's children
+    /// rather than removing them. Nodes left like that by accident are leaks,
+    /// and this method helps find them.
+    ///
+    /// Taffy has no notion of an intended root, so the roots you lay out are
+    /// parentless too and are included in the result. Filter them out to get
+    /// the leaked nodes. The IDs are sorted in ascending order.
+    ///
+    /// @returns - The IDs of all parentless nodes (`BigUint64Array`)
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const child = tree.newLeaf(new Style());
+    /// const mid = tree.newWithChildren(new Style(), BigUint64Array.from([child]));
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([mid]));
+    /// tree.remove(mid);
+    ///
+    /// const leaked = Array.from(tree.orphanNodes()).filter((id) => id !== root);
+    /// // [child]
+    /// ```
+    #[wasm_bindgen(js_name = orphanNodes)]
+    pub fn orphan_nodes(&self) -> Box<[u64]> {
+        let mut orphans: Vec<u64> = self
+            .state
+            .node_ids
+            .iter()
+            .filter(|&&node| self.tree.parent(node).is_none())
+            .map(|&node| node.into())
+            .collect();
+        orphans.sort_unstable();
+        orphans.into_boxed_slice()
+    }
+
+    /// Checks the tree for structural problems
+    ///
+    /// Walks every node and checks that parent and child links agree, that no
+    /// node is listed as a child of several nodes or refers to a removed node,
+    /// and that no node is its own descendant. Such corruption can be caused
+    /// by, e.g., adding a node that already has a parent to another parent
+    /// without removing it first. Layout and traversal of a corrupt subtree
+    /// can misbehave or, in the case of a cycle, never finish.
+    ///
+    /// Parentless nodes are not problems in themselves (every root is one);
+    /// use `orphanNodes()` to list them.
+    ///
+    /// @returns - The problems found, sorted by node ID; empty for a healthy tree
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const leaf = tree.newLeaf(new Style());
+    /// tree.newWithChildren(new Style(), BigUint64Array.from([leaf]));
+    ///
+    /// const problems = tree.validateTree(); // []
+    /// for (const { kind, message } of problems) {
+    ///   console.warn(`[${kind}] ${message}`);
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = validateTree)]
+    pub fn validate_tree(&self) -> Result<JsTreeProblems, JsValue> {
+        let mut ids: Vec<NodeId> = self.state.node_ids.iter().copied().collect();
+        ids.sort_unstable_by_key(|&id| u64::from(id));
+        let mut problems = Vec::new();
+        let mut problem = |kind, node: NodeId, message: String| {
+            problems.push(TreeProblemDto {
+                kind,
+                node: node.into(),
+                message,
+            });
+        };
+
+        // Which live nodes list each node as a child
+        let mut listed_by: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+        for &node in &ids {
+            for child in self.tree.children(node).map_err(to_js_error)? {
+                if self.state.node_ids.contains(&child) {
+                    listed_by.entry(child).or_default().push(node);
+                } else {
+                    let message = format!(
+                        "Node {} lists removed node {} as a child",
+                        u64::from(node),
+                        u64::from(child)
+                    );
+                    problem("dangling-child", node, message);
+                }
+            }
+        }
+
+        for &node in &ids {
+            let parents = listed_by.get(&node).map_or(&[][..], Vec::as_slice);
+            let id = u64::from(node);
+            if parents.len() > 1 {
+                let parents: Vec<String> =
+                    parents.iter().map(|&p| u64::from(p).to_string()).collect();
+                let message = format!("Node {id} is a child of nodes {}", parents.join(", "));
+                problem("multiple-parents", node, message);
+            }
+            match self.tree.parent(node) {
+                Some(parent) if !self.state.node_ids.contains(&parent) => {
+                    let message = format!(
+                        "Node {id} has removed node {} as its parent",
+                        u64::from(parent)
+                    );
+                    problem("orphan", node, message);
+                }
+                Some(parent) if !parents.contains(&parent) => {
+                    let message = format!(
+                        "Node {id} has node {} as its parent, which does not list it as a child",
+                        u64::from(parent)
+                    );
+                    problem("parent-mismatch", node, message);
+                }
+                None if !parents.is_empty() => {
+                    let message = format!("Node {id} is listed as a child but has no parent");
+                    problem("parent-mismatch", node, message);
+                }
+                _ => {}
+            }
+        }
+
+        // Depth-first search over child links; reaching a node that is still
+        // on the stack closes a cycle
+        const ON_STACK: u8 = 1;
+        const DONE: u8 = 2;
+        let mut state: HashMap<NodeId, u8> = HashMap::new();
+        for &start in &ids {
+            if state.contains_key(&start) {
+                continue;
+            }
+            state.insert(start, ON_STACK);
+            let children = self.tree.children(start).map_err(to_js_error)?;
+            let mut stack = vec![(start, children.into_iter())];
+            while let Some((node, children)) = stack.last_mut() {
+                let node = *node;
+                match children.next() {
+                    Some(child) if self.state.node_ids.contains(&child) => {
+                        match state.get(&child) {
+                            None => {
+                                state.insert(child, ON_STACK);
+                                let children = self.tree.children(child).map_err(to_js_error)?;
+                                stack.push((child, children.into_iter()));
+                            }
+                            Some(&ON_STACK) => {
+                                let message =
+                                    format!("Node {} is its own descendant", u64::from(child));
+                                problem("cycle", child, message);
+                            }
+                            Some(_) => {}
+                        }
+                    }
+                    Some(_) => {}
+                    None => {
+                        state.insert(node, DONE);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        problems.sort_by_key(|p| p.node);
+        Ok(serialize_with_node_ids(&problems).unchecked_into())
+    }
+
+    /// Gets the deepest nesting level in a subtree
+    ///
+    /// Returns the number of edges on the longest path from `root` down to a
+    /// leaf: `0` for a node without children, `1` if it only has leaf
+    /// children, and so on. Useful to warn about pathologically deep trees.
+    ///
+    /// @param root - The root node ID of the subtree
+    ///
+    /// @returns - The maximum depth below `root`
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const leaf = tree.newLeaf(new Style());
+    /// const mid = tree.newWithChildren(new Style(), BigUint64Array.from([leaf]));
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([mid]));
+    /// console.log(tree.maxDepth(root)); // 2
+    /// ```
+    #[wasm_bindgen(js_name = maxDepth)]
+    pub fn max_depth(&self, root: u64) -> Result<usize, JsValue> {
+        let root = NodeId::from(root);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let mut max = 0;
+        let mut stack = vec![(root, 0)];
+        while let Some((node, depth)) = stack.pop() {
+            max = max.max(depth);
+            let children = self.tree.children(node).map_err(to_js_error)?;
+            stack.extend(children.into_iter().map(|c| (c, depth + 1)));
+        }
+        Ok(max)
+    }
+
+    /// Gets the number of children of a node
+    ///
+    /// @param parent - The parent node ID
     ///
     /// @returns - The number of direct children
     ///
@@ -705,6 +1634,78 @@ impl JsTaffyTree {
         self.tree.child_count(NodeId::from(parent))
     }
 
+    /// Gets the number of descendants of a node
+    ///
+    /// Counts children, grandchildren and so on, but not `root` itself.
+    /// Unlike `childCount()`, this walks the whole subtree.
+    ///
+    /// @param root - The root node ID of the subtree
+    ///
+    /// @returns - The total number of nodes below `root`
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const leaf = tree.newLeaf(new Style());
+    /// const mid = tree.newWithChildren(new Style(), BigUint64Array.from([leaf]));
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([mid]));
+    /// console.log(tree.descendantCount(root)); // 2
+    /// ```
+    #[wasm_bindgen(js_name = descendantCount)]
+    pub fn descendant_count(&self, root: u64) -> Result<usize, JsValue> {
+        let root = NodeId::from(root);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let mut count = 0;
+        let mut stack = self.tree.children(root).map_err(to_js_error)?;
+        while let Some(node) = stack.pop() {
+            count += 1;
+            stack.extend(self.tree.children(node).map_err(to_js_error)?);
+        }
+        Ok(count)
+    }
+
+    /// Gets the IDs of all descendants of a node
+    ///
+    /// Returns children, grandchildren and so on in depth-first pre-order
+    /// (each node before its children, siblings in child order), so a whole
+    /// subtree can be processed without calling `children()` per node.
+    ///
+    /// @param root - The root node ID of the subtree
+    /// @param includeRoot - Whether to list `root` itself first (default `false`)
+    ///
+    /// @returns - The descendant IDs (`BigUint64Array`)
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const leaf = tree.newLeaf(new Style());
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([leaf]));
+    ///
+    /// for (const node of tree.descendants(root, true)) {
+    ///   tree.setStyleProperty(node, "boxSizing", BoxSizing.ContentBox);
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = descendants)]
+    pub fn descendants(
+        &self,
+        root: u64,
+        #[wasm_bindgen(js_name = "includeRoot")] include_root: Option<bool>,
+    ) -> Result<Box<[u64]>, JsValue> {
+        let root = NodeId::from(root);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let skip = if include_root.unwrap_or(false) { 0 } else { 1 };
+        Ok(self
+            .collect_subtree(root)
+            .into_iter()
+            .skip(skip)
+            .map(u64::from)
+            .collect())
+    }
+
     /// Gets the parent of a node
     ///
     /// @param child - The child node ID
@@ -724,6 +1725,147 @@ impl JsTaffyTree {
         self.tree.parent(NodeId::from(child)).map(u64::from)
     }
 
+    /// Checks whether a node is a root, i.e. has no parent
+    ///
+    /// Same as `parent(node) === undefined`, as a cheap stop condition for
+    /// walks up the tree. Detached nodes are roots too, whether or not they
+    /// have been laid out.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - `true` if the node has no parent
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const leaf = tree.newLeaf(new Style());
+    /// const mid = tree.newWithChildren(new Style(), BigUint64Array.from([leaf]));
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([mid]));
+    ///
+    /// let node = leaf;
+    /// while (!tree.isRoot(node)) {
+    ///   node = tree.parent(node)!;
+    /// }
+    /// // node === root
+    /// ```
+    #[wasm_bindgen(js_name = isRoot)]
+    pub fn is_root(&self, node: u64) -> Result<bool, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        Ok(self.tree.parent(node).is_none())
+    }
+
+    /// Gets the first child of a node
+    ///
+    /// @param parent - The parent node ID
+    ///
+    /// @returns - The first child node ID, or `undefined` if the node has no children
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const parentId = tree.newLeaf(new Style());
+    /// const childId = tree.newLeaf(new Style());
+    /// tree.addChild(parentId, childId);
+    /// const first: bigint | undefined = tree.firstChild(parentId);
+    /// ```
+    #[wasm_bindgen(js_name = firstChild)]
+    pub fn first_child(&self, parent: u64) -> Result<Option<u64>, JsValue> {
+        let parent = NodeId::from(parent);
+        self.ensure_node(parent).map_err(to_js_error)?;
+        Ok(self.tree.child_at_index(parent, 0).ok().map(u64::from))
+    }
+
+    /// Gets the last child of a node
+    ///
+    /// @param parent - The parent node ID
+    ///
+    /// @returns - The last child node ID, or `undefined` if the node has no children
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const parentId = tree.newLeaf(new Style());
+    /// const childId = tree.newLeaf(new Style());
+    /// tree.addChild(parentId, childId);
+    /// const last: bigint | undefined = tree.lastChild(parentId);
+    /// ```
+    #[wasm_bindgen(js_name = lastChild)]
+    pub fn last_child(&self, parent: u64) -> Result<Option<u64>, JsValue> {
+        let parent = NodeId::from(parent);
+        self.ensure_node(parent).map_err(to_js_error)?;
+        let count = self.tree.child_count(parent);
+        if count == 0 {
+            return Ok(None);
+        }
+        Ok(self
+            .tree
+            .child_at_index(parent, count - 1)
+            .ok()
+            .map(u64::from))
+    }
+
+    /// Checks whether a node is an ancestor of another node
+    ///
+    /// Walks up the parent chain of `b` looking for `a`. A node is not its own
+    /// ancestor. Useful for drag-and-drop validation, e.g. to refuse dropping
+    /// a node into its own subtree.
+    ///
+    /// @param a - The potential ancestor node ID
+    /// @param b - The potential descendant node ID
+    ///
+    /// @returns - `true` if `a` is a (direct or indirect) parent of `b`
+    ///
+    /// @throws `TaffyError` if either node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const leaf = tree.newLeaf(new Style());
+    /// const mid = tree.newWithChildren(new Style(), BigUint64Array.from([leaf]));
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([mid]));
+    ///
+    /// tree.isAncestorOf(root, leaf); // true
+    /// tree.isAncestorOf(leaf, root); // false
+    /// ```
+    #[wasm_bindgen(js_name = isAncestorOf)]
+    pub fn is_ancestor_of(&self, a: u64, b: u64) -> Result<bool, JsValue> {
+        self.is_ancestor(NodeId::from(a), NodeId::from(b))
+            .map_err(to_js_error)
+    }
+
+    /// Checks whether a node is a descendant of another node
+    ///
+    /// The inverse of `isAncestorOf()`: `isDescendantOf(a, b)` is the same as
+    /// `isAncestorOf(b, a)`. A node is not its own descendant.
+    ///
+    /// @param a - The potential descendant node ID
+    /// @param b - The potential ancestor node ID
+    ///
+    /// @returns - `true` if `a` is inside the subtree of `b`
+    ///
+    /// @throws `TaffyError` if either node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const leaf = tree.newLeaf(new Style());
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([leaf]));
+    ///
+    /// tree.isDescendantOf(leaf, root); // true
+    /// ```
+    #[wasm_bindgen(js_name = isDescendantOf)]
+    pub fn is_descendant_of(&self, a: u64, b: u64) -> Result<bool, JsValue> {
+        self.is_ancestor(NodeId::from(b), NodeId::from(a))
+            .map_err(to_js_error)
+    }
+
     /// Gets all children of a node
     ///
     /// @param parent - The parent node ID
@@ -746,6 +1888,47 @@ impl JsTaffyTree {
             .map_err(to_js_error)
     }
 
+    /// Gets the absolutely positioned children of a node
+    ///
+    /// Returns the children whose `position` is `Position.Absolute`, in
+    /// source order. They are taken out of flow, so renderers typically paint
+    /// them after the in-flow children, in this order.
+    ///
+    /// @param parent - The parent node ID
+    ///
+    /// @returns - Array of child node IDs (`BigUint64Array`)
+    ///
+    /// @throws `TaffyError` if the parent node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const overlayStyle = new Style();
+    /// overlayStyle.position = Position.Absolute;
+    /// const overlay = tree.newLeaf(overlayStyle);
+    /// const content = tree.newLeaf(new Style());
+    /// const parent = tree.newWithChildren(new Style(), BigUint64Array.from([overlay, content]));
+    ///
+    /// const overlays = new Set(tree.absoluteSiblings(parent));
+    /// const children = tree.children(parent);
+    /// const paintOrder = [
+    ///   ...children.filter((child) => !overlays.has(child)),
+    ///   ...overlays,
+    /// ]; // [content, overlay]
+    /// ```
+    #[wasm_bindgen(js_name = absoluteSiblings)]
+    pub fn absolute_siblings(&self, parent: u64) -> Result<Box<[u64]>, JsValue> {
+        let parent = NodeId::from(parent);
+        self.ensure_node(parent).map_err(to_js_error)?;
+        let mut absolute = Vec::new();
+        for child in self.tree.children(parent).map_err(to_js_error)? {
+            if self.tree.style(child).map_err(to_js_error)?.position == Position::Absolute {
+                absolute.push(u64::from(child));
+            }
+        }
+        Ok(absolute.into_boxed_slice())
+    }
+
     // =========================================================================
     // Style Management
     // =========================================================================
@@ -753,7 +1936,8 @@ impl JsTaffyTree {
     /// Sets the style for an existing node
     ///
     /// This replaces the node's current style with the provided one.
-    /// The node will be marked as dirty and require re-layout.
+    /// The node will be marked as dirty and require re-layout. Inside a batch
+    /// (see `beginBatch()`), the style is applied when the batch ends.
     ///
     /// @param node - The node ID
     /// @param style - The new style configuration
@@ -770,11 +1954,79 @@ impl JsTaffyTree {
     /// ```
     #[wasm_bindgen(js_name = setStyle)]
     pub fn set_style(&mut self, node: u64, style: &JsStyle) -> Result<(), JsValue> {
-        map_void_result(self.tree.set_style(NodeId::from(node), style.inner.clone()))
+        self.ensure_mutable()?;
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        if self.state.batch_depth > 0 {
+            self.state.batched_styles.insert(node, style.inner.clone());
+            return Ok(());
+        }
+        map_void_result(self.tree.set_style(node, style.inner.clone()))?;
+        self.notify_style_change(node);
+        Ok(())
+    }
+
+    /// Sets a single style property of an existing node
+    ///
+    /// `key` is the property name as used on `Style` (e.g. `"flexGrow"` or
+    /// `"margin"`) and `value` accepts the same values as that property's
+    /// setter; invalid values are ignored. This saves building a whole `Style`
+    /// for a one-field change in hot editing loops.
+    ///
+    /// If the new value leaves the style unchanged, the node is not marked
+    /// dirty, so redundant updates don't trigger a re-layout. Any actual
+    /// change marks the node dirty, like `setStyle()`, and is likewise
+    /// deferred inside a batch.
+    ///
+    /// @param node - The node ID
+    /// @param key - The `Style` property name
+    /// @param value - The new property value
+    ///
+    /// @throws `TaffyError` if the node does not exist, the property name is
+    /// unknown, or the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const nodeId = tree.newLeaf(new Style());
+    /// tree.setStyleProperty(nodeId, "flexGrow", 2);
+    /// tree.setStyleProperty(nodeId, "margin", { left: 8, right: 8, top: 0, bottom: 0 });
+    /// ```
+    #[wasm_bindgen(js_name = setStyleProperty)]
+    pub fn set_style_property(
+        &mut self,
+        node: u64,
+        key: &str,
+        value: JsValue,
+    ) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let current = match self.state.batched_styles.get(&node) {
+            Some(pending) => pending,
+            None => self.tree.style(node).map_err(to_js_error)?,
+        };
+        let mut style = JsStyle::from(current.clone());
+        if !style.set_property(key, value) {
+            let error = JsTaffyError::from(ErrorKind::UnknownStyleProperty(key.to_string()));
+            return Err(JsValue::from(error));
+        }
+        if style.inner == *current {
+            return Ok(());
+        }
+        if self.state.batch_depth > 0 {
+            self.state.batched_styles.insert(node, style.inner);
+            return Ok(());
+        }
+        map_void_result(self.tree.set_style(node, style.inner))?;
+        self.notify_style_change(node);
+        Ok(())
     }
 
     /// Gets the style for a node
     ///
+    /// Inside a batch, this includes the style changes made so far.
+    ///
     /// @param node - The node ID
     ///
     /// @returns - The node's `Style`
@@ -790,24 +2042,23 @@ impl JsTaffyTree {
     /// ```
     #[wasm_bindgen(js_name = getStyle)]
     pub fn style(&self, node: u64) -> Result<JsStyle, JsValue> {
+        if let Some(pending) = self.state.batched_styles.get(&NodeId::from(node)) {
+            return Ok(JsStyle::from(pending.clone()));
+        }
         match self.tree.style(NodeId::from(node)) {
-            Ok(s) => Ok(JsStyle { inner: s.clone() }),
+            Ok(s) => Ok(JsStyle::from(s.clone())),
             Err(e) => Err(JsValue::from(JsTaffyError::from(e))),
         }
     }
 
-    // =========================================================================
-    // Layout Results
-    // =========================================================================
-
-    /// Gets the computed layout for a node
+    /// Checks whether a node is absolutely positioned
     ///
-    /// Call this after `computeLayout()` to retrieve the computed position
-    /// and size for a node.
+    /// Equivalent to `getStyle(node).position === Position.Absolute`, without
+    /// copying the whole style across the boundary.
     ///
     /// @param node - The node ID
     ///
-    /// @returns - The computed `Layout`
+    /// @returns - `true` if the node's `position` is `Absolute`
     ///
     /// @throws `TaffyError` if the node does not exist
     ///
@@ -815,65 +2066,1509 @@ impl JsTaffyTree {
     /// ```typescript
     /// const tree = new TaffyTree();
     /// const style = new Style();
-    /// style.size = { width: 100, height: 100 };
-    /// const rootId = tree.newLeaf(style);
-    /// const nodeId = rootId;
+    /// style.position = Position.Absolute;
+    /// const nodeId = tree.newLeaf(style);
     ///
-    /// tree.computeLayout(rootId, { width: 800, height: 600 });
-    /// const layout: Layout = tree.getLayout(nodeId);
-    /// console.log(`Position: (${layout.x}, ${layout.y}), Size: ${layout.width}x${layout.height}`);
+    /// if (tree.isAbsolute(nodeId)) {
+    ///   // Hit-test against the containing block instead of the parent's flow
+    /// }
     /// ```
-    #[wasm_bindgen(js_name = getLayout)]
-    pub fn layout(&self, node: u64) -> Result<JsLayout, JsValue> {
-        match self.tree.layout(NodeId::from(node)) {
-            Ok(l) => Ok(JsLayout::from(l)),
-            Err(e) => Err(JsValue::from(JsTaffyError::from(e))),
+    #[wasm_bindgen(js_name = isAbsolute)]
+    pub fn is_absolute(&self, node: u64) -> Result<bool, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        Ok(style.position == Position::Absolute)
+    }
+
+    /// Gets the display mode a node is laid out with
+    ///
+    /// Taffy 0.9 does not rewrite display values (there is no blockification
+    /// or `Block` fallback), so this is the authored `display` except that a
+    /// node inside a `Display.None` ancestor reports `Display.None`, since the
+    /// whole subtree is hidden.
+    ///
+    /// Note that a node without children is sized as a leaf (from its style
+    /// and measure function) whatever its display; the returned value is the
+    /// mode its children would be laid out with.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The effective `Display` value
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const hiddenStyle = new Style();
+    /// hiddenStyle.display = Display.None;
+    /// const node = tree.newLeaf(new Style());
+    /// tree.newWithChildren(hiddenStyle, BigUint64Array.from([node]));
+    ///
+    /// if (tree.resolvedDisplay(node) === Display.None) {
+    ///   // Skip painting the node
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = resolvedDisplay)]
+    pub fn resolved_display(&self, node: u64) -> Result<JsDisplay, JsValue> {
+        let mut current = NodeId::from(node);
+        self.ensure_node(current).map_err(to_js_error)?;
+        let display = self.tree.style(current).map_err(to_js_error)?.display;
+        while let Some(parent) = self.tree.parent(current) {
+            if self.tree.style(parent).map_err(to_js_error)?.display == Display::None {
+                return Ok(JsDisplay::None);
+            }
+            current = parent;
         }
+        Ok(display.into())
     }
 
-    /// Gets the unrounded (fractional) layout for a node
+    /// Gets the justify-content value a node's children are distributed with
     ///
-    /// Returns the raw computed values before any rounding is applied.
-    /// Useful when you need sub-pixel precision.
+    /// Returns the authored `justifyContent` when it is set. When it is unset,
+    /// returns the value Taffy behaves as: `JustifyContent.Start` for grid
+    /// containers and `JustifyContent.FlexStart` for everything else.
+    /// `justifyContent` has no effect on block containers, so `FlexStart`
+    /// there simply means "packed at the start".
     ///
     /// @param node - The node ID
     ///
-    /// @returns - The unrounded `Layout`
+    /// @returns - The effective `JustifyContent` value
+    ///
+    /// @throws `TaffyError` if the node does not exist
     ///
     /// @example
     /// ```typescript
     /// const tree = new TaffyTree();
-    /// const nodeId = tree.newLeaf(new Style());
-    /// const layout: Layout = tree.unroundedLayout(nodeId);
-    /// console.log(`Exact width: ${layout.width}`);
+    /// const node = tree.newLeaf(new Style());
+    /// tree.resolvedJustifyContent(node); // JustifyContent.FlexStart
     /// ```
-    #[wasm_bindgen(js_name = unroundedLayout)]
-    pub fn unrounded_layout(&self, node: u64) -> JsLayout {
-        JsLayout::from(self.tree.unrounded_layout(NodeId::from(node)))
+    #[wasm_bindgen(js_name = resolvedJustifyContent)]
+    pub fn resolved_justify_content(&self, node: u64) -> Result<JsJustifyContent, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        let justify_content = style.justify_content.unwrap_or(match style.display {
+            Display::Grid => JustifyContent::Start,
+            _ => JustifyContent::FlexStart,
+        });
+        Ok(justify_content.into())
     }
 
-    /// Gets detailed layout information for grid layouts
+    /// Gets the self-alignment a grid item is laid out with on both axes
     ///
-    /// @note
-    /// This method is only available when the `detailed_layout_info`
-    /// feature is enabled.
+    /// Resolves the item's `justifySelf` and `alignSelf` the way Taffy's grid
+    /// algorithm does: a value set on the item wins, then the grid
+    /// container's `justifyItems` / `alignItems`. When neither is set, an item
+    /// is stretched along an axis unless it has a definite size there, in
+    /// which case it is aligned to the start. A definite height, or an
+    /// `aspectRatio` with a definite width, also gives it a definite width,
+    /// and an `aspectRatio` alone keeps it from being stretched vertically.
     ///
     /// @param node - The node ID
     ///
-    /// @returns - Detailed grid info or "None" for non-grid nodes
+    /// @returns - The concrete `{ justifySelf, alignSelf }` (never
+    ///   `AlignSelf.Auto`), or `undefined` if the node is not a grid item (it has
+    ///   no grid parent, or is hidden)
     ///
     /// @throws `TaffyError` if the node does not exist
-    #[cfg(feature = "detailed_layout_info")]
-    #[wasm_bindgen(js_name = detailedLayoutInfo)]
-    pub fn detailed_layout_info(&self, node: u64) -> Result<JsValue, JsValue> {
-        match self.tree.detailed_layout_info(NodeId::from(node)) {
-            DetailedLayoutInfo::Grid(info) => {
-                let dto = DetailedGridInfoDto {
-                    rows: DetailedGridTracksInfoDto {
-                        negative_implicit_tracks: info.rows.negative_implicit_tracks,
-                        explicit_tracks: info.rows.explicit_tracks,
-                        positive_implicit_tracks: info.rows.positive_implicit_tracks,
-                        gutters: info.rows.gutters.clone(),
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const itemStyle = new Style();
+    /// itemStyle.size = { width: 50, height: "auto" };
+    /// const item = tree.newLeaf(itemStyle);
+    /// const gridStyle = new Style();
+    /// gridStyle.display = Display.Grid;
+    /// const grid = tree.newWithChildren(gridStyle, BigUint64Array.from([item]));
+    ///
+    /// tree.resolvedGridAlignment(item);
+    /// // { justifySelf: AlignSelf.Start, alignSelf: AlignSelf.Stretch }
+    /// ```
+    #[wasm_bindgen(js_name = resolvedGridAlignment)]
+    pub fn resolved_grid_alignment(
+        &self,
+        node: u64,
+    ) -> Result<Option<JsResolvedGridAlignment>, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        let Some(parent) = self.tree.parent(node) else {
+            return Ok(None);
+        };
+        let parent_style = self.tree.style(parent).map_err(to_js_error)?;
+        if parent_style.display != Display::Grid || style.display == Display::None {
+            return Ok(None);
+        }
+
+        let definite_width = !style.size.width.is_auto();
+        let definite_height = !style.size.height.is_auto();
+        let has_aspect_ratio = style.aspect_ratio.is_some();
+        let fallback = |definite: bool| {
+            if definite {
+                AlignSelf::Start
+            } else {
+                AlignSelf::Stretch
+            }
+        };
+        let justify_self = style
+            .justify_self
+            .or(parent_style.justify_items)
+            .unwrap_or_else(|| fallback(definite_width || (definite_height && has_aspect_ratio)));
+        let align_self = style
+            .align_self
+            .or(parent_style.align_items)
+            .unwrap_or_else(|| fallback(definite_height || has_aspect_ratio));
+
+        let dto = ResolvedGridAlignmentDto {
+            justify_self: JsAlignSelf::from(justify_self) as u8,
+            align_self: JsAlignSelf::from(align_self) as u8,
+        };
+        Ok(Some(serialize(&dto).unchecked_into()))
+    }
+
+    /// Gets the ancestor that establishes a node's stacking context
+    ///
+    /// Taffy has no `z-index`, and every node is `Position.Relative` by
+    /// default, so CSS's "positioned element" rule would make every parent a
+    /// stacking context. Instead, this uses a rule that fits how overlays are
+    /// built with Taffy: a node is painted in the stacking context of its
+    /// nearest `Position.Absolute` ancestor, or of the root of its tree if it
+    /// has none.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The ID of the ancestor establishing the stacking context, or
+    ///   `undefined` for a root node
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const overlayStyle = new Style();
+    /// overlayStyle.position = Position.Absolute;
+    /// const nodeId = tree.newLeaf(new Style());
+    /// const overlay = tree.newWithChildren(overlayStyle, BigUint64Array.from([nodeId]));
+    /// tree.newWithChildren(new Style(), BigUint64Array.from([overlay]));
+    ///
+    /// // Paint each overlay's contents in its own layer
+    /// const layer = tree.stackingContext(nodeId) ?? nodeId; // overlay
+    /// ```
+    #[wasm_bindgen(js_name = stackingContext)]
+    pub fn stacking_context(&self, node: u64) -> Result<Option<u64>, JsValue> {
+        let mut current = NodeId::from(node);
+        self.ensure_node(current).map_err(to_js_error)?;
+        let mut context = None;
+        while let Some(parent) = self.tree.parent(current) {
+            if self.tree.style(parent).map_err(to_js_error)?.position == Position::Absolute {
+                return Ok(Some(parent.into()));
+            }
+            context = Some(parent.into());
+            current = parent;
+        }
+        Ok(context)
+    }
+
+    // =========================================================================
+    // Layout Results
+    // =========================================================================
+
+    /// Gets the computed layout for a node
+    ///
+    /// Call this after `computeLayout()` to retrieve the computed position
+    /// and size for a node.
+    ///
+    /// The returned `Layout` is a copy: it does not change when the layout is
+    /// recomputed. To keep a snapshot on the tree itself, see `pinLayout()`.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The computed `Layout`
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.size = { width: 100, height: 100 };
+    /// const rootId = tree.newLeaf(style);
+    /// const nodeId = rootId;
+    ///
+    /// tree.computeLayout(rootId, { width: 800, height: 600 });
+    /// const layout: Layout = tree.getLayout(nodeId);
+    /// console.log(`Position: (${layout.x}, ${layout.y}), Size: ${layout.width}x${layout.height}`);
+    /// ```
+    #[wasm_bindgen(js_name = getLayout)]
+    pub fn layout(&self, node: u64) -> Result<JsLayout, JsValue> {
+        match self.tree.layout(NodeId::from(node)) {
+            Ok(l) => Ok(JsLayout::from(l)),
+            Err(e) => Err(JsValue::from(JsTaffyError::from(e))),
+        }
+    }
+
+    /// Gets the computed layout of a node, or `undefined` if it has none yet
+    ///
+    /// Unlike `getLayout()`, which returns an all-zero `Layout` for a node
+    /// that has never been laid out, this returns `undefined` in that case
+    /// (see `hasLayout()`), so the "not laid out yet" state cannot be mistaken
+    /// for an empty node at the origin. Once the node has been laid out, both
+    /// methods return the same layout, which may be stale if the tree changed
+    /// since the last layout run.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The computed `Layout`, or `undefined` before the first layout
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const node = tree.newLeaf(new Style());
+    /// tree.tryGetLayout(node); // undefined
+    ///
+    /// tree.computeLayout(node, { width: 800, height: 600 });
+    /// const layout = tree.tryGetLayout(node);
+    /// if (layout) {
+    ///   console.log(layout.width, layout.height);
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = tryGetLayout)]
+    pub fn try_get_layout(&self, node: u64) -> Result<Option<JsLayout>, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+        Ok(self
+            .state
+            .laid_out
+            .contains(&node)
+            .then(|| JsLayout::from(layout)))
+    }
+
+    /// Gets the computed layouts of many nodes as one flat array
+    ///
+    /// Reading thousands of layouts one `getLayout()` call at a time is
+    /// dominated by the cost of crossing into WASM and allocating a `Layout`
+    /// per node. This copies them all in a single call instead, as 12 numbers
+    /// per node, in the order the nodes were given:
+    ///
+    /// | Offset | Value |
+    /// |--------|-------|
+    /// | 0, 1 | `x`, `y` |
+    /// | 2, 3 | `width`, `height` |
+    /// | 4–7 | `borderLeft`, `borderRight`, `borderTop`, `borderBottom` |
+    /// | 8–11 | `paddingLeft`, `paddingRight`, `paddingTop`, `paddingBottom` |
+    ///
+    /// The values of the node at index `i` of `nodes` start at `i * 12`.
+    ///
+    /// @param nodes - The node IDs
+    ///
+    /// @returns - The layouts, 12 values per node
+    ///
+    /// @throws `TaffyError` if any of the nodes does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.size = { width: 50, height: 20 };
+    /// const nodes = BigUint64Array.from([tree.newLeaf(style), tree.newLeaf(style)]);
+    /// const root = tree.newWithChildren(new Style(), nodes);
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// const layouts = tree.getLayoutBatch(nodes);
+    /// for (let i = 0; i < nodes.length; i++) {
+    ///   const [x, y, width, height] = layouts.subarray(i * 12, i * 12 + 4);
+    ///   console.log(x, y, width, height); // 0 0 50 20, then 50 0 50 20
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = getLayoutBatch)]
+    pub fn get_layout_batch(&self, nodes: &[u64]) -> Result<Vec<f32>, JsValue> {
+        let mut values = Vec::with_capacity(nodes.len() * 12);
+        for &node in nodes.iter() {
+            let node = NodeId::from(node);
+            self.ensure_node(node).map_err(to_js_error)?;
+            let layout = self.tree.layout(node).map_err(to_js_error)?;
+            values.extend_from_slice(&[
+                layout.location.x,
+                layout.location.y,
+                layout.size.width,
+                layout.size.height,
+                layout.border.left,
+                layout.border.right,
+                layout.border.top,
+                layout.border.bottom,
+                layout.padding.left,
+                layout.padding.right,
+                layout.padding.top,
+                layout.padding.bottom,
+            ]);
+        }
+        Ok(values)
+    }
+
+    /// Checks whether a node has been laid out
+    ///
+    /// Before its first layout, `getLayout()` returns an all-zero `Layout`
+    /// that cannot be told apart from a genuinely empty node at the origin.
+    /// This returns `false` until a layout method (`computeLayout()` and its
+    /// variants) has run on the node or one of its ancestors, and `true` from
+    /// then on, even if the layout has since become stale because of later
+    /// changes. A node added to an already laid-out tree has no layout until
+    /// the next layout run.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - `true` if the node has a computed layout
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const node = tree.newLeaf(new Style());
+    /// tree.hasLayout(node); // false
+    ///
+    /// tree.computeLayout(node, { width: 800, height: 600 });
+    /// if (tree.hasLayout(node)) {
+    ///   console.log(tree.getLayout(node));
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = hasLayout)]
+    pub fn has_layout(&self, node: u64) -> Result<bool, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        Ok(self.state.laid_out.contains(&node))
+    }
+
+    /// Stores a snapshot of a node's current layout
+    ///
+    /// The snapshot survives later layout computations and can be read back
+    /// with `pinnedLayout()`. This is the "First" step of a FLIP animation:
+    /// pin the layouts, change the tree and recompute, then animate each node
+    /// from its pinned layout to its new one. Pinning a node again replaces
+    /// its snapshot; removing the node discards it.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const node = tree.newLeaf(new Style());
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([node]));
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// tree.pinLayout(node);
+    /// const expandedStyle = new Style();
+    /// expandedStyle.margin = { left: 100, right: 0, top: 50, bottom: 0 };
+    /// tree.setStyle(node, expandedStyle);
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// const first = tree.pinnedLayout(node)!;
+    /// const last = tree.getLayout(node);
+    /// // Start the node at its old position, then transition the offset to 0
+    /// const offset = { x: first.x - last.x, y: first.y - last.y }; // { x: -100, y: -50 }
+    /// ```
+    #[wasm_bindgen(js_name = pinLayout)]
+    pub fn pin_layout(&mut self, node: u64) -> Result<(), JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let layout = *self.tree.layout(node).map_err(to_js_error)?;
+        self.state.pinned_layouts.insert(node, layout);
+        Ok(())
+    }
+
+    /// Gets the layout snapshot stored with `pinLayout()`
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The pinned `Layout`, or `undefined` if the node has no snapshot
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const root = tree.newLeaf(new Style());
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// tree.pinLayout(root);
+    /// tree.computeLayout(root, { width: 400, height: 600 });
+    /// const before: Layout | undefined = tree.pinnedLayout(root);
+    /// ```
+    #[wasm_bindgen(js_name = pinnedLayout)]
+    pub fn pinned_layout(&self, node: u64) -> Option<JsLayout> {
+        self.state
+            .pinned_layouts
+            .get(&NodeId::from(node))
+            .map(JsLayout::from)
+    }
+
+    /// Gets the computed box model for a node
+    ///
+    /// Returns the margin, border and padding widths together with the
+    /// resulting content box in a single call, built from the node's `Layout`.
+    /// The content box is positioned relative to the node's parent, like
+    /// `Layout.x` / `Layout.y`.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The node's `BoxModel`
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.size = { width: 100, height: 50 };
+    /// style.border = { left: 1, right: 1, top: 1, bottom: 1 };
+    /// style.padding = { left: 10, right: 10, top: 10, bottom: 10 };
+    /// const nodeId = tree.newLeaf(style);
+    /// tree.computeLayout(nodeId, { width: 800, height: 600 });
+    ///
+    /// const box = tree.boxModel(nodeId);
+    /// console.log(box.border.top, box.padding.top); // 1, 10
+    /// console.log(box.content); // { x: 11, y: 11, width: 78, height: 28 }
+    /// ```
+    #[wasm_bindgen(js_name = boxModel)]
+    pub fn box_model(&self, node: u64) -> Result<JsBoxModel, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+        Ok(serialize(&BoxModelDto::from(layout)).unchecked_into())
+    }
+
+    /// Gets a node's computed padding in pixels
+    ///
+    /// Returns the four `Layout.padding*` values in one object, with
+    /// percentages already resolved by the last layout.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The padding as `{ left, right, top, bottom }`
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.size = { width: 200, height: 100 };
+    /// style.padding = { left: "10%", right: "10%", top: 8, bottom: 8 };
+    /// const nodeId = tree.newLeaf(style);
+    /// tree.computeLayout(nodeId, { width: 800, height: 600 });
+    ///
+    /// const { left, top } = tree.resolvedPadding(nodeId); // 80, 8
+    /// ```
+    #[wasm_bindgen(js_name = resolvedPadding)]
+    pub fn resolved_padding(&self, node: u64) -> Result<JsRectNumber, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+        Ok(serialize(&RectDto::<f32>::from(layout.padding)).unchecked_into())
+    }
+
+    /// Gets a node's computed border widths in pixels
+    ///
+    /// Returns the four `Layout.border*` values in one object, with
+    /// percentages already resolved by the last layout.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The border widths as `{ left, right, top, bottom }`
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.border = { left: 2, right: 2, top: 1, bottom: 1 };
+    /// const nodeId = tree.newLeaf(style);
+    /// tree.computeLayout(nodeId, { width: 800, height: 600 });
+    ///
+    /// const { left, top } = tree.resolvedBorder(nodeId); // 2, 1
+    /// ```
+    #[wasm_bindgen(js_name = resolvedBorder)]
+    pub fn resolved_border(&self, node: u64) -> Result<JsRectNumber, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+        Ok(serialize(&RectDto::<f32>::from(layout.border)).unchecked_into())
+    }
+
+    /// Gets a node's computed margins in pixels
+    ///
+    /// Returns the four `Layout.margin*` values in one object, with
+    /// percentages resolved and `auto` margins replaced by the space they
+    /// took up (e.g. the centering offset of a `margin: auto` flex item).
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The margins as `{ left, right, top, bottom }`
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.size = { width: 200, height: 100 };
+    /// style.margin = { left: "auto", right: "auto", top: 0, bottom: 0 };
+    /// const centeredNode = tree.newLeaf(style);
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([centeredNode]));
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// const { left, right } = tree.resolvedMargin(centeredNode); // 300, 300
+    /// ```
+    #[wasm_bindgen(js_name = resolvedMargin)]
+    pub fn resolved_margin(&self, node: u64) -> Result<JsRectNumber, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+        Ok(serialize(&RectDto::<f32>::from(layout.margin)).unchecked_into())
+    }
+
+    /// Checks whether a node's `auto` margins took up free space
+    ///
+    /// An axis reports `true` when one of its margins is authored as `auto`
+    /// and the last layout resolved it to a non-zero size, i.e. the margin
+    /// is what centered the node or pushed it aside. `auto` margins that
+    /// found no free space to absorb report `false`.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - `{ horizontal, vertical }` flags for the two axes
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.size = { width: 200, height: 100 };
+    /// style.margin = { left: "auto", right: "auto", top: 0, bottom: 0 };
+    /// const node = tree.newLeaf(style);
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([node]));
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// if (tree.autoMarginApplied(node).horizontal) {
+    ///   console.log("centered by margin: auto", tree.resolvedMargin(node));
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = autoMarginApplied)]
+    pub fn auto_margin_applied(&self, node: u64) -> Result<JsAutoMarginApplied, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let authored = self.tree.style(node).map_err(to_js_error)?.margin;
+        let resolved = self.tree.layout(node).map_err(to_js_error)?.margin;
+        let applied = |margin: LengthPercentageAuto, size: f32| margin.is_auto() && size != 0.0;
+        let dto = AutoMarginAppliedDto {
+            horizontal: applied(authored.left, resolved.left)
+                || applied(authored.right, resolved.right),
+            vertical: applied(authored.top, resolved.top)
+                || applied(authored.bottom, resolved.bottom),
+        };
+        Ok(serialize(&dto).unchecked_into())
+    }
+
+    /// Checks on which axes a node's content overflows it
+    ///
+    /// An axis overflows when the computed content size exceeds the node's
+    /// size on that axis (`contentWidth > width`, `contentHeight > height`).
+    /// Useful for deciding whether to show scroll affordances.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - A `Point<boolean>` with the overflow state of each axis
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.size = { width: 100, height: 100 };
+    /// const nodeId = tree.newLeaf(style);
+    /// tree.computeLayout(nodeId, { width: 800, height: 600 });
+    ///
+    /// const overflow = tree.overflowAxes(nodeId);
+    /// if (overflow.y) {
+    ///   console.log("Show a vertical scrollbar");
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = overflowAxes)]
+    pub fn overflow_axes(&self, node: u64) -> Result<JsPointBool, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+        let axes = Point {
+            x: layout.content_size.width > layout.size.width,
+            y: layout.content_size.height > layout.size.height,
+        };
+        Ok(serialize(&axes).unchecked_into())
+    }
+
+    /// Gets the scrollable extent of a scroll container
+    ///
+    /// Returns the equivalent of the DOM's `scrollWidth` and `scrollHeight`:
+    /// the size of the content measured from the padding edge, including
+    /// padding, and never smaller than the visible area (the padding box
+    /// minus any scrollbar). The scrollable distance on an axis is the
+    /// returned value minus that visible size, which is what a custom
+    /// scrollbar needs to size and position its thumb.
+    ///
+    /// Only nodes whose `overflow` is `Hidden` or `Scroll` on at least one
+    /// axis are scroll containers. All other nodes return zero for both
+    /// dimensions.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The scroll width and height in pixels
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const itemStyle = new Style();
+    /// itemStyle.size = { width: 100, height: 50 };
+    /// itemStyle.flexShrink = 0;
+    /// const items = Array.from({ length: 10 }, () => tree.newLeaf(itemStyle));
+    /// const listStyle = new Style();
+    /// listStyle.flexDirection = FlexDirection.Column;
+    /// listStyle.size = { width: 100, height: 200 };
+    /// listStyle.overflow = { x: Overflow.Visible, y: Overflow.Scroll };
+    /// const list = tree.newWithChildren(listStyle, BigUint64Array.from(items));
+    /// tree.computeLayout(list, { width: 800, height: 600 });
+    ///
+    /// const bounds = tree.scrollBounds(list); // { width: 100, height: 500 }
+    /// const layout = tree.getLayout(list);
+    /// const visible = layout.height - layout.borderTop - layout.borderBottom;
+    /// const thumbHeight = `${(visible / bounds.height) * 100}%`; // "40%"
+    /// ```
+    #[wasm_bindgen(js_name = scrollBounds)]
+    pub fn scroll_bounds(&self, node: u64) -> Result<JsSizeNumber, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        let is_scroll_container = [style.overflow.x, style.overflow.y].iter().any(|overflow| {
+            matches!(
+                overflow,
+                TaffyStyle::Overflow::Hidden | TaffyStyle::Overflow::Scroll
+            )
+        });
+        if !is_scroll_container {
+            return Ok(serialize(&Size::<f32>::ZERO).unchecked_into());
+        }
+
+        // Taffy measures the content size from the border edge
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+        let visible = Size {
+            width: layout.size.width
+                - layout.border.left
+                - layout.border.right
+                - layout.scrollbar_size.width,
+            height: layout.size.height
+                - layout.border.top
+                - layout.border.bottom
+                - layout.scrollbar_size.height,
+        };
+        let bounds = Size {
+            width: (layout.content_size.width - layout.border.left).max(visible.width),
+            height: (layout.content_size.height - layout.border.top).max(visible.height),
+        };
+        Ok(serialize(&bounds).unchecked_into())
+    }
+
+    /// Checks which of a node's dimensions are determined by its content
+    ///
+    /// A dimension is content-sized when nothing but the node's content fixes
+    /// it, so it changes whenever the content does. This is derived from the
+    /// styles of the node and its parent, following these rules:
+    ///
+    /// - A dimension with an explicit `size` (length or percentage) is not
+    ///   content-sized; with an `aspectRatio`, one explicit dimension fixes both.
+    /// - A flex item's main size is not content-sized if it has a `flexGrow`
+    ///   above zero or a non-`auto` `flexBasis`, and its cross size is not
+    ///   content-sized if it is stretched (`alignSelf`/`alignItems` is
+    ///   `Stretch`, the default, and neither cross-axis margin is `auto`).
+    /// - A grid item's width and height are not content-sized if it is
+    ///   stretched on that axis by `justifySelf`/`justifyItems` and
+    ///   `alignSelf`/`alignItems` respectively (`Stretch` is the default).
+    /// - A block item's width is not content-sized, as it fills its container.
+    /// - An absolutely positioned node's width (height) is not content-sized
+    ///   if both its `left` and `right` (`top` and `bottom`) insets are set.
+    ///
+    /// Shrinking is not taken into account: a content-sized flex item can
+    /// still be shrunk below its content size when its line overflows.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - A `Size<boolean>` that is `true` for each content-sized dimension
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const inputNode = tree.newLeaf(new Style());
+    /// tree.newWithChildren(new Style(), BigUint64Array.from([inputNode]));
+    ///
+    /// const sized = tree.isContentSized(inputNode); // { width: true, height: false }
+    /// if (sized.width || sized.height) {
+    ///   // Re-measure the input whenever its text changes
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = isContentSized)]
+    pub fn is_content_sized(&self, node: u64) -> Result<JsSizeBool, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        let auto = Size {
+            width: style.size.width.is_auto(),
+            height: style.size.height.is_auto(),
+        };
+        let mut sized = match style.aspect_ratio {
+            Some(_) => Size {
+                width: auto.width && auto.height,
+                height: auto.width && auto.height,
+            },
+            None => auto,
+        };
+
+        if let Some(parent) = self.tree.parent(node) {
+            let parent_style = self.tree.style(parent).map_err(to_js_error)?;
+            let margin = style.margin;
+            if style.position == Position::Absolute {
+                sized.width &= style.inset.left.is_auto() || style.inset.right.is_auto();
+                sized.height &= style.inset.top.is_auto() || style.inset.bottom.is_auto();
+            } else if parent_style.display == Display::Flex {
+                let is_row = matches!(
+                    parent_style.flex_direction,
+                    FlexDirection::Row | FlexDirection::RowReverse
+                );
+                let main_forced = style.flex_grow > 0.0 || !style.flex_basis.is_auto();
+                let align = style.align_self.or(parent_style.align_items);
+                let cross_margin_auto = if is_row {
+                    margin.top.is_auto() || margin.bottom.is_auto()
+                } else {
+                    margin.left.is_auto() || margin.right.is_auto()
+                };
+                let cross_forced = align.unwrap_or(AlignItems::Stretch) == AlignItems::Stretch
+                    && !cross_margin_auto;
+                let (main, cross) = if is_row {
+                    (&mut sized.width, &mut sized.height)
+                } else {
+                    (&mut sized.height, &mut sized.width)
+                };
+                *main &= !main_forced;
+                *cross &= !cross_forced;
+            } else if parent_style.display == Display::Grid {
+                let justify = style.justify_self.or(parent_style.justify_items);
+                let align = style.align_self.or(parent_style.align_items);
+                sized.width &= justify.unwrap_or(AlignItems::Stretch) != AlignItems::Stretch
+                    || margin.left.is_auto()
+                    || margin.right.is_auto();
+                sized.height &= align.unwrap_or(AlignItems::Stretch) != AlignItems::Stretch
+                    || margin.top.is_auto()
+                    || margin.bottom.is_auto();
+            } else if parent_style.display == Display::Block {
+                sized.width = false;
+            }
+        }
+        Ok(serialize(&sized).unchecked_into())
+    }
+
+    /// Computes a node's preferred size from one known dimension
+    ///
+    /// Applies only the node's `aspectRatio` (width / height): given one
+    /// dimension, the other is derived from it. Without an aspect ratio, a
+    /// missing dimension falls back to the node's content size from the last
+    /// layout. Known dimensions are returned as-is, so passing both simply
+    /// echoes them. No other style (min/max size, box sizing) is applied,
+    /// which makes this suitable for custom measure functions and for sizing
+    /// nodes before they are laid out.
+    ///
+    /// @param node - The node ID
+    /// @param knownWidth - The width, if known
+    /// @param knownHeight - The height, if known
+    ///
+    /// @returns - The preferred `{ width, height }`
+    ///
+    /// @throws `TaffyError` if the node does not exist, or if a dimension can
+    /// be derived neither from the aspect ratio nor from a content size
+    /// (e.g. no dimension is known and the node has never been laid out)
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.aspectRatio = 16 / 9;
+    /// const video = tree.newLeaf(style);
+    /// tree.aspectSizedFor(video, 320); // { width: 320, height: 180 }
+    /// tree.aspectSizedFor(video, undefined, 90); // { width: 160, height: 90 }
+    /// ```
+    #[wasm_bindgen(js_name = aspectSizedFor)]
+    pub fn aspect_sized_for(
+        &self,
+        node: u64,
+        #[wasm_bindgen(js_name = "knownWidth")] known_width: Option<f32>,
+        #[wasm_bindgen(js_name = "knownHeight")] known_height: Option<f32>,
+    ) -> Result<JsSizeNumber, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let ratio = self.tree.style(node).map_err(to_js_error)?.aspect_ratio;
+        let content = match self.state.laid_out.contains(&node) {
+            true => Some(self.tree.layout(node).map_err(to_js_error)?.content_size),
+            false => None,
+        };
+        let width = match (known_width, ratio) {
+            (Some(width), _) => Some(width),
+            (None, Some(ratio)) => known_height.map(|height| height * ratio),
+            (None, None) => None,
+        };
+        let height = match (known_height, ratio) {
+            (Some(height), _) => Some(height),
+            (None, Some(ratio)) => known_width.map(|width| width / ratio),
+            (None, None) => None,
+        };
+        let size = match (
+            width.or(content.map(|c| c.width)),
+            height.or(content.map(|c| c.height)),
+        ) {
+            (Some(width), Some(height)) => Size { width, height },
+            _ => {
+                let error = JsTaffyError::from(ErrorKind::UnresolvableSize(node));
+                return Err(JsValue::from(error));
+            }
+        };
+        Ok(serialize(&size).unchecked_into())
+    }
+
+    /// Gets a container's gap resolved to pixels
+    ///
+    /// The `gap` style getter returns the authored value (e.g. `"10%"`); this
+    /// returns the pixel value used by the last layout. Percentages resolve
+    /// against the container's computed content box: `gap.width` against its
+    /// width (the `column` gap) and `gap.height` against its height (the
+    /// `row` gap).
+    ///
+    /// Gaps only apply to flex and grid containers; other nodes report zeros.
+    ///
+    /// @param node - The container node ID
+    ///
+    /// @returns - The resolved `{ row, column }` gap in pixels
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const style = new Style();
+    /// style.display = Display.Grid;
+    /// style.size = { width: 200, height: 400 };
+    /// style.gap = { width: "5%", height: "10%" };
+    /// const grid = tree.newLeaf(style);
+    /// tree.computeLayout(grid, { width: 800, height: 600 });
+    ///
+    /// const gap = tree.resolvedGap(grid); // { row: 40, column: 10 }
+    /// ```
+    #[wasm_bindgen(js_name = resolvedGap)]
+    pub fn resolved_gap(&self, node: u64) -> Result<JsResolvedGap, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+
+        let dto = match style.display {
+            Display::Flex | Display::Grid => ResolvedGapDto {
+                row: style
+                    .gap
+                    .height
+                    .resolve_or_zero(Some(layout.content_box_height()), |_, _| 0.0),
+                column: style
+                    .gap
+                    .width
+                    .resolve_or_zero(Some(layout.content_box_width()), |_, _| 0.0),
+            },
+            _ => ResolvedGapDto::default(),
+        };
+        Ok(serialize(&dto).unchecked_into())
+    }
+
+    /// Gets the flex basis of a flex item in pixels
+    ///
+    /// Taffy does not expose the flex base size it computes internally, so this
+    /// re-derives it from the item's style and the parent's last computed
+    /// layout, following the same steps:
+    ///
+    /// 1. A definite `flexBasis`, resolving percentages against the parent's
+    ///    content box main size
+    /// 2. Otherwise a definite main-axis `size` (or one implied by
+    ///    `aspectRatio` and a definite cross size)
+    /// 3. Otherwise the item's max-content main size (see `maxContentSize()`)
+    ///
+    /// With `BoxSizing.ContentBox`, padding and border are added to definite
+    /// values. The result is floored at the item's main-axis padding and
+    /// border, and is the size *before* growing, shrinking and min/max
+    /// clamping.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The flex basis in pixels, or `null` if the node is not a flex
+    ///   item (it has no flex parent, or is absolutely positioned or hidden)
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const itemStyle = new Style();
+    /// itemStyle.flexBasis = "25%";
+    /// itemStyle.flexGrow = 1;
+    /// const item = tree.newLeaf(itemStyle);
+    /// const rootStyle = new Style();
+    /// rootStyle.size = { width: 800, height: 100 };
+    /// const root = tree.newWithChildren(rootStyle, BigUint64Array.from([item]));
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// tree.resolvedFlexBasis(item); // 200, grown to 800 by flexGrow
+    /// ```
+    #[wasm_bindgen(js_name = resolvedFlexBasis)]
+    pub fn resolved_flex_basis(&self, node: u64) -> Result<Option<f32>, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        let Some(parent) = self.tree.parent(node) else {
+            return Ok(None);
+        };
+        let parent_style = self.tree.style(parent).map_err(to_js_error)?;
+        if parent_style.display != Display::Flex
+            || style.position == Position::Absolute
+            || style.display == Display::None
+        {
+            return Ok(None);
+        }
+
+        let is_row = matches!(
+            parent_style.flex_direction,
+            FlexDirection::Row | FlexDirection::RowReverse
+        );
+        let main = |size: Size<f32>| if is_row { size.width } else { size.height };
+        let parent_layout = self.tree.layout(parent).map_err(to_js_error)?;
+        let container = Size {
+            width: parent_layout.content_box_width(),
+            height: parent_layout.content_box_height(),
+        };
+
+        let calc = |_, _| 0.0;
+        let edges = style.padding.resolve_or_zero(Some(container.width), calc)
+            + style.border.resolve_or_zero(Some(container.width), calc);
+        let padding_border = if is_row {
+            edges.left + edges.right
+        } else {
+            edges.top + edges.bottom
+        };
+        let adjustment = match style.box_sizing {
+            BoxSizing::ContentBox => padding_border,
+            BoxSizing::BorderBox => 0.0,
+        };
+
+        let size = Size {
+            width: style.size.width.maybe_resolve(Some(container.width), calc),
+            height: style
+                .size
+                .height
+                .maybe_resolve(Some(container.height), calc),
+        }
+        .map(|size| size.map(|size| size + adjustment));
+        let (main_size, cross_size) = if is_row {
+            (size.width, size.height)
+        } else {
+            (size.height, size.width)
+        };
+        let main_size = main_size.or_else(|| {
+            let ratio = style.aspect_ratio?;
+            cross_size.map(|cross| if is_row { cross * ratio } else { cross / ratio })
+        });
+
+        let basis = match style
+            .flex_basis
+            .maybe_resolve(Some(main(container)), calc)
+            .map(|basis| basis + adjustment)
+            .or(main_size)
+        {
+            Some(basis) => basis,
+            None => main(self.detached_size(node, AvailableSpace::MaxContent)?),
+        };
+        Ok(Some(basis.max(padding_border)))
+    }
+
+    /// Checks whether a flex container's items overflow its main axis
+    ///
+    /// Compares the items' computed sizes (after shrinking) with the
+    /// container's content box. Without wrapping, the container overflows when
+    /// the items' outer main sizes plus the gaps between them exceed it; with
+    /// `flexWrap`, items move to new lines instead, so it only overflows when
+    /// a single item is wider than a line. Absolutely positioned and hidden
+    /// children are not flex items and are ignored.
+    ///
+    /// Uses the last computed layout. Nodes that are not flex containers never
+    /// report overflow.
+    ///
+    /// @param node - The container node ID
+    ///
+    /// @returns - `true` if the items exceed the container's main size
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const buttonStyle = new Style();
+    /// buttonStyle.size = { width: 100, height: 32 };
+    /// buttonStyle.flexShrink = 0;
+    /// const buttons = [1, 2, 3, 4].map(() => tree.newLeaf(buttonStyle));
+    /// const toolbar = tree.newWithChildren(new Style(), BigUint64Array.from(buttons));
+    ///
+    /// tree.computeLayout(toolbar, { width: 320, height: 48 });
+    /// if (tree.mainAxisOverflow(toolbar)) {
+    ///   // Show a "more" button for the buttons that do not fit
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = mainAxisOverflow)]
+    pub fn main_axis_overflow(&self, node: u64) -> Result<bool, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        if style.display != Display::Flex {
+            return Ok(false);
+        }
+        let is_row = matches!(
+            style.flex_direction,
+            FlexDirection::Row | FlexDirection::RowReverse
+        );
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+        let (available, gap) = if is_row {
+            let width = layout.content_box_width();
+            (
+                width,
+                style.gap.width.resolve_or_zero(Some(width), |_, _| 0.0),
+            )
+        } else {
+            let height = layout.content_box_height();
+            (
+                height,
+                style.gap.height.resolve_or_zero(Some(height), |_, _| 0.0),
+            )
+        };
+
+        let mut sizes = Vec::new();
+        for child in self.tree.children(node).map_err(to_js_error)? {
+            let child_style = self.tree.style(child).map_err(to_js_error)?;
+            if child_style.position == Position::Absolute || child_style.display == Display::None {
+                continue;
+            }
+            let child_layout = self.tree.layout(child).map_err(to_js_error)?;
+            sizes.push(if is_row {
+                child_layout.size.width + child_layout.margin.left + child_layout.margin.right
+            } else {
+                child_layout.size.height + child_layout.margin.top + child_layout.margin.bottom
+            });
+        }
+
+        // Allow for floating point error in the summed sizes
+        let available = available + 0.01;
+        Ok(match style.flex_wrap {
+            FlexWrap::NoWrap => {
+                let gaps = gap * sizes.len().saturating_sub(1) as f32;
+                sizes.iter().sum::<f32>() + gaps > available
+            }
+            FlexWrap::Wrap | FlexWrap::WrapReverse => sizes.iter().any(|&size| size > available),
+        })
+    }
+
+    /// Gets a node's layout as inline CSS for absolute positioning
+    ///
+    /// Returns `position`, `left`, `top`, `width` and `height` declarations
+    /// with `left`/`top` in absolute coordinates (relative to the root of the
+    /// node's tree rather than its parent), ready to be assigned to a DOM
+    /// element's `style.cssText`.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - A CSS declaration string
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const rootStyle = new Style();
+    /// rootStyle.padding = { left: 10, right: 10, top: 10, bottom: 10 };
+    /// const childStyle = new Style();
+    /// childStyle.size = { width: 100, height: 50 };
+    /// const child = tree.newLeaf(childStyle);
+    /// const root = tree.newWithChildren(rootStyle, BigUint64Array.from([child]));
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// const css: string = tree.layoutToCssPosition(child);
+    /// // "position: absolute; left: 10px; top: 10px; width: 100px; height: 50px;"
+    /// ```
+    #[wasm_bindgen(js_name = layoutToCssPosition)]
+    pub fn layout_to_css_position(&self, node: u64) -> Result<String, JsValue> {
+        let node = NodeId::from(node);
+        let location = self.absolute_location(node).map_err(to_js_error)?;
+        let size = self.tree.layout(node).map_err(to_js_error)?.size;
+        Ok(format!(
+            "position: absolute; left: {}px; top: {}px; width: {}px; height: {}px;",
+            location.x, location.y, size.width, size.height
+        ))
+    }
+
+    /// Gets the border boxes of a subtree as DOMRect-like objects
+    ///
+    /// Returns one `{ nodeId, x, y, width, height, top, right, bottom, left }`
+    /// object per node, the shape many web APIs and libraries expect from a
+    /// `DOMRect`. Coordinates are absolute (relative to the root of the node's
+    /// tree, not to `root`).
+    ///
+    /// The array is in depth-first pre-order: `root` first, then each child
+    /// followed by its own descendants, in child order.
+    ///
+    /// @param root - The root node ID of the subtree
+    ///
+    /// @returns - An array of `NodeDomRect` objects
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const childStyle = new Style();
+    /// childStyle.size = { width: 100, height: 50 };
+    /// const child = tree.newLeaf(childStyle);
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([child]));
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// for (const rect of tree.domRects(root)) {
+    ///   console.log(rect.nodeId, rect.left, rect.top, rect.right, rect.bottom);
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = domRects)]
+    pub fn dom_rects(&self, root: u64) -> Result<JsNodeDomRects, JsValue> {
+        let root = NodeId::from(root);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let parent_origin = match self.tree.parent(root) {
+            Some(parent) => self.absolute_location(parent).map_err(to_js_error)?,
+            None => Point::ZERO,
+        };
+
+        let mut rects = Vec::new();
+        let mut stack = vec![(root, parent_origin)];
+        while let Some((node, parent_origin)) = stack.pop() {
+            let layout = self.tree.layout(node).map_err(to_js_error)?;
+            let location = Point {
+                x: parent_origin.x + layout.location.x,
+                y: parent_origin.y + layout.location.y,
+            };
+            rects.push(DomRectDto::new(node.into(), location, layout.size));
+            let children = self.tree.children(node).map_err(to_js_error)?;
+            stack.extend(children.into_iter().rev().map(|c| (c, location)));
+        }
+        Ok(serialize_with_node_ids(&rects).unchecked_into())
+    }
+
+    /// Writes the computed layout of a subtree to DOM elements
+    ///
+    /// Walks `root` and its descendants once and, for every node that has an
+    /// entry in `elements`, sets the element's `style.width`, `style.height`
+    /// and `style.transform`. Nodes without an element are skipped, but their
+    /// descendants are still visited.
+    ///
+    /// Positions are applied as `translate(x, y)` transforms in the coordinate
+    /// space of the root of the node's tree (as in `domRects()`), which avoids
+    /// triggering browser layout. The elements are therefore expected to be
+    /// siblings inside one container that sits where the tree's root is, each
+    /// with `position: absolute; left: 0; top: 0` and
+    /// `box-sizing: border-box`. This method does not set those properties,
+    /// so set them once when creating the elements.
+    ///
+    /// The DOM is accessed through plain property assignments rather than
+    /// `web-sys`, so any object with a `style` object works, which keeps the
+    /// method usable in tests and with virtual DOMs.
+    ///
+    /// @note
+    /// This method is only available when the `dom` feature is enabled. It is
+    /// off by default for Rust users, and on in the npm package.
+    ///
+    /// @param root - The root node ID of the subtree
+    /// @param elements - A map from node IDs to the elements to update
+    ///
+    /// @returns - The number of elements updated
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const labelStyle = new Style();
+    /// labelStyle.size = { width: 100, height: 20 };
+    /// const label = tree.newLeaf(labelStyle);
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([label]));
+    ///
+    /// // In a browser this is `document.createElement("div")`, styled with
+    /// // "position: absolute; left: 0; top: 0; box-sizing: border-box"
+    /// const element = { style: {} } as HTMLElement;
+    /// const elements = new Map([[label, element]]);
+    ///
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    /// tree.applyToDom(root, elements); // 1
+    /// console.log(element.style.width, element.style.transform); // "100px" "translate(0px, 0px)"
+    /// ```
+    #[cfg(feature = "dom")]
+    #[wasm_bindgen(js_name = applyToDom)]
+    pub fn apply_to_dom(&self, root: u64, elements: JsDomElementMap) -> Result<u32, JsValue> {
+        let root = NodeId::from(root);
+        let elements: js_sys::Map = elements.unchecked_into();
+        self.ensure_node(root).map_err(to_js_error)?;
+        let parent_origin = match self.tree.parent(root) {
+            Some(parent) => self.absolute_location(parent).map_err(to_js_error)?,
+            None => Point::ZERO,
+        };
+
+        let mut applied = 0;
+        let mut stack = vec![(root, parent_origin)];
+        while let Some((node, parent_origin)) = stack.pop() {
+            let layout = self.tree.layout(node).map_err(to_js_error)?;
+            let location = Point {
+                x: parent_origin.x + layout.location.x,
+                y: parent_origin.y + layout.location.y,
+            };
+            let element = elements.get(&JsValue::from(u64::from(node)));
+            if !element.is_undefined() {
+                let style = js_sys::Reflect::get(&element, &"style".into())?;
+                let width = format!("{}px", layout.size.width);
+                let height = format!("{}px", layout.size.height);
+                let transform = format!("translate({}px, {}px)", location.x, location.y);
+                js_sys::Reflect::set(&style, &"width".into(), &width.into())?;
+                js_sys::Reflect::set(&style, &"height".into(), &height.into())?;
+                js_sys::Reflect::set(&style, &"transform".into(), &transform.into())?;
+                applied += 1;
+            }
+            let children = self.tree.children(node).map_err(to_js_error)?;
+            stack.extend(children.into_iter().rev().map(|c| (c, location)));
+        }
+        Ok(applied)
+    }
+
+    /// Gets a subtree as nested plain objects combining style and layout
+    ///
+    /// Returns `{ id, style, layout, children }` for `root`, with `children`
+    /// holding the same structure for each child, in order. `style` is a
+    /// plain object of the properties that differ from their defaults, in the
+    /// shape `setStyleProperty()` and `buildTree()` accept. `layout` holds the
+    /// node's `x`, `y`, `width` and `height`, with `x`/`y` relative to its
+    /// parent as in `getLayout()`.
+    ///
+    /// This copies the whole subtree into JavaScript objects, so prefer
+    /// `getLayout()` or `domRects()` in hot paths. It is meant for declarative
+    /// renderers and test snapshots, and should be called after
+    /// `computeLayout()`.
+    ///
+    /// @param root - The root node ID of the subtree
+    ///
+    /// @returns - A `NestedNode` for `root`
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const root = tree.buildTree({
+    ///   style: { padding: { left: 10, right: 10, top: 10, bottom: 10 } },
+    ///   children: [{ style: { size: { width: 100, height: 50 } } }],
+    /// });
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// const snapshot = tree.toNestedObject(root);
+    /// console.log(snapshot.children[0].layout); // { x: 10, y: 10, width: 100, height: 50 }
+    /// ```
+    #[wasm_bindgen(js_name = toNestedObject)]
+    pub fn to_nested_object(&self, root: u64) -> Result<JsNestedNode, JsValue> {
+        let root = NodeId::from(root);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let object = self.nested_object(root)?;
+        Ok(JsValue::from(object).unchecked_into())
+    }
+
+    /// Gets a node's layout in the coordinate space of one of its ancestors
+    ///
+    /// The returned layout is identical to `getLayout(node)` except that `x` and
+    /// `y` are relative to `ancestor` rather than to the node's parent: they are
+    /// the sum of the offsets from `node` up to, but not including, `ancestor`.
+    /// This is useful for rendering a subtree into its own layer or canvas.
+    ///
+    /// @param node - The node ID
+    /// @param ancestor - The node ID of an ancestor of `node`
+    ///
+    /// @returns - The node's `Layout` with `x`/`y` relative to `ancestor`
+    ///
+    /// @throws `TaffyError` if either node does not exist, or if `ancestor` is
+    /// not an ancestor of `node`
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const paddedStyle = new Style();
+    /// paddedStyle.padding = { left: 10, right: 10, top: 10, bottom: 10 };
+    /// const leaf = tree.newLeaf(new Style());
+    /// const layer = tree.newWithChildren(paddedStyle, BigUint64Array.from([leaf]));
+    /// const root = tree.newWithChildren(paddedStyle, BigUint64Array.from([layer]));
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// const inLayer = tree.layoutRelativeTo(leaf, layer); // x: 10, y: 10
+    /// const inRoot = tree.layoutRelativeTo(leaf, root); // x: 20, y: 20
+    /// ```
+    #[wasm_bindgen(js_name = layoutRelativeTo)]
+    pub fn layout_relative_to(&self, node: u64, ancestor: u64) -> Result<JsLayout, JsValue> {
+        let node = NodeId::from(node);
+        let ancestor = NodeId::from(ancestor);
+        self.ensure_node(node).map_err(to_js_error)?;
+        self.ensure_node(ancestor).map_err(to_js_error)?;
+
+        let mut layout = *self.tree.layout(node).map_err(to_js_error)?;
+        let mut current = self.tree.parent(node);
+        loop {
+            match current {
+                Some(id) if id == ancestor => return Ok(JsLayout::from(layout)),
+                Some(id) => {
+                    let offset = self.tree.layout(id).map_err(to_js_error)?.location;
+                    layout.location.x += offset.x;
+                    layout.location.y += offset.y;
+                    current = self.tree.parent(id);
+                }
+                None => {
+                    let error = JsTaffyError::from(ErrorKind::NotAnAncestor { node, ancestor });
+                    return Err(JsValue::from(error));
+                }
+            }
+        }
+    }
+
+    /// Gets a node's layout in viewport coordinates, accounting for scrolling
+    ///
+    /// Taffy does not track scroll positions, so `x` and `y` from
+    /// `getLayout()` describe the unscrolled page. This method walks from the
+    /// node up to the root, summing offsets like `layoutToCssPosition()`, and
+    /// subtracts the scroll position of every ancestor listed in
+    /// `scrollOffsets`. The node's own scroll position is ignored, since
+    /// scrolling a container moves its content, not the container itself.
+    ///
+    /// All other fields are identical to `getLayout(node)`.
+    ///
+    /// @param node - The node ID
+    /// @param scrollOffsets - A map from scroll container node IDs to their
+    /// current `{ x, y }` scroll position. Entries that are not valid points
+    /// are ignored; pass `undefined` for no scrolling.
+    ///
+    /// @returns - The node's `Layout` with `x`/`y` in viewport coordinates
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const itemStyle = new Style();
+    /// itemStyle.size = { width: 100, height: 50 };
+    /// itemStyle.margin = { left: 0, right: 0, top: 200, bottom: 0 };
+    /// const item = tree.newLeaf(itemStyle);
+    /// const listStyle = new Style();
+    /// listStyle.overflow = { x: Overflow.Visible, y: Overflow.Scroll };
+    /// const list = tree.newWithChildren(listStyle, BigUint64Array.from([item]));
+    /// tree.computeLayout(list, { width: 800, height: 600 });
+    ///
+    /// // The list's content is scrolled up by 120px
+    /// const scroll = new Map([[list, { x: 0, y: 120 }]]);
+    /// const onScreen: Layout = tree.viewportLayout(item, scroll); // y: 80
+    /// ```
+    #[wasm_bindgen(js_name = viewportLayout)]
+    pub fn viewport_layout(
+        &self,
+        node: u64,
+        scroll_offsets: JsScrollOffsets,
+    ) -> Result<JsLayout, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let offsets = scroll_offsets.dyn_ref::<js_sys::Map>();
+
+        let mut layout = *self.tree.layout(node).map_err(to_js_error)?;
+        let mut current = self.tree.parent(node);
+        while let Some(id) = current {
+            let ancestor = self.tree.layout(id).map_err(to_js_error)?;
+            layout.location.x += ancestor.location.x;
+            layout.location.y += ancestor.location.y;
+            let scroll = offsets
+                .map(|offsets| offsets.get(&JsValue::from(u64::from(id))))
+                .and_then(|scroll| serde_wasm_bindgen::from_value::<ScrollOffsetDto>(scroll).ok());
+            if let Some(scroll) = scroll {
+                layout.location.x -= scroll.x;
+                layout.location.y -= scroll.y;
+            }
+            current = self.tree.parent(id);
+        }
+        Ok(JsLayout::from(layout))
+    }
+
+    /// Gets the unrounded (fractional) layout for a node
+    ///
+    /// Returns the raw computed values before any rounding is applied.
+    /// Useful when you need sub-pixel precision.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The unrounded `Layout`
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const nodeId = tree.newLeaf(new Style());
+    /// const layout: Layout = tree.unroundedLayout(nodeId);
+    /// console.log(`Exact width: ${layout.width}`);
+    /// ```
+    #[wasm_bindgen(js_name = unroundedLayout)]
+    pub fn unrounded_layout(&self, node: u64) -> JsLayout {
+        JsLayout::from(self.tree.unrounded_layout(NodeId::from(node)))
+    }
+
+    /// Gets detailed layout information for grid layouts
+    ///
+    /// @note
+    /// This method is only available when the `detailed_layout_info`
+    /// feature is enabled.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - Detailed grid info or "None" for non-grid nodes
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    #[cfg(feature = "detailed_layout_info")]
+    #[wasm_bindgen(js_name = detailedLayoutInfo)]
+    pub fn detailed_layout_info(&self, node: u64) -> Result<JsValue, JsValue> {
+        match self.tree.detailed_layout_info(NodeId::from(node)) {
+            DetailedLayoutInfo::Grid(info) => {
+                let dto = DetailedGridInfoDto {
+                    rows: DetailedGridTracksInfoDto {
+                        negative_implicit_tracks: info.rows.negative_implicit_tracks,
+                        explicit_tracks: info.rows.explicit_tracks,
+                        positive_implicit_tracks: info.rows.positive_implicit_tracks,
+                        gutters: info.rows.gutters.clone(),
                         sizes: info.rows.sizes.clone(),
                     },
                     columns: DetailedGridTracksInfoDto {
@@ -900,6 +3595,254 @@ impl JsTaffyTree {
         }
     }
 
+    /// Gets where the grid auto-placement algorithm put a grid item
+    ///
+    /// After laying out a grid container, returns the resolved 1-indexed row and
+    /// column start lines of an auto-placed child. An item counts as auto-placed
+    /// when at least one of its axes has no definite line (e.g. `"auto"` or a span).
+    ///
+    /// @note
+    /// This method is only available when the `detailed_layout_info`
+    /// feature is enabled.
+    ///
+    /// @param node - The grid item node ID
+    ///
+    /// @returns - The resolved `{ row, column }`, or `null` if the node is explicitly
+    /// placed, is not an in-flow child of a grid container, or has not been laid out
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const gridStyle = new Style();
+    /// gridStyle.display = Display.Grid;
+    /// gridStyle.gridTemplateColumns = [
+    ///   { min: 50, max: 50 },
+    ///   { min: 50, max: 50 },
+    /// ];
+    /// const a = tree.newLeaf(new Style());
+    /// const b = tree.newLeaf(new Style());
+    /// const c = tree.newLeaf(new Style());
+    /// const grid = tree.newWithChildren(gridStyle, BigUint64Array.from([a, b, c]));
+    /// tree.computeLayout(grid, { width: 100, height: 100 });
+    ///
+    /// const placement = tree.autoPlacement(c); // { row: 2, column: 1 }
+    /// ```
+    #[cfg(feature = "detailed_layout_info")]
+    #[wasm_bindgen(js_name = autoPlacement)]
+    pub fn auto_placement(&self, node: u64) -> Result<JsGridAutoPlacement, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        let null = || Ok(JsValue::NULL.unchecked_into());
+
+        if style.grid_row.is_definite() && style.grid_column.is_definite() {
+            return null();
+        }
+        let Some(parent) = self.tree.parent(node) else {
+            return null();
+        };
+        if self.tree.style(parent).map_err(to_js_error)?.display != Display::Grid {
+            return null();
+        }
+
+        // Detailed grid info lists in-flow items in source order
+        let in_flow = |id: &NodeId| {
+            self.tree.style(*id).is_ok_and(|s| {
+                s.display != Display::None && s.position != TaffyStyle::Position::Absolute
+            })
+        };
+        let children = self.tree.children(parent).map_err(to_js_error)?;
+        let Some(index) = children
+            .iter()
+            .filter(|id| in_flow(id))
+            .position(|&id| id == node)
+        else {
+            return null();
+        };
+
+        match self.tree.detailed_layout_info(parent) {
+            DetailedLayoutInfo::Grid(info) => match info.items.get(index) {
+                Some(item) => {
+                    let dto = GridAutoPlacementDto {
+                        row: item.row_start as i32 - info.rows.negative_implicit_tracks as i32,
+                        column: item.column_start as i32
+                            - info.columns.negative_implicit_tracks as i32,
+                    };
+                    Ok(serialize(&dto).unchecked_into())
+                }
+                None => null(),
+            },
+            DetailedLayoutInfo::None => null(),
+        }
+    }
+
+    /// Gets the number of resolved columns and rows in a grid container
+    ///
+    /// Counts include explicit tracks as well as implicit tracks created during
+    /// layout, which is useful for drawing grid overlays or "add row/column"
+    /// affordances.
+    ///
+    /// @note
+    /// This method is only available when the `detailed_layout_info`
+    /// feature is enabled.
+    ///
+    /// @param node - The grid container node ID
+    ///
+    /// @returns - The `{ columns, rows }` track counts, or `null` if the node is
+    /// not a grid container or has not been laid out
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const gridStyle = new Style();
+    /// gridStyle.display = Display.Grid;
+    /// gridStyle.gridTemplateColumns = [
+    ///   { min: 50, max: 50 },
+    ///   { min: 50, max: 50 },
+    /// ];
+    /// const items = [0, 1, 2].map(() => tree.newLeaf(new Style()));
+    /// const grid = tree.newWithChildren(gridStyle, BigUint64Array.from(items));
+    /// tree.computeLayout(grid, { width: 100, height: 100 });
+    ///
+    /// const dims = tree.gridDimensions(grid); // { columns: 2, rows: 2 }
+    /// ```
+    #[cfg(feature = "detailed_layout_info")]
+    #[wasm_bindgen(js_name = gridDimensions)]
+    pub fn grid_dimensions(&self, node: u64) -> Result<JsGridDimensions, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        if self.tree.style(node).map_err(to_js_error)?.display != Display::Grid {
+            return Ok(JsValue::NULL.unchecked_into());
+        }
+
+        match self.tree.detailed_layout_info(node) {
+            DetailedLayoutInfo::Grid(info) => {
+                let count = |t: &taffy::DetailedGridTracksInfo| {
+                    (t.negative_implicit_tracks + t.explicit_tracks + t.positive_implicit_tracks)
+                        as u32
+                };
+                let dto = GridDimensionsDto {
+                    columns: count(&info.columns),
+                    rows: count(&info.rows),
+                };
+                Ok(serialize(&dto).unchecked_into())
+            }
+            DetailedLayoutInfo::None => Ok(JsValue::NULL.unchecked_into()),
+        }
+    }
+
+    /// Gets the index of the flex line an item was placed on
+    ///
+    /// After laying out a wrapping flex container, returns which line (0-indexed,
+    /// in the order lines are filled) the item ended up on. Lines are derived
+    /// from the laid-out positions of the item and its in-flow siblings: a new
+    /// line starts where an item no longer advances along the main axis and sits
+    /// beyond the previous line on the cross axis. Items of a `nowrap` container
+    /// are always on line 0.
+    ///
+    /// @param node - The flex item node ID
+    ///
+    /// @returns - The line index, or `undefined` if the node is not an in-flow
+    /// child of a flex container
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const rowStyle = new Style();
+    /// rowStyle.flexWrap = FlexWrap.Wrap;
+    /// rowStyle.size = { width: 250, height: "auto" };
+    /// const itemStyle = new Style();
+    /// itemStyle.size = { width: 100, height: 20 };
+    /// const items = [0, 1, 2].map(() => tree.newLeaf(itemStyle));
+    /// const row = tree.newWithChildren(rowStyle, BigUint64Array.from(items));
+    /// tree.computeLayout(row, { width: 800, height: 600 });
+    ///
+    /// items.map((item) => tree.flexLineOf(item)); // [0, 0, 1]
+    /// ```
+    #[wasm_bindgen(js_name = flexLineOf)]
+    pub fn flex_line_of(&self, node: u64) -> Result<Option<u32>, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        let in_flow =
+            |s: &TaffyStyle::Style| s.display != Display::None && s.position != Position::Absolute;
+        if !in_flow(style) {
+            return Ok(None);
+        }
+        let Some(parent) = self.tree.parent(node) else {
+            return Ok(None);
+        };
+        let container = self.tree.style(parent).map_err(to_js_error)?;
+        if container.display != Display::Flex {
+            return Ok(None);
+        }
+        if container.flex_wrap == FlexWrap::NoWrap {
+            return Ok(Some(0));
+        }
+
+        let row = matches!(
+            container.flex_direction,
+            FlexDirection::Row | FlexDirection::RowReverse
+        );
+        let main_reverse = matches!(
+            container.flex_direction,
+            FlexDirection::RowReverse | FlexDirection::ColumnReverse
+        );
+        let cross_reverse = container.flex_wrap == FlexWrap::WrapReverse;
+        // Margin-box (start, end) spans on the main and cross axes
+        let spans = |layout: &Layout| {
+            let x = (
+                layout.location.x - layout.margin.left,
+                layout.location.x + layout.size.width + layout.margin.right,
+            );
+            let y = (
+                layout.location.y - layout.margin.top,
+                layout.location.y + layout.size.height + layout.margin.bottom,
+            );
+            if row { (x, y) } else { (y, x) }
+        };
+
+        let mut line = 0;
+        let mut previous: Option<((f32, f32), (f32, f32))> = None;
+        for child in self.tree.children(parent).map_err(to_js_error)? {
+            if !in_flow(self.tree.style(child).map_err(to_js_error)?) {
+                continue;
+            }
+            let (main, cross) = spans(self.tree.layout(child).map_err(to_js_error)?);
+            if let Some((prev_main, line_cross)) = &mut previous {
+                let advances = if main_reverse {
+                    main.1 <= prev_main.0
+                } else {
+                    main.0 >= prev_main.1
+                };
+                let beyond = if cross_reverse {
+                    cross.1 <= line_cross.0
+                } else {
+                    cross.0 >= line_cross.1
+                };
+                if !advances && beyond {
+                    line += 1;
+                    *line_cross = cross;
+                } else {
+                    *line_cross = (line_cross.0.min(cross.0), line_cross.1.max(cross.1));
+                }
+                *prev_main = main;
+            } else {
+                previous = Some((main, cross));
+            }
+            if child == node {
+                return Ok(Some(line));
+            }
+        }
+        Ok(None)
+    }
+
     // =========================================================================
     // Dirty Tracking
     // =========================================================================
@@ -907,7 +3850,8 @@ impl JsTaffyTree {
     /// Marks a node as dirty (requiring re-layout)
     ///
     /// Use this when a node's content has changed but its style hasn't.
-    /// For example, when text content changes and needs remeasuring.
+    /// For example, when text content changes and needs remeasuring. Inside a
+    /// batch (see `beginBatch()`), the node is marked when the batch ends.
     ///
     /// @param node - The node ID to mark dirty
     ///
@@ -927,7 +3871,62 @@ impl JsTaffyTree {
     /// ```
     #[wasm_bindgen(js_name = markDirty)]
     pub fn mark_dirty(&mut self, node: u64) -> Result<(), JsValue> {
-        map_void_result(self.tree.mark_dirty(NodeId::from(node)))
+        self.ensure_mutable()?;
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        if self.state.batch_depth > 0 {
+            self.state.batched_dirty.insert(node);
+            return Ok(());
+        }
+        map_void_result(self.tree.mark_dirty(node))
+    }
+
+    /// Clears the cached layout of a node and all of its descendants
+    ///
+    /// In Taffy 0.9, `markDirty()` already discards the node's layout cache
+    /// (and its ancestors', whose layout depends on it), so the next layout
+    /// always recomputes it. It does not touch the node's descendants, which
+    /// keep their caches and may answer from them. This method marks
+    /// every node in the subtree dirty, so everything under `node` is laid out
+    /// and re-measured from scratch; use it when inputs that measure functions
+    /// depend on changed for a whole subtree, e.g. after a web font loads.
+    /// Inside a batch, the subtree is marked when the batch ends.
+    ///
+    /// @param node - The root node ID of the subtree to invalidate
+    ///
+    /// @throws `TaffyError` if the node does not exist or the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const label = tree.newLeafWithContext(new Style(), { text: "Hello" });
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([label]));
+    /// let charWidth = 8;
+    /// const measureText: MeasureFunction = (known, available, node, ctx) => ({
+    ///   width: ctx.text.length * charWidth,
+    ///   height: 16,
+    /// });
+    /// tree.computeLayoutWithMeasure(root, { width: 800, height: 600 }, measureText);
+    ///
+    /// // A web font finished loading, so every text node measures differently
+    /// charWidth = 9;
+    /// tree.invalidateLayout(root);
+    /// tree.computeLayoutWithMeasure(root, { width: 800, height: 600 }, measureText);
+    /// ```
+    #[wasm_bindgen(js_name = invalidateLayout)]
+    pub fn invalidate_layout(&mut self, node: u64) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let subtree = self.collect_subtree(node);
+        if self.state.batch_depth > 0 {
+            self.state.batched_dirty.extend(subtree);
+            return Ok(());
+        }
+        for id in subtree {
+            map_void_result(self.tree.mark_dirty(id))?;
+        }
+        Ok(())
     }
 
     /// Checks if a node is dirty (needs re-layout)
@@ -957,6 +3956,176 @@ impl JsTaffyTree {
         map_bool_result(self.tree.dirty(NodeId::from(node)))
     }
 
+    /// Gets a key identifying the layout inputs of a subtree
+    ///
+    /// Hashes the style and child IDs of `node` and every descendant. The key
+    /// changes whenever any of them changes, so it can be used to cache
+    /// expensive work derived from a subtree's layout (e.g. rasterization) and
+    /// skip it when the inputs are unchanged.
+    ///
+    /// The key does not cover the available space passed to `computeLayout()`
+    /// or content reported by measure functions; include those in your own
+    /// cache key if they vary. Keys are only comparable within the same build
+    /// of the library.
+    ///
+    /// @param node - The root node ID of the subtree
+    ///
+    /// @returns - The cache key (`bigint`)
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const content = tree.newLeaf(new Style());
+    /// const card = tree.newWithChildren(new Style(), BigUint64Array.from([content]));
+    /// const rasterCache = new Map<bigint, string>();
+    /// const rasterize = (node: bigint) => `bitmap of node ${node}`;
+    ///
+    /// const key = tree.layoutCacheKey(card);
+    /// let bitmap = rasterCache.get(key);
+    /// if (!bitmap) {
+    ///   bitmap = rasterize(card);
+    ///   rasterCache.set(key, bitmap);
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = layoutCacheKey)]
+    pub fn layout_cache_key(&self, node: u64) -> Result<u64, JsValue> {
+        let root = NodeId::from(node);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let mut hasher = DefaultHasher::new();
+        for id in self.collect_subtree(root) {
+            u64::from(id).hash(&mut hasher);
+            hash_style(self.tree.style(id).map_err(to_js_error)?, &mut hasher);
+            self.tree.child_count(id).hash(&mut hasher);
+        }
+        Ok(hasher.finish())
+    }
+
+    /// Gets a hash of a node's style
+    ///
+    /// Equal styles have equal hashes, so frameworks can memoize work that
+    /// depends on a node's style: if the hash is unchanged since the work was
+    /// last done, it can be skipped. Different styles may in rare cases
+    /// collide.
+    ///
+    /// Hashes are stable for the lifetime of the page and across runs of
+    /// the same build of the library, but may change between versions; do
+    /// not persist them.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The style hash (`bigint`)
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const node = tree.newLeaf(new Style());
+    /// const lastHashes = new Map<bigint, bigint>();
+    ///
+    /// const hash = tree.styleHash(node);
+    /// if (hash !== lastHashes.get(node)) {
+    ///   lastHashes.set(node, hash);
+    ///   // Repaint the node
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = styleHash)]
+    pub fn style_hash(&self, node: u64) -> Result<u64, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        let style = self.tree.style(node).map_err(to_js_error)?;
+        let mut hasher = DefaultHasher::new();
+        hash_style(style, &mut hasher);
+        Ok(hasher.finish())
+    }
+
+    // =========================================================================
+    // Batched Updates
+    // =========================================================================
+
+    /// Starts collecting style changes to apply together
+    ///
+    /// Until the matching `endBatch()`, `setStyle()`, `setStyleProperty()` and
+    /// `markDirty()` only record their changes. When the batch ends, each
+    /// node is updated once with its final style, so many edits to the same
+    /// node mark its ancestors dirty and fire `onStyleChange()` only once, and
+    /// edits that end up restoring the original style do neither.
+    ///
+    /// While a batch is open:
+    ///
+    /// - `getStyle()` and `setStyleProperty()` see the changes made so far;
+    ///   other queries see the styles from before the batch
+    /// - Structural changes (adding, removing or moving nodes) and node
+    ///   contexts are applied immediately
+    /// - Computing a layout first applies the changes made so far
+    ///
+    /// Batches nest: a `beginBatch()` inside an open batch joins it, and the
+    /// changes are applied when the outermost batch ends. Prefer
+    /// `batchUpdate()`, which pairs the calls even if the callback throws.
+    ///
+    /// @throws `TaffyError` if the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const node = tree.newLeaf(new Style());
+    /// tree.beginBatch();
+    /// tree.setStyleProperty(node, "flexGrow", 1);
+    /// tree.setStyleProperty(node, "flexShrink", 0);
+    /// tree.endBatch(); // node is marked dirty once
+    /// ```
+    #[wasm_bindgen(js_name = beginBatch)]
+    pub fn begin_batch(&mut self) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
+        self.state.batch_depth += 1;
+        Ok(())
+    }
+
+    /// Ends a batch started with `beginBatch()`
+    ///
+    /// Ending the outermost batch applies the collected changes. Calling this
+    /// without an open batch does nothing. The tree cannot be frozen while a
+    /// batch is open, so the changes can always be applied.
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const node = tree.newLeaf(new Style());
+    /// tree.beginBatch();
+    /// tree.setStyleProperty(node, "flexGrow", 1);
+    /// tree.endBatch();
+    /// console.log(tree.getStyle(node).flexGrow); // 1
+    /// ```
+    #[wasm_bindgen(js_name = endBatch)]
+    pub fn end_batch(&mut self) {
+        if self.state.batch_depth == 0 {
+            return;
+        }
+        self.state.batch_depth -= 1;
+        if self.state.batch_depth == 0 {
+            self.apply_batch();
+        }
+    }
+
+    /// Checks whether a batch is open
+    ///
+    /// @returns - `true` between `beginBatch()` and the matching `endBatch()`
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// tree.batchUpdate(() => {
+    ///   console.log(tree.isBatching()); // true
+    /// });
+    /// console.log(tree.isBatching()); // false
+    /// ```
+    #[wasm_bindgen(js_name = isBatching)]
+    pub fn is_batching(&self) -> bool {
+        self.state.batch_depth > 0
+    }
+
     // =========================================================================
     // Layout Computation
     // =========================================================================
@@ -967,6 +4136,26 @@ impl JsTaffyTree {
     /// that needs to be measured during layout. The measure function is
     /// called for each leaf node that needs measurement.
     ///
+    /// Measurements are cached with the rest of the layout, so a node is only
+    /// re-measured when it (or a descendant) is dirty. When a measurement
+    /// depends on external state that Taffy cannot see, such as a web font
+    /// finishing loading, give the node's context a `version` property and bump
+    /// it whenever that state changes: before computing, every node in the
+    /// subtree whose `context.version` differs (by `Object.is`) from the value
+    /// seen on the previous call is marked dirty and re-measured. A measure
+    /// function may bump its own context's `version` to request a fresh
+    /// measurement on the next call.
+    ///
+    /// Nodes whose context was set with a `measure` function (see
+    /// `setNodeContext()`) are measured by that function instead, with the same
+    /// arguments; `measureFunc` is the fallback for all other nodes.
+    ///
+    /// A measure function may also return a `baseline`: the distance in pixels
+    /// from the top of the measured content to its first baseline. Taffy 0.9
+    /// has no way to pass a leaf's baseline into layout, so `AlignItems.Baseline`
+    /// still aligns measured leaves by their bottom edge; the value is recorded
+    /// instead and can be read back with `measuredBaseline()`.
+    ///
     /// @param node - The root node ID to compute layout for
     /// @param availableSpace - The available space constraints
     /// @param measureFunc - A function that measures leaf node content
@@ -992,6 +4181,23 @@ impl JsTaffyTree {
     ///   }
     /// );
     /// ```
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const context = { text: "Hello", version: 0 };
+    /// const textNode = tree.newLeafWithContext(new Style(), context);
+    /// const measure: MeasureFunction = (known, available, node, ctx) => ({
+    ///   width: ctx.text.length * 8,
+    ///   height: 16,
+    /// });
+    ///
+    /// tree.computeLayoutWithMeasure(textNode, { width: 800, height: 600 }, measure);
+    ///
+    /// // A web font finished loading: force the text to be measured again
+    /// context.version++;
+    /// tree.computeLayoutWithMeasure(textNode, { width: 800, height: 600 }, measure);
+    /// ```
     #[wasm_bindgen(js_name = computeLayoutWithMeasure)]
     pub fn compute_layout_with_measure(
         &mut self,
@@ -999,6 +4205,7 @@ impl JsTaffyTree {
         #[wasm_bindgen(js_name = "availableSpace")] available_space: JsAvailableSizeArg,
         #[wasm_bindgen(js_name = "measureFunc")] measure_func: JsMeasureFunctionArg,
     ) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
         let js_value: JsValue = available_space.unchecked_into();
         let js_space = match serde_wasm_bindgen::from_value::<AvailableSizeDto>(js_value) {
             Ok(s) => s,
@@ -1010,13 +4217,22 @@ impl JsTaffyTree {
         };
 
         let space: Size<AvailableSpace> = js_space.into();
+        let root = NodeId::from(node);
+        self.ensure_node(root).map_err(to_js_error)?;
+        self.invalidate_changed_context_versions(root);
         let func: js_sys::Function = measure_func.unchecked_into();
+        let node_measures = self.state.node_measures.clone();
+        let mut measure_calls = HashMap::new();
+        let mut measured_baselines = HashMap::new();
+        let profiling = self.state.profiling;
+        let mut measure_times = HashMap::new();
         let measure = |known_dimensions: Size<Option<f32>>,
                        available_space: Size<AvailableSpace>,
                        _node: NodeId,
                        context: Option<&mut JsValue>,
                        _style: &TaffyStyle::Style|
          -> Size<f32> {
+            *measure_calls.entry(_node).or_insert(0) += 1;
             let this = JsValue::NULL;
             let known_val =
                 serde_wasm_bindgen::to_value(&known_dimensions).unwrap_or(JsValue::NULL);
@@ -1027,9 +4243,7 @@ impl JsTaffyTree {
             let available_val =
                 serde_wasm_bindgen::to_value(&available_dto).unwrap_or(JsValue::NULL);
             let ctx = context.cloned().unwrap_or(JsValue::UNDEFINED);
-            let style = JsStyle {
-                inner: _style.clone(),
-            };
+            let style = JsStyle::from(_style.clone());
             let style_val = JsValue::from(style);
             let node_id: u64 = _node.into();
             let node_val = JsValue::from(node_id);
@@ -1039,13 +4253,207 @@ impl JsTaffyTree {
             args.push(&node_val);
             args.push(&ctx);
             args.push(&style_val);
+            let func = node_measures.get(&_node).unwrap_or(&func);
+            let start = profiling.then(now);
             let result_val = func.apply(&this, &args).unwrap_or(JsValue::UNDEFINED);
-            serde_wasm_bindgen::from_value(result_val).unwrap_or(Size::ZERO)
+            if let Some(start) = start {
+                *measure_times.entry(_node).or_insert(0.0) += now() - start;
+            }
+            match serde_wasm_bindgen::from_value::<MeasuredSizeDto>(result_val) {
+                Ok(measured) => {
+                    match measured.baseline {
+                        Some(baseline) => measured_baselines.insert(_node, baseline),
+                        None => measured_baselines.remove(&_node),
+                    };
+                    Size {
+                        width: measured.width,
+                        height: measured.height,
+                    }
+                }
+                Err(_) => Size::ZERO,
+            }
         };
-        map_void_result(
-            self.tree
-                .compute_layout_with_measure(NodeId::from(node), space, measure),
-        )
+        let result = self.run_layout(NodeId::from(node), space, measure);
+        self.state.measure_calls = measure_calls;
+        self.state.measured_baselines = measured_baselines;
+        self.state.measure_times = measure_times;
+        result
+    }
+
+    /// Gets how many times the measure function was called for a node
+    ///
+    /// Counts the calls made for `node` during the most recent layout. A node
+    /// measured dozens of times in one layout points at a sizing instability,
+    /// such as a measure function whose result depends on values it shouldn't.
+    /// Counts are reset by every layout computation; layouts computed without a
+    /// measure function (e.g. `computeLayout()`) leave every count at `0`.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The number of measure function calls for the node
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const textNodes = ["Hello", "World"].map((text) =>
+    ///   tree.newLeafWithContext(new Style(), { text }),
+    /// );
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from(textNodes));
+    /// const measureText: MeasureFunction = (known, available, node, ctx) => ({
+    ///   width: ctx.text.length * 8,
+    ///   height: 16,
+    /// });
+    /// tree.computeLayoutWithMeasure(root, { width: 800, height: 600 }, measureText);
+    /// for (const node of textNodes) {
+    ///   if (tree.measureCallCount(node) > 10) {
+    ///     console.warn("Unstable measurement", node);
+    ///   }
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = measureCallCount)]
+    pub fn measure_call_count(&self, node: u64) -> Result<u32, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        Ok(self.state.measure_calls.get(&node).copied().unwrap_or(0))
+    }
+
+    /// Gets the baseline a measure function reported for a node
+    ///
+    /// Returns the `baseline` from the node's last measurement during the most
+    /// recent `computeLayoutWithMeasure()` call, so hosts can align text by its
+    /// baseline themselves (see `computeLayoutWithMeasure()` for why Taffy does
+    /// not). Every layout computation resets the recorded baselines.
+    ///
+    /// @param node - The node ID
+    ///
+    /// @returns - The reported baseline in pixels, or `undefined` if the node
+    ///   was not measured or its measure function returned no baseline
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const textNode = tree.newLeafWithContext(new Style(), { text: "Hello" });
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([textNode]));
+    /// const measureText = (text: string) => ({ width: text.length * 8, height: 20, ascent: 16 });
+    ///
+    /// const space = { width: 800, height: 600 };
+    /// tree.computeLayoutWithMeasure(root, space, (known, available, node, ctx) => {
+    ///   const metrics = measureText(ctx.text);
+    ///   return { width: metrics.width, height: metrics.height, baseline: metrics.ascent };
+    /// });
+    /// const baseline = tree.measuredBaseline(textNode) ?? tree.getLayout(textNode).height;
+    /// ```
+    #[wasm_bindgen(js_name = measuredBaseline)]
+    pub fn measured_baseline(&self, node: u64) -> Result<Option<f32>, JsValue> {
+        let node = NodeId::from(node);
+        self.ensure_node(node).map_err(to_js_error)?;
+        Ok(self.state.measured_baselines.get(&node).copied())
+    }
+
+    /// Checks whether the last layout changed the root's size
+    ///
+    /// Compares the size computed for the root by the most recent layout with
+    /// the size from the layout before it. Use it to decide whether a size
+    /// change needs to be propagated outward, e.g. to a parent scroll region.
+    ///
+    /// Only layouts of root nodes (nodes without a parent) are tracked, so
+    /// `computeSubtree()` on a nested node does not affect the result. The
+    /// first layout of a tree always counts as a change.
+    ///
+    /// @returns - `true` if the root size differs from the previous layout
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const rootStyle = new Style();
+    /// rootStyle.size = { width: "100%", height: "auto" };
+    /// const root = tree.newLeaf(rootStyle);
+    ///
+    /// const onResize = (width: number) => {
+    ///   tree.computeLayout(root, { width, height: 600 });
+    ///   if (tree.rootSizeChanged()) {
+    ///     console.log("Root resized to", tree.getLayout(root).width);
+    ///   }
+    /// };
+    /// onResize(800); // logs
+    /// onResize(800); // same size, nothing logged
+    /// onResize(1024); // logs
+    /// ```
+    #[wasm_bindgen(js_name = rootSizeChanged)]
+    pub fn root_size_changed(&self) -> bool {
+        self.state.root_size_changed
+    }
+
+    /// Gets the computed size of a root node
+    ///
+    /// Returns just the `width` and `height` of the node's last layout,
+    /// without building a `Layout` object. This is the value most often read
+    /// after a layout, e.g. to size the canvas or container the tree is
+    /// rendered into; pair it with `rootSizeChanged()` to only resize when
+    /// needed.
+    ///
+    /// @param root - The root node ID (any node is accepted)
+    ///
+    /// @returns - The computed `{ width, height }`
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const childStyle = new Style();
+    /// childStyle.size = { width: 300, height: 150 };
+    /// const child = tree.newLeaf(childStyle);
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([child]));
+    /// const canvas = { width: 0, height: 0 };
+    ///
+    /// tree.computeLayout(root, { width: "max-content", height: "max-content" });
+    /// if (tree.rootSizeChanged()) {
+    ///   const { width, height } = tree.rootSize(root); // 300, 150
+    ///   canvas.width = width;
+    ///   canvas.height = height;
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = rootSize)]
+    pub fn root_size(&self, root: u64) -> Result<JsSizeNumber, JsValue> {
+        let root = NodeId::from(root);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let layout = self.tree.layout(root).map_err(to_js_error)?;
+        Ok(serialize(&layout.size).unchecked_into())
+    }
+
+    /// Gets the available space passed to the most recent layout
+    ///
+    /// Returns the constraints of the last successful `computeLayout()` (or
+    /// any other layout method), in the same `Size<AvailableSpace>` shape
+    /// they are passed in, so apps can skip a recompute when the constraints
+    /// have not changed. Every layout is recorded, whichever node it was
+    /// computed for.
+    ///
+    /// @returns - The last available space, or `undefined` if no layout has
+    /// been computed since the tree was created or cleared
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const root = tree.newLeaf(new Style());
+    /// tree.computeLayout(root, { width: 800, height: "max-content" });
+    ///
+    /// const viewportWidth = 1024;
+    /// const last = tree.lastAvailableSpace();
+    /// if (last?.width !== viewportWidth) {
+    ///   tree.computeLayout(root, { width: viewportWidth, height: "max-content" });
+    /// }
+    /// ```
+    #[wasm_bindgen(js_name = lastAvailableSpace)]
+    pub fn last_available_space(&self) -> Option<JsAvailableSizeArg> {
+        self.state
+            .last_available_space
+            .map(|space| serialize(&AvailableSizeDto::from(space)).unchecked_into())
     }
 
     /// Computes the layout for a subtree
@@ -1085,11 +4493,12 @@ impl JsTaffyTree {
         node: u64,
         #[wasm_bindgen(js_name = "availableSpace")] available_space: JsAvailableSizeArg,
     ) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
         let js_value: JsValue = available_space.unchecked_into();
         match serde_wasm_bindgen::from_value::<AvailableSizeDto>(js_value) {
             Ok(js_space) => {
                 let space: Size<AvailableSpace> = js_space.into();
-                map_void_result(self.tree.compute_layout(NodeId::from(node), space))
+                self.run_layout(NodeId::from(node), space, |_, _, _, _, _| Size::ZERO)
             }
             Err(_) => Err(JsValue::from(JsTaffyError::from(
                 NativeTaffyError::InvalidInputNode(NodeId::from(node)),
@@ -1097,6 +4506,308 @@ impl JsTaffyTree {
         }
     }
 
+    /// Computes the layout for a subtree and then freezes the tree
+    ///
+    /// Equivalent to `computeLayout(node, availableSpace)` followed by
+    /// `freeze()`, for static content that is laid out once. Afterwards every
+    /// read returns the stored layout, and any mutation, including another
+    /// `computeLayout()`, throws a `TaffyError` instead of silently paying for
+    /// a recompute.
+    ///
+    /// Freeze mode applies to the whole tree, not just the subtree of `node`.
+    /// To update the content later, call `unfreeze()`, make the changes and
+    /// call `computeAndFreeze()` again.
+    ///
+    /// If the layout fails, the tree is left unfrozen.
+    ///
+    /// @param node - The root node ID to compute layout for
+    /// @param availableSpace - The available space constraints
+    ///
+    /// @throws `TaffyError` if the tree is already frozen, a batch is open,
+    /// the node does not exist or available space is invalid
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const buttonStyle = new Style();
+    /// buttonStyle.size = { width: 80, height: 32 };
+    /// const buttons = BigUint64Array.from([tree.newLeaf(buttonStyle), tree.newLeaf(buttonStyle)]);
+    /// const toolbar = tree.newWithChildren(new Style(), buttons);
+    /// tree.computeAndFreeze(toolbar, { width: 800, height: 48 });
+    ///
+    /// // Later, to change the toolbar:
+    /// tree.unfreeze();
+    /// const newToolbarStyle = new Style();
+    /// newToolbarStyle.justifyContent = JustifyContent.End;
+    /// tree.setStyle(toolbar, newToolbarStyle);
+    /// tree.computeAndFreeze(toolbar, { width: 800, height: 48 });
+    /// ```
+    #[wasm_bindgen(js_name = computeAndFreeze)]
+    pub fn compute_and_freeze(
+        &mut self,
+        node: u64,
+        #[wasm_bindgen(js_name = "availableSpace")] available_space: JsAvailableSizeArg,
+    ) -> Result<(), JsValue> {
+        self.ensure_no_batch()?;
+        self.compute_layout(node, available_space)?;
+        self.freeze()
+    }
+
+    /// Computes the layout and then visits every node of the subtree
+    ///
+    /// After a regular `computeLayout()`, calls `visitor(nodeId, layout)` for
+    /// `node` and each of its descendants, so an app can render in the same
+    /// call instead of walking the tree again. `layout` is the same object
+    /// `getLayout()` would return (positioned relative to the parent).
+    ///
+    /// Nodes are visited in paint order: depth-first pre-order, so a node is
+    /// visited before its children, and siblings in child order.
+    ///
+    /// The visitor must not call methods on this tree: the tree is in use for
+    /// the whole call, so any such call throws. If the visitor throws, the
+    /// traversal stops and the error is rethrown; the layout itself has
+    /// already been computed at that point.
+    ///
+    /// @param node - The root node ID to compute layout for
+    /// @param availableSpace - The available space constraints
+    /// @param visitor - Called with `(nodeId, layout)` for every node
+    ///
+    /// @throws `TaffyError` if the node does not exist, available space is
+    /// invalid, or the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const root = tree.newLeaf(new Style());
+    /// const boxes: string[] = [];
+    /// tree.computeLayoutWithVisitor(root, { width: 800, height: 600 }, (node, layout) => {
+    ///   boxes.push(`${node}: ${layout.width}x${layout.height} at ${layout.x},${layout.y}`);
+    /// });
+    /// ```
+    #[wasm_bindgen(js_name = computeLayoutWithVisitor)]
+    pub fn compute_layout_with_visitor(
+        &mut self,
+        node: u64,
+        #[wasm_bindgen(js_name = "availableSpace")] available_space: JsAvailableSizeArg,
+        visitor: JsLayoutVisitor,
+    ) -> Result<(), JsValue> {
+        self.compute_layout(node, available_space)?;
+        let visitor: js_sys::Function = visitor.unchecked_into();
+        for id in self.collect_subtree(NodeId::from(node)) {
+            let layout = JsLayout::from(self.tree.layout(id).map_err(to_js_error)?);
+            visitor.call2(
+                &JsValue::NULL,
+                &JsValue::from(u64::from(id)),
+                &layout.into(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Computes the layout at several available space constraints in one call
+    ///
+    /// Runs a layout of `node` for each entry of `spaces`, in order, and
+    /// returns the root layout of each run. Useful for probing how a component
+    /// sizes at several responsive breakpoints without crossing the JS/WASM
+    /// boundary once per size.
+    ///
+    /// Each run fully replaces the previous one, so afterwards the tree holds
+    /// the layout of the last run only (or is left as it was if `spaces` is
+    /// empty).
+    ///
+    /// @param node - The root node ID to compute layout for
+    /// @param spaces - The available space constraints to lay out at
+    ///
+    /// @returns - The root `Layout` of each run, in the order of `spaces`
+    ///
+    /// @throws `TaffyError` if the node does not exist, any available space is
+    /// invalid, or the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const tileStyle = new Style();
+    /// tileStyle.size = { width: 300, height: 100 };
+    /// const tiles = [tree.newLeaf(tileStyle), tree.newLeaf(tileStyle), tree.newLeaf(tileStyle)];
+    /// const cardStyle = new Style();
+    /// cardStyle.flexWrap = FlexWrap.Wrap;
+    /// const card = tree.newWithChildren(cardStyle, BigUint64Array.from(tiles));
+    ///
+    /// const [mobile, tablet, desktop] = tree.computeLayoutMulti(card, [
+    ///   { width: 375, height: "max-content" },
+    ///   { width: 768, height: "max-content" },
+    ///   { width: 1280, height: "max-content" },
+    /// ]);
+    /// console.log(mobile.height, tablet.height, desktop.height); // 300 200 100
+    /// ```
+    #[wasm_bindgen(js_name = computeLayoutMulti)]
+    pub fn compute_layout_multi(
+        &mut self,
+        node: u64,
+        spaces: JsAvailableSizeArray,
+    ) -> Result<Vec<JsLayout>, JsValue> {
+        self.ensure_mutable()?;
+        let root = NodeId::from(node);
+        self.ensure_node(root).map_err(to_js_error)?;
+        let js_value: JsValue = spaces.unchecked_into();
+        let spaces = serde_wasm_bindgen::from_value::<Vec<AvailableSizeDto>>(js_value)
+            .map_err(|_| to_js_error(NativeTaffyError::InvalidInputNode(root)))?;
+
+        let mut layouts = Vec::with_capacity(spaces.len());
+        for space in spaces {
+            self.run_layout(root, space.into(), |_, _, _, _, _| Size::ZERO)?;
+            layouts.push(JsLayout::from(self.tree.layout(root).map_err(to_js_error)?));
+        }
+        Ok(layouts)
+    }
+
+    /// Computes the layout with the node sized to its content
+    ///
+    /// Equivalent to `computeLayout(node, { width: "max-content", height: "max-content" })`:
+    /// the node takes its natural content size in both axes, unless its style
+    /// sets an explicit size.
+    ///
+    /// This commits a full layout: the node and all its descendants get new
+    /// layouts, readable with `getLayout()`. To lay out a non-root node at its
+    /// content size without disturbing its ancestors, use `computeSubtree()`.
+    ///
+    /// @param node - The root node ID to compute layout for
+    ///
+    /// @throws `TaffyError` if the node does not exist or the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const childStyle = new Style();
+    /// childStyle.size = { width: 120, height: 40 };
+    /// const child = tree.newLeaf(childStyle);
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([child]));
+    ///
+    /// tree.computeLayoutContent(root);
+    /// console.log(tree.getLayout(root).width); // 120
+    /// ```
+    #[wasm_bindgen(js_name = computeLayoutContent)]
+    pub fn compute_layout_content(&mut self, node: u64) -> Result<(), JsValue> {
+        self.ensure_mutable()?;
+        let space = Size {
+            width: AvailableSpace::MaxContent,
+            height: AvailableSpace::MaxContent,
+        };
+        self.run_layout(NodeId::from(node), space, |_, _, _, _, _| Size::ZERO)
+    }
+
+    /// Computes the layout of a node in isolation, as if it were a root
+    ///
+    /// Lays out `node` and its descendants against `availableSpace` alone,
+    /// ignoring the node's parent. Useful for measuring a popover or menu
+    /// before deciding where to place it.
+    ///
+    /// Afterwards `getLayout()` on the node and its descendants returns the
+    /// detached results, with the node itself at `(0, 0)`. The layouts of the
+    /// node's ancestors are left untouched, but they are marked dirty so that
+    /// the next `computeLayout()` on the real root lays the subtree out in
+    /// context again.
+    ///
+    /// @param node - The node ID to lay out
+    /// @param availableSpace - The available space constraints
+    ///
+    /// @throws `TaffyError` if the node does not exist, available space is
+    /// invalid, or the tree is frozen
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const menuStyle = new Style();
+    /// menuStyle.padding = { left: 8, right: 8, top: 4, bottom: 4 };
+    /// const menu = tree.newLeaf(menuStyle);
+    /// const root = tree.newWithChildren(new Style(), BigUint64Array.from([menu]));
+    /// tree.computeLayout(root, { width: 800, height: 600 });
+    ///
+    /// // Natural size of the menu, regardless of where it sits in the tree
+    /// tree.computeSubtree(menu, { width: "max-content", height: "max-content" });
+    /// const { width, height } = tree.getLayout(menu);
+    /// ```
+    #[wasm_bindgen(js_name = computeSubtree)]
+    pub fn compute_subtree(
+        &mut self,
+        node: u64,
+        #[wasm_bindgen(js_name = "availableSpace")] available_space: JsAvailableSizeArg,
+    ) -> Result<(), JsValue> {
+        self.compute_layout(node, available_space)?;
+        // Ancestors' cached layouts no longer match their descendants' layouts
+        if let Some(parent) = self.tree.parent(NodeId::from(node)) {
+            map_void_result(self.tree.mark_dirty(parent))?;
+        }
+        Ok(())
+    }
+
+    /// Gets a node's min-content size without committing a layout
+    ///
+    /// Lays the node out as a root under `min-content` constraints in both
+    /// axes, matching CSS `width: min-content` / `height: min-content`. An
+    /// explicit size on the node still applies, as it does in CSS.
+    ///
+    /// The layout runs on a detached copy of the subtree, so the results of
+    /// the last `computeLayout()` are left untouched. Copying the subtree and
+    /// running a full sizing pass makes this as expensive as a layout of the
+    /// subtree; call `minContentSize()` and `maxContentSize()` only when both
+    /// are needed. Nodes are not measured, as with `computeLayout()`, and the
+    /// result is not rounded.
+    ///
+    /// @param node - The node ID to size
+    ///
+    /// @returns - The min-content `{ width, height }` in pixels
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const rootStyle = new Style();
+    /// rootStyle.flexWrap = FlexWrap.Wrap;
+    /// const childStyle = new Style();
+    /// childStyle.size = { width: 100, height: 20 };
+    /// const root = tree.newWithChildren(
+    ///   rootStyle,
+    ///   BigUint64Array.from([tree.newLeaf(childStyle), tree.newLeaf(childStyle)]),
+    /// );
+    ///
+    /// tree.minContentSize(root); // { width: 100, height: 40 }
+    /// tree.maxContentSize(root); // { width: 200, height: 20 }
+    /// ```
+    #[wasm_bindgen(js_name = minContentSize)]
+    pub fn min_content_size(&self, node: u64) -> Result<JsSizeNumber, JsValue> {
+        let size = self.detached_size(NodeId::from(node), AvailableSpace::MinContent)?;
+        Ok(serialize(&size).unchecked_into())
+    }
+
+    /// Gets a node's max-content size without committing a layout
+    ///
+    /// Lays the node out as a root under `max-content` constraints in both
+    /// axes, matching CSS `width: max-content` / `height: max-content`. See
+    /// `minContentSize()` for the cost and the treatment of measured nodes.
+    ///
+    /// @param node - The node ID to size
+    ///
+    /// @returns - The max-content `{ width, height }` in pixels
+    ///
+    /// @throws `TaffyError` if the node does not exist
+    ///
+    /// @example
+    /// ```typescript
+    /// const tree = new TaffyTree();
+    /// const labelStyle = new Style();
+    /// labelStyle.padding = { left: 12, right: 12, top: 4, bottom: 4 };
+    /// const label = tree.newLeaf(labelStyle);
+    ///
+    /// const { width } = tree.maxContentSize(label); // 24
+    /// ```
+    #[wasm_bindgen(js_name = maxContentSize)]
+    pub fn max_content_size(&self, node: u64) -> Result<JsSizeNumber, JsValue> {
+        let size = self.detached_size(NodeId::from(node), AvailableSpace::MaxContent)?;
+        Ok(serialize(&size).unchecked_into())
+    }
+
     // =========================================================================
     // Utilities
     // =========================================================================
@@ -1120,3 +4831,508 @@ impl JsTaffyTree {
         self.tree.print_tree(NodeId::from(node));
     }
 }
+
+// =============================================================================
+// Internal Helpers
+// =============================================================================
+
+impl JsTaffyTree {
+    /// Returns an error if the tree is frozen
+    fn ensure_mutable(&self) -> Result<(), JsValue> {
+        if self.state.frozen {
+            Err(JsValue::from(JsTaffyError::from(ErrorKind::Frozen)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns an error if a batch is open
+    fn ensure_no_batch(&self) -> Result<(), JsValue> {
+        if self.state.batch_depth > 0 {
+            Err(JsValue::from(JsTaffyError::from(ErrorKind::BatchOpen)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that `node` has not been removed
+    ///
+    /// Taffy panics instead of returning an error when given the ID of a
+    /// removed node, so methods that are commonly called with stale IDs check
+    /// them first.
+    fn ensure_node(&self, node: NodeId) -> Result<(), NativeTaffyError> {
+        if self.state.node_ids.contains(&node) {
+            Ok(())
+        } else {
+            Err(NativeTaffyError::InvalidInputNode(node))
+        }
+    }
+
+    /// Drops everything recorded about `node` outside of the Taffy tree
+    ///
+    /// Called when a node is removed, so that the per-node maps do not grow
+    /// with every removal or keep reporting data for a removed ID.
+    fn forget_node(&mut self, node: NodeId) {
+        self.state.context_versions.remove(&node);
+        self.state.measure_calls.remove(&node);
+        self.state.measured_baselines.remove(&node);
+        self.state.pinned_layouts.remove(&node);
+        self.state.node_measures.remove(&node);
+        self.state.measure_times.remove(&node);
+        self.state.node_ids.remove(&node);
+        self.state.laid_out.remove(&node);
+        self.state.batched_styles.remove(&node);
+        self.state.batched_dirty.remove(&node);
+    }
+
+    /// Computes the layout of `root`, reporting runs over the slow-layout budget
+    fn run_layout<MeasureFunction>(
+        &mut self,
+        root: NodeId,
+        space: Size<AvailableSpace>,
+        measure: MeasureFunction,
+    ) -> Result<(), JsValue>
+    where
+        MeasureFunction: FnMut(
+            Size<Option<f32>>,
+            Size<AvailableSpace>,
+            NodeId,
+            Option<&mut JsValue>,
+            &TaffyStyle::Style,
+        ) -> Size<f32>,
+    {
+        self.ensure_node(root).map_err(to_js_error)?;
+        self.apply_batch();
+        self.state.measure_calls.clear();
+        self.state.measured_baselines.clear();
+        self.state.measure_times.clear();
+        let start = (self.state.slow_layout.is_some() || self.state.profiling).then(now);
+        let result = map_void_result(self.tree.compute_layout_with_measure(root, space, measure));
+
+        if let (Some(start), true) = (start, self.state.profiling) {
+            self.state.profiled_layout = Some((root, now() - start));
+        }
+
+        if let (Some(start), Some((threshold, callback))) = (start, &self.state.slow_layout) {
+            let duration = now() - start;
+            if duration > *threshold {
+                let info = SlowLayoutInfoDto {
+                    node: root.into(),
+                    duration_ms: duration,
+                    threshold_ms: *threshold,
+                    node_count: self.collect_subtree(root).len() as u32,
+                };
+                let _ = callback.call1(&JsValue::NULL, &serialize_with_node_ids(&info));
+            }
+        }
+
+        if result.is_ok() {
+            self.state.last_available_space = Some(space);
+            self.state.laid_out.extend(self.collect_subtree(root));
+        }
+        if result.is_ok() && self.tree.parent(root).is_none() {
+            let size = self.tree.layout(root).map_err(to_js_error)?.size;
+            self.state.root_size_changed = self.state.last_root_size != Some(size);
+            self.state.last_root_size = Some(size);
+        }
+        result
+    }
+
+    /// Lays out a detached copy of the subtree under `root` and returns its size
+    ///
+    /// Only styles are copied, so the live tree's layouts and caches are not
+    /// touched.
+    fn detached_size(&self, root: NodeId, space: AvailableSpace) -> Result<Size<f32>, JsValue> {
+        self.ensure_node(root).map_err(to_js_error)?;
+        let mut scratch: TaffyTree<()> = TaffyTree::new();
+        scratch.disable_rounding();
+        let copy = self.copy_subtree(root, &mut scratch).map_err(to_js_error)?;
+        let space = Size {
+            width: space,
+            height: space,
+        };
+        scratch.compute_layout(copy, space).map_err(to_js_error)?;
+        Ok(scratch.layout(copy).map_err(to_js_error)?.size)
+    }
+
+    /// Builds the `toNestedObject()` structure for `node` and its descendants
+    fn nested_object(&self, node: NodeId) -> Result<js_sys::Object, JsValue> {
+        let style = JsStyle::from(self.tree.style(node).map_err(to_js_error)?.clone());
+        let layout = self.tree.layout(node).map_err(to_js_error)?;
+        let layout = LayoutRectDto {
+            x: layout.location.x,
+            y: layout.location.y,
+            width: layout.size.width,
+            height: layout.size.height,
+        };
+        let children = js_sys::Array::new();
+        for child in self.tree.children(node).map_err(to_js_error)? {
+            children.push(&self.nested_object(child)?.into());
+        }
+
+        let object = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&object, &"id".into(), &u64::from(node).into());
+        let _ = js_sys::Reflect::set(&object, &"style".into(), &style.to_plain_object());
+        let _ = js_sys::Reflect::set(&object, &"layout".into(), &serialize(&layout));
+        let _ = js_sys::Reflect::set(&object, &"children".into(), &children);
+        Ok(object)
+    }
+
+    /// Copies the styles of `root` and its descendants into `target`
+    fn copy_subtree(
+        &self,
+        root: NodeId,
+        target: &mut TaffyTree<()>,
+    ) -> Result<NodeId, NativeTaffyError> {
+        let children = self
+            .tree
+            .children(root)?
+            .into_iter()
+            .map(|child| self.copy_subtree(child, target))
+            .collect::<Result<Vec<_>, _>>()?;
+        target.new_with_children(self.tree.style(root)?.clone(), &children)
+    }
+
+    /// Builds the node described by `spec` and its descendants, recording
+    /// every created node in `created`
+    fn build_spec(
+        &mut self,
+        spec: &JsValue,
+        path: &str,
+        created: &mut Vec<NodeId>,
+    ) -> Result<NodeId, JsValue> {
+        let invalid = |path: String, reason: String| {
+            JsValue::from(JsTaffyError::from(ErrorKind::InvalidTreeSpec {
+                path,
+                reason,
+            }))
+        };
+        if !spec.is_object() {
+            return Err(invalid(path.to_string(), "expected an object".into()));
+        }
+        let field = |name: &str| js_sys::Reflect::get(spec, &JsValue::from_str(name));
+
+        let mut style = JsStyle::from(self.state.default_style.clone());
+        let style_spec = field("style")?;
+        if !style_spec.is_undefined() {
+            let style_path = format!("{path}.style");
+            let Some(properties) = style_spec.dyn_ref::<js_sys::Object>() else {
+                return Err(invalid(style_path, "expected an object".into()));
+            };
+            for entry in js_sys::Object::entries(properties).iter() {
+                let entry: js_sys::Array = entry.unchecked_into();
+                let key = entry.get(0).as_string().unwrap_or_default();
+                if !style.set_property(&key, entry.get(1)) {
+                    let reason = ErrorKind::UnknownStyleProperty(key).to_string();
+                    return Err(invalid(style_path, reason));
+                }
+            }
+        }
+
+        let children_spec = field("children")?;
+        let mut children = Vec::new();
+        if !children_spec.is_undefined() {
+            let Some(children_spec) = children_spec.dyn_ref::<js_sys::Array>() else {
+                return Err(invalid(
+                    format!("{path}.children"),
+                    "expected an array".into(),
+                ));
+            };
+            for (index, child) in children_spec.iter().enumerate() {
+                let child_path = format!("{path}.children[{index}]");
+                children.push(self.build_spec(&child, &child_path, created)?);
+            }
+        }
+
+        let node = self
+            .tree
+            .new_with_children(style.inner, &children)
+            .map_err(to_js_error)?;
+        self.state.node_ids.insert(node);
+        created.push(node);
+
+        let context = field("context")?;
+        if !context.is_undefined() {
+            let measure = Self::context_measure(&context);
+            self.tree
+                .set_node_context(node, Some(context))
+                .map_err(to_js_error)?;
+            self.set_node_measure(node, measure);
+        }
+        Ok(node)
+    }
+
+    /// Gets the `measure` function of an object context, if any
+    fn context_measure(context: &JsValue) -> Option<js_sys::Function> {
+        if !context.is_object() {
+            return None;
+        }
+        js_sys::Reflect::get(context, &JsValue::from_str("measure"))
+            .ok()
+            .and_then(|measure| measure.dyn_into::<js_sys::Function>().ok())
+    }
+
+    /// Stores (or, with `None`, forgets) a node's own measure function
+    fn set_node_measure(&mut self, node: NodeId, measure: Option<js_sys::Function>) {
+        match measure {
+            Some(measure) => self.state.node_measures.insert(node, measure),
+            None => self.state.node_measures.remove(&node),
+        };
+    }
+
+    /// Sums the recorded measure times of each node in `nodes` and its
+    /// descendants
+    ///
+    /// `nodes` must list every descendant after its ancestors, as
+    /// `collect_subtree()` does.
+    fn profile_totals(&self, nodes: &[NodeId]) -> Result<HashMap<NodeId, f64>, JsValue> {
+        // Walking the pre-order list backwards sums every child before its parent
+        let mut totals: HashMap<NodeId, f64> = HashMap::new();
+        for &node in nodes.iter().rev() {
+            let own = self.state.measure_times.get(&node).copied().unwrap_or(0.0);
+            let children: f64 = self
+                .tree
+                .children(node)
+                .map_err(to_js_error)?
+                .iter()
+                .map(|child| totals.get(child).copied().unwrap_or(0.0))
+                .sum();
+            totals.insert(node, own + children);
+        }
+        Ok(totals)
+    }
+
+    /// Collects `root` and all its descendants in depth-first pre-order
+    fn collect_subtree(&self, root: NodeId) -> Vec<NodeId> {
+        let mut nodes = Vec::new();
+        let mut stack = vec![root];
+        while let Some(id) = stack.pop() {
+            nodes.push(id);
+            if let Ok(children) = self.tree.children(id) {
+                stack.extend(children.into_iter().rev());
+            }
+        }
+        nodes
+    }
+
+    /// Marks dirty every node under `root` whose `context.version` changed
+    ///
+    /// Versions are compared with `Object.is` against the value recorded on the
+    /// previous call, so bumping a version forces the node to be re-measured.
+    fn invalidate_changed_context_versions(&mut self, root: NodeId) {
+        let key = JsValue::from_str("version");
+        let mut stack = vec![root];
+        while let Some(id) = stack.pop() {
+            let version = self
+                .tree
+                .get_node_context(id)
+                .filter(|ctx| ctx.is_object())
+                .and_then(|ctx| js_sys::Reflect::get(ctx, &key).ok())
+                .filter(|v| !v.is_undefined());
+            let changed = match (self.state.context_versions.get(&id), &version) {
+                (Some(prev), Some(v)) => !js_sys::Object::is(prev, v),
+                (None, None) => false,
+                _ => true,
+            };
+            if changed {
+                let _ = self.tree.mark_dirty(id);
+                match version {
+                    Some(v) => self.state.context_versions.insert(id, v),
+                    None => self.state.context_versions.remove(&id),
+                };
+            }
+            if let Ok(children) = self.tree.children(id) {
+                stack.extend(children);
+            }
+        }
+    }
+
+    /// Applies the changes collected by the open batch
+    ///
+    /// Styles equal to the node's current style are skipped, so changes that
+    /// were undone within the batch neither dirty the node nor notify.
+    fn apply_batch(&mut self) {
+        for node in std::mem::take(&mut self.state.batched_dirty) {
+            let _ = self.tree.mark_dirty(node);
+        }
+        for (node, style) in std::mem::take(&mut self.state.batched_styles) {
+            if self.tree.style(node).is_ok_and(|current| *current == style) {
+                continue;
+            }
+            let _ = self.tree.set_style(node, style);
+            self.notify_style_change(node);
+        }
+    }
+
+    /// Calls the `onStyleChange()` callback, if any, for `node`
+    fn notify_style_change(&self, node: NodeId) {
+        if let Some(callback) = &self.state.style_change {
+            let _ = callback.call1(&JsValue::NULL, &u64::from(node).into());
+        }
+    }
+
+    /// Sums the locations of `node` and all its ancestors
+    fn absolute_location(&self, node: NodeId) -> Result<Point<f32>, NativeTaffyError> {
+        self.ensure_node(node)?;
+        let mut location = Point::ZERO;
+        let mut current = Some(node);
+        while let Some(id) = current {
+            let layout = self.tree.layout(id)?;
+            location.x += layout.location.x;
+            location.y += layout.location.y;
+            current = self.tree.parent(id);
+        }
+        Ok(location)
+    }
+
+    /// Checks whether `ancestor` is a strict ancestor of `node`
+    fn is_ancestor(&self, ancestor: NodeId, node: NodeId) -> Result<bool, NativeTaffyError> {
+        self.ensure_node(ancestor)?;
+        self.ensure_node(node)?;
+        let mut current = self.tree.parent(node);
+        while let Some(id) = current {
+            if id == ancestor {
+                return Ok(true);
+            }
+            current = self.tree.parent(id);
+        }
+        Ok(false)
+    }
+
+    /// Finds the position of `child` among the children of `parent`
+    fn index_of_child(&self, parent: NodeId, child: NodeId) -> Result<usize, NativeTaffyError> {
+        self.ensure_node(parent)?;
+        self.tree
+            .children(parent)?
+            .iter()
+            .position(|&c| c == child)
+            .ok_or(NativeTaffyError::InvalidChildNode(child))
+    }
+}
+
+// =============================================================================
+// Trait Implementations
+// =============================================================================
+
+impl Default for JsTaffyTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// =============================================================================
+// Style Hashing
+// =============================================================================
+
+/// Feeds every field of a style into `state`
+///
+/// `Style` has no `Hash` impl because it holds floats, so the fields are
+/// hashed one by one, with floats hashed by their bits (see [`hash_f32`]).
+fn hash_style<H: Hasher>(style: &TaffyStyle::Style, state: &mut H) {
+    (style.display as u8).hash(state);
+    style.item_is_table.hash(state);
+    style.item_is_replaced.hash(state);
+    (style.box_sizing as u8).hash(state);
+    (style.overflow.x as u8, style.overflow.y as u8).hash(state);
+    hash_f32(style.scrollbar_width, state);
+    (style.position as u8).hash(state);
+    hash_rect(style.inset.map(|v| v.into_raw()), state);
+    for size in [style.size, style.min_size, style.max_size] {
+        hash_length(size.width.into_raw(), state);
+        hash_length(size.height.into_raw(), state);
+    }
+    style.aspect_ratio.is_some().hash(state);
+    hash_f32(style.aspect_ratio.unwrap_or_default(), state);
+    hash_rect(style.margin.map(|v| v.into_raw()), state);
+    hash_rect(style.padding.map(|v| v.into_raw()), state);
+    hash_rect(style.border.map(|v| v.into_raw()), state);
+
+    let items = [
+        style.align_items,
+        style.align_self,
+        style.justify_items,
+        style.justify_self,
+    ];
+    items.map(|v| v.map(|v| v as u8)).hash(state);
+    let content = [style.align_content, style.justify_content];
+    content.map(|v| v.map(|v| v as u8)).hash(state);
+    hash_length(style.gap.width.into_raw(), state);
+    hash_length(style.gap.height.into_raw(), state);
+    (style.text_align as u8).hash(state);
+
+    (style.flex_direction as u8, style.flex_wrap as u8).hash(state);
+    hash_length(style.flex_basis.into_raw(), state);
+    hash_f32(style.flex_grow, state);
+    hash_f32(style.flex_shrink, state);
+
+    for template in [&style.grid_template_rows, &style.grid_template_columns] {
+        template.len().hash(state);
+        for component in template {
+            match component {
+                TaffyStyle::GridTemplateComponent::Single(track) => {
+                    0u8.hash(state);
+                    hash_track(*track, state);
+                }
+                TaffyStyle::GridTemplateComponent::Repeat(repetition) => {
+                    let count = match repetition.count {
+                        TaffyStyle::RepetitionCount::AutoFill => (0u8, 0),
+                        TaffyStyle::RepetitionCount::AutoFit => (1u8, 0),
+                        TaffyStyle::RepetitionCount::Count(count) => (2u8, count),
+                    };
+                    (1u8, count, repetition.tracks.len()).hash(state);
+                    repetition.tracks.iter().for_each(|t| hash_track(*t, state));
+                    repetition.line_names.hash(state);
+                }
+            }
+        }
+    }
+    for tracks in [&style.grid_auto_rows, &style.grid_auto_columns] {
+        tracks.len().hash(state);
+        tracks.iter().for_each(|t| hash_track(*t, state));
+    }
+    (style.grid_auto_flow as u8).hash(state);
+    style.grid_template_areas.len().hash(state);
+    for area in &style.grid_template_areas {
+        area.name.hash(state);
+        (area.row_start, area.row_end).hash(state);
+        (area.column_start, area.column_end).hash(state);
+    }
+    style.grid_template_row_names.hash(state);
+    style.grid_template_column_names.hash(state);
+    for line in [&style.grid_row, &style.grid_column] {
+        hash_placement(&line.start, state);
+        hash_placement(&line.end, state);
+    }
+}
+
+/// Hashes a float by its bits, treating `-0.0` as `0.0` since they compare equal
+fn hash_f32<H: Hasher>(value: f32, state: &mut H) {
+    let value = if value == 0.0 { 0.0 } else { value };
+    value.to_bits().hash(state);
+}
+
+fn hash_length<H: Hasher>(value: CompactLength, state: &mut H) {
+    value.tag().hash(state);
+    hash_f32(value.value(), state);
+}
+
+fn hash_rect<H: Hasher>(rect: Rect<CompactLength>, state: &mut H) {
+    for side in [rect.left, rect.right, rect.top, rect.bottom] {
+        hash_length(side, state);
+    }
+}
+
+fn hash_track<H: Hasher>(track: TrackSizingFunction, state: &mut H) {
+    hash_length(track.min.into_raw(), state);
+    hash_length(track.max.into_raw(), state);
+}
+
+fn hash_placement<H: Hasher>(placement: &GridPlacement, state: &mut H) {
+    match placement {
+        GridPlacement::Auto => 0u8.hash(state),
+        GridPlacement::Line(line) => (1u8, line.as_i16()).hash(state),
+        GridPlacement::NamedLine(name, index) => (2u8, name, index).hash(state),
+        GridPlacement::Span(span) => (3u8, span).hash(state),
+        GridPlacement::NamedSpan(name, span) => (4u8, name, span).hash(state),
+    }
+}
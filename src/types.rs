@@ -21,6 +21,12 @@
 //! | [`RectDto<T>`] | `Rect<T>` | Left, right, top, bottom quad |
 //! | [`AvailableSizeDto`] | `Size<AvailableSpace>` | Layout constraints |
 //! | [`AvailableSpaceDto`] | `AvailableSpace` | Single dimension constraint |
+//! | [`BoxModelDto`] | `Layout` | Computed margin/border/padding/content boxes |
+//!
+//! ## Dimension Helpers
+//!
+//! The exported [`px`], [`percent`] and [`auto`] functions build `Dimension`
+//! values with an explicit unit.
 //!
 //! ## TypeScript Declarations
 //!
@@ -32,13 +38,17 @@
 //! - `MeasureFunction` callback signature
 //! - Detailed grid layout info types
 
+use crate::utils::serialize;
 use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::Cell;
 use std::fmt;
-use taffy::geometry::{Rect, Size};
+use taffy::geometry::{Point, Rect, Size};
 use taffy::style::{
     AvailableSpace, CompactLength, Dimension, LengthPercentage, LengthPercentageAuto,
 };
+use taffy::tree::Layout;
+use wasm_bindgen::JsCast;
 use wasm_bindgen::prelude::*;
 
 // =============================================================================
@@ -59,6 +69,10 @@ extern "C" {
     #[wasm_bindgen(typescript_type = "Size<AvailableSpace>")]
     pub type JsAvailableSizeArg;
 
+    /// A list of available space constraints (see `computeLayoutMulti()`)
+    #[wasm_bindgen(typescript_type = "Size<AvailableSpace>[]")]
+    pub type JsAvailableSizeArray;
+
     /// Measure function callback type
     ///
     /// Used with `computeLayoutWithMeasure()` for custom content measurement.
@@ -69,10 +83,54 @@ extern "C" {
     #[wasm_bindgen(typescript_type = "Point<Overflow>")]
     pub type JsPointOverflow;
 
+    /// DOM elements keyed by node ID (see `applyToDom()`)
+    #[wasm_bindgen(typescript_type = "Map<bigint, HTMLElement>")]
+    pub type JsDomElementMap;
+
+    /// Size with a boolean per dimension
+    #[wasm_bindgen(typescript_type = "Size<boolean>")]
+    pub type JsSizeBool;
+
+    /// Point with a boolean per axis
+    #[wasm_bindgen(typescript_type = "Point<boolean>")]
+    pub type JsPointBool;
+
+    /// Size in pixels
+    #[wasm_bindgen(typescript_type = "Size<number>")]
+    pub type JsSizeNumber;
+
+    /// Name-to-value maps of all exported enums (see `getEnums()`)
+    #[wasm_bindgen(typescript_type = "Readonly<Record<string, Readonly<Record<string, number>>>>")]
+    pub type JsEnumMaps;
+
+    /// Callback for layouts that exceed the slow-layout threshold
+    #[wasm_bindgen(typescript_type = "(info: SlowLayoutInfo) => void")]
+    pub type JsSlowLayoutCallback;
+
+    /// Callback invoked with the ID of a node whose style changed
+    #[wasm_bindgen(typescript_type = "(node: bigint) => void")]
+    pub type JsStyleChangeCallback;
+
+    /// Callback invoked per node by `computeLayoutWithVisitor()`
+    #[wasm_bindgen(typescript_type = "(node: bigint, layout: Layout) => void")]
+    pub type JsLayoutVisitor;
+
     /// Single dimension type (Length, Percent, or Auto)
     #[wasm_bindgen(typescript_type = "Dimension")]
     pub type JsDimension;
 
+    /// Single length/percentage value (no auto)
+    #[wasm_bindgen(typescript_type = "LengthPercentage")]
+    pub type JsLengthPercentage;
+
+    /// Single length/percentage/auto value
+    #[wasm_bindgen(typescript_type = "LengthPercentageAuto")]
+    pub type JsLengthPercentageAuto;
+
+    /// Raw tag and value of a CompactLength
+    #[wasm_bindgen(typescript_type = "RawCompactLength")]
+    pub type JsRawCompactLength;
+
     /// Size with dimension type (width and height)
     #[wasm_bindgen(typescript_type = "Size<Dimension>")]
     pub type JsSizeDimension;
@@ -89,6 +147,54 @@ extern "C" {
     #[wasm_bindgen(typescript_type = "Size<LengthPercentage>")]
     pub type JsSizeLengthPercentage;
 
+    /// DOMRect-shaped rectangles of a subtree
+    #[wasm_bindgen(typescript_type = "NodeDomRect[]")]
+    pub type JsNodeDomRects;
+
+    /// Structural problems found by `validateTree()`
+    #[wasm_bindgen(typescript_type = "TreeProblem[]")]
+    pub type JsTreeProblems;
+
+    /// Computed box model (margin, border, padding and content box)
+    #[wasm_bindgen(typescript_type = "BoxModel")]
+    pub type JsBoxModel;
+
+    /// Milliseconds per node recorded by a profiled layout
+    #[wasm_bindgen(typescript_type = "Map<bigint, number>")]
+    pub type JsLayoutProfile;
+
+    /// Scroll positions keyed by scroll container node ID
+    #[wasm_bindgen(typescript_type = "Map<bigint, Point<number>> | undefined")]
+    pub type JsScrollOffsets;
+
+    /// Rect with pixel values
+    #[wasm_bindgen(typescript_type = "Rect<number>")]
+    pub type JsRectNumber;
+
+    /// Gap resolved to pixels
+    #[wasm_bindgen(typescript_type = "ResolvedGap")]
+    pub type JsResolvedGap;
+
+    /// Axes on which `auto` margins absorbed free space
+    #[wasm_bindgen(typescript_type = "AutoMarginApplied")]
+    pub type JsAutoMarginApplied;
+
+    /// Self-alignment of a grid item on both axes
+    #[wasm_bindgen(typescript_type = "ResolvedGridAlignment")]
+    pub type JsResolvedGridAlignment;
+
+    /// Subtree of styles and layouts returned by `toNestedObject()`
+    #[wasm_bindgen(typescript_type = "NestedNode")]
+    pub type JsNestedNode;
+
+    /// Declarative subtree description for `buildTree()`
+    #[wasm_bindgen(typescript_type = "TreeSpec")]
+    pub type JsTreeSpec;
+
+    /// Plain-object snapshot of a style, keyed by property name (see `Style.toObject()`)
+    #[wasm_bindgen(typescript_type = "Record<string, unknown>")]
+    pub type JsStyleObject;
+
     // =========================================================================
     // Optional Enum Types (for consistent getter/setter signatures)
     // =========================================================================
@@ -125,6 +231,10 @@ extern "C" {
     #[wasm_bindgen(typescript_type = "GridTemplateComponent[]")]
     pub type JsGridTemplateComponents;
 
+    /// Structured grid tracks type
+    #[wasm_bindgen(typescript_type = "GridTrack[]")]
+    pub type JsGridTracks;
+
     /// Grid template areas type
     #[wasm_bindgen(typescript_type = "GridTemplateArea[]")]
     pub type JsGridTemplateAreas;
@@ -136,6 +246,100 @@ extern "C" {
     /// Non-repeated grid tracks (for auto-columns/rows)
     #[wasm_bindgen(typescript_type = "TrackSizingFunction[]")]
     pub type JsTrackSizingFunctions;
+
+    /// Resolved auto-placement of a grid item (or null)
+    #[wasm_bindgen(typescript_type = "GridAutoPlacement | null")]
+    pub type JsGridAutoPlacement;
+
+    /// Resolved grid track counts (or null)
+    #[wasm_bindgen(typescript_type = "GridDimensions | null")]
+    pub type JsGridDimensions;
+}
+
+// =============================================================================
+// Viewport Units
+// =============================================================================
+
+thread_local! {
+    /// Viewport used to resolve `vw` / `vh` units (see `setViewport()`)
+    static VIEWPORT: Cell<Option<Size<f32>>> = const { Cell::new(None) };
+}
+
+/// Sets the viewport used to resolve `vw` / `vh` units
+///
+/// Once a viewport is set, length strings such as `"50vw"` or `"30vh"` are
+/// accepted by `Style` setters and converted to pixels against it. Units
+/// are resolved eagerly when the style property is set: changing the
+/// viewport later does not update existing styles, so re-set them after a
+/// viewport change.
+///
+/// Styles are resolved before they belong to any tree, so the viewport is a
+/// module-wide setting rather than a `TaffyTree` method.
+///
+/// @param width - The viewport width in pixels (`100vw`)
+/// @param height - The viewport height in pixels (`100vh`)
+///
+/// @example
+/// ```typescript
+/// setViewport(1280, 720);
+///
+/// const style = new Style();
+/// style.size = { width: "50vw", height: "10vh" };
+/// console.log(style.size); // { width: 640, height: 72 }
+/// ```
+#[wasm_bindgen(js_name = setViewport)]
+pub fn set_viewport(width: f32, height: f32) {
+    VIEWPORT.with(|v| v.set(Some(Size { width, height })));
+}
+
+/// Clears the viewport set by `setViewport()`
+///
+/// Afterwards, `vw` / `vh` units are rejected by `Style` setters again.
+///
+/// @example
+/// ```typescript
+/// setViewport(1280, 720);
+/// clearViewport();
+/// ```
+#[wasm_bindgen(js_name = clearViewport)]
+pub fn clear_viewport() {
+    VIEWPORT.with(|v| v.set(None));
+}
+
+/// Resolves a viewport-relative string such as `"50vw"` or `"30vh"` to pixels
+///
+/// Returns `None` if the string does not end in a viewport unit, and an error
+/// if the number is invalid or no viewport has been configured.
+fn parse_viewport_length<E: de::Error>(value: &str) -> Option<Result<f32, E>> {
+    let (num_str, basis) = if let Some(num_str) = value.strip_suffix("vw") {
+        (num_str, VIEWPORT.with(Cell::get).map(|v| v.width))
+    } else if let Some(num_str) = value.strip_suffix("vh") {
+        (num_str, VIEWPORT.with(Cell::get).map(|v| v.height))
+    } else {
+        return None;
+    };
+    Some(match (num_str.parse::<f32>(), basis) {
+        (Ok(n), Some(basis)) => Ok(n / 100.0 * basis),
+        (Ok(_), None) => Err(E::custom(
+            "Viewport units require a viewport; call setViewport() first",
+        )),
+        (Err(_), _) => Err(E::custom("Invalid viewport unit value")),
+    })
+}
+
+// =============================================================================
+// Conversion Diagnostics
+// =============================================================================
+
+/// Logs that a `CompactLength` could not be represented by a DTO
+///
+/// Values such as `calc()` have no DTO representation and are read back as a
+/// fallback. Logging makes this loss visible instead of silently reporting
+/// a wrong value.
+fn log_lossy_conversion(kind: &str, tag: usize, fallback: &str) {
+    crate::utils::log(&format!(
+        "{kind} conversion: unsupported CompactLength tag {tag} (e.g. calc()) read back as {fallback}"
+    ));
 }
 
 // =============================================================================
@@ -216,15 +420,18 @@ impl<'de> Deserialize<'de> for DimensionDto {
             {
                 if value == "auto" {
                     Ok(DimensionDto::Auto)
-                } else if value.ends_with('%') {
+                } else if let Some(num_str) = value.strip_suffix('%') {
                     // Try parsing the number part
-                    let num_str = &value[..value.len() - 1];
                     match num_str.parse::<f32>() {
                         Ok(p) => Ok(DimensionDto::Percent(p)),
                         Err(_) => Err(E::custom("Invalid percentage value")),
                     }
+                } else if let Some(px) = parse_viewport_length(value) {
+                    px.map(DimensionDto::Length)
                 } else {
-                    Err(E::custom("Expected 'auto' or a string ending with '%'"))
+                    Err(E::custom(
+                        "Expected 'auto' or a string ending with '%', 'vw' or 'vh'",
+                    ))
                 }
             }
         }
@@ -251,12 +458,92 @@ impl From<Dimension> for DimensionDto {
             match d.into_raw().tag() {
                 CompactLength::LENGTH_TAG => DimensionDto::Length(d.value()),
                 CompactLength::PERCENT_TAG => DimensionDto::Percent(d.value() * 100.0),
-                _ => DimensionDto::Auto,
+                tag => {
+                    log_lossy_conversion("Dimension", tag, "\"auto\"");
+                    DimensionDto::Auto
+                }
             }
         }
     }
 }
 
+/// Data Transfer Object exposing the raw tag and value of a `CompactLength`
+///
+/// Unlike the other DTOs this mapping is lossless for every tag, but values are
+/// not scaled: percentages are fractions (0.0 to 1.0).
+#[derive(Serialize, Debug, Clone)]
+pub struct RawCompactLengthDto {
+    /// The `CompactLength` tag (see `CompactLength::*_TAG`)
+    pub tag: u32,
+    /// The numeric payload, as returned by `CompactLength::value()`
+    pub value: f32,
+}
+
+impl From<CompactLength> for RawCompactLengthDto {
+    fn from(l: CompactLength) -> Self {
+        RawCompactLengthDto {
+            tag: l.tag() as u32,
+            value: l.value(),
+        }
+    }
+}
+
+// =============================================================================
+// Dimension Helpers
+// =============================================================================
+
+/// Creates a pixel `Dimension`
+///
+/// Returns `n` unchanged: a bare number always means pixels. Use this to make
+/// that intent explicit next to `percent()` and `auto()`.
+///
+/// @param n - The length in pixels
+///
+/// @returns - The pixel `Dimension`
+///
+/// @example
+/// ```typescript
+/// const style = new Style();
+/// style.size = { width: px(200), height: percent(50) };
+/// ```
+#[wasm_bindgen]
+pub fn px(n: f32) -> JsDimension {
+    serialize(&DimensionDto::Length(n)).unchecked_into()
+}
+
+/// Creates a percentage `Dimension`
+///
+/// Percentages are whole numbers, as in CSS: `percent(50)` is `"50%"`, half
+/// of the parent's size, not `0.5`.
+///
+/// @param n - The percentage, where `100` is the parent's full size
+///
+/// @returns - The percentage `Dimension`, e.g. `"50%"`
+///
+/// @example
+/// ```typescript
+/// const style = new Style();
+/// style.size = { width: percent(100), height: px(40) };
+/// ```
+#[wasm_bindgen]
+pub fn percent(n: f32) -> JsDimension {
+    serialize(&DimensionDto::Percent(n)).unchecked_into()
+}
+
+/// Creates the `auto` `Dimension`
+///
+/// @returns - `"auto"`
+///
+/// @example
+/// ```typescript
+/// const style = new Style();
+/// style.size = { width: auto(), height: px(40) };
+/// ```
+#[wasm_bindgen]
+pub fn auto() -> JsDimension {
+    serialize(&DimensionDto::Auto).unchecked_into()
+}
+
 // =============================================================================
 // LengthPercentage DTO
 // =============================================================================
@@ -324,15 +611,16 @@ impl<'de> Deserialize<'de> for LengthPercentageDto {
             where
                 E: de::Error,
             {
-                if value.ends_with('%') {
+                if let Some(num_str) = value.strip_suffix('%') {
                     // Try parsing the number part
-                    let num_str = &value[..value.len() - 1];
                     match num_str.parse::<f32>() {
                         Ok(p) => Ok(LengthPercentageDto::Percent(p)),
                         Err(_) => Err(E::custom("Invalid percentage value")),
                     }
+                } else if let Some(px) = parse_viewport_length(value) {
+                    px.map(LengthPercentageDto::Length)
                 } else {
-                    Err(E::custom("Expected a string ending with '%'"))
+                    Err(E::custom("Expected a string ending with '%', 'vw' or 'vh'"))
                 }
             }
         }
@@ -356,7 +644,10 @@ impl From<LengthPercentage> for LengthPercentageDto {
         match inner.tag() {
             CompactLength::LENGTH_TAG => LengthPercentageDto::Length(inner.value()),
             CompactLength::PERCENT_TAG => LengthPercentageDto::Percent(inner.value() * 100.0),
-            _ => LengthPercentageDto::Length(0.0),
+            tag => {
+                log_lossy_conversion("LengthPercentage", tag, "0");
+                LengthPercentageDto::Length(0.0)
+            }
         }
     }
 }
@@ -433,15 +724,18 @@ impl<'de> Deserialize<'de> for LengthPercentageAutoDto {
             {
                 if value == "auto" {
                     Ok(LengthPercentageAutoDto::Auto)
-                } else if value.ends_with('%') {
+                } else if let Some(num_str) = value.strip_suffix('%') {
                     // Try parsing the number part
-                    let num_str = &value[..value.len() - 1];
                     match num_str.parse::<f32>() {
                         Ok(p) => Ok(LengthPercentageAutoDto::Percent(p)),
                         Err(_) => Err(E::custom("Invalid percentage value")),
                     }
+                } else if let Some(px) = parse_viewport_length(value) {
+                    px.map(LengthPercentageAutoDto::Length)
                 } else {
-                    Err(E::custom("Expected 'auto' or a string ending with '%'"))
+                    Err(E::custom(
+                        "Expected 'auto' or a string ending with '%', 'vw' or 'vh'",
+                    ))
                 }
             }
         }
@@ -471,7 +765,10 @@ impl From<LengthPercentageAuto> for LengthPercentageAutoDto {
                 CompactLength::PERCENT_TAG => {
                     LengthPercentageAutoDto::Percent(inner.value() * 100.0)
                 }
-                _ => LengthPercentageAutoDto::Auto,
+                tag => {
+                    log_lossy_conversion("LengthPercentageAuto", tag, "\"auto\"");
+                    LengthPercentageAutoDto::Auto
+                }
             }
         }
     }
@@ -545,6 +842,263 @@ mod tests {
         assert_eq!(point.x, taffy::style::Overflow::Hidden);
         assert_eq!(point.y, taffy::style::Overflow::Scroll);
     }
+
+    #[test]
+    fn test_viewport_units() {
+        use serde::de::IntoDeserializer;
+        use serde::de::value::{Error, StrDeserializer};
+
+        let parse = |v: &str| {
+            let de: StrDeserializer<Error> = v.into_deserializer();
+            DimensionDto::deserialize(de)
+        };
+
+        clear_viewport();
+        assert!(parse("50vw").is_err());
+
+        set_viewport(800.0, 600.0);
+        assert!(matches!(parse("50vw"), Ok(DimensionDto::Length(400.0))));
+        assert!(matches!(parse("10vh"), Ok(DimensionDto::Length(60.0))));
+        assert!(matches!(parse("25%"), Ok(DimensionDto::Percent(25.0))));
+        assert!(parse("abcvw").is_err());
+        clear_viewport();
+    }
+
+    #[test]
+    fn test_dimension_auto_is_not_zero() {
+        assert!(matches!(
+            DimensionDto::from(Dimension::auto()),
+            DimensionDto::Auto
+        ));
+        assert!(matches!(
+            DimensionDto::from(Dimension::length(0.0)),
+            DimensionDto::Length(0.0)
+        ));
+        assert!(Dimension::from(DimensionDto::Auto).is_auto());
+        assert!(!Dimension::from(DimensionDto::Length(0.0)).is_auto());
+    }
+
+    #[test]
+    fn test_length_percentage_percent_scaling() {
+        // "10%" is stored by Taffy as the fraction 0.1 and reported back as 10
+        let border: LengthPercentage = LengthPercentageDto::Percent(10.0).into();
+        assert_eq!(border.into_raw().tag(), CompactLength::PERCENT_TAG);
+        assert_eq!(border.into_raw().value(), 0.1);
+
+        match LengthPercentageDto::from(border) {
+            LengthPercentageDto::Percent(p) => assert_eq!(p, 10.0),
+            other => panic!("expected a percentage, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_grid_track_round_trip() {
+        use GridTrackDto::*;
+
+        let px = |value| Box::new(Px { value });
+        let tracks = vec![
+            Px { value: 100.0 },
+            Percent { value: 50.0 },
+            Fr { value: 1.0 },
+            Auto,
+            MinContent,
+            MaxContent,
+            FitContent { limit: px(120.0) },
+            FitContent {
+                limit: Box::new(Percent { value: 25.0 }),
+            },
+            Minmax {
+                min: px(100.0),
+                max: Box::new(Fr { value: 2.0 }),
+            },
+            Repeat {
+                count: RepetitionCountDto::AutoFill,
+                tracks: vec![Px { value: 80.0 }, Fr { value: 1.0 }],
+                line_names: vec![vec!["a".into()], vec![], vec!["b".into()]],
+            },
+        ];
+        let template = GridTrackDto::to_template::<String>(tracks.clone()).unwrap();
+        assert_eq!(GridTrackDto::from_template(&template), tracks);
+
+        // Tracks that are not valid in their position are rejected
+        let invalid = Minmax {
+            min: Box::new(Fr { value: 1.0 }),
+            max: px(10.0),
+        };
+        assert!(GridTrackDto::to_template::<String>(vec![invalid]).is_err());
+        let nested = Repeat {
+            count: RepetitionCountDto::Count(2),
+            tracks: vec![Repeat {
+                count: RepetitionCountDto::Count(2),
+                tracks: vec![],
+                line_names: vec![],
+            }],
+            line_names: vec![],
+        };
+        assert!(GridTrackDto::to_template::<String>(vec![nested]).is_err());
+    }
+
+    #[test]
+    fn test_grid_template_round_trip() {
+        let single = |css: &str| GridTemplateComponentDto::Single(parse_track(css).unwrap());
+        let rows = vec![
+            single("auto"),
+            single("1fr"),
+            single("minmax(100, 1fr)"),
+            GridTemplateComponentDto::parse_repeat("repeat(2, minmax(10px, 2fr) 50%)").unwrap(),
+        ];
+        let template: Vec<GridTemplateComponent<String>> =
+            rows.into_iter().map(Into::into).collect();
+
+        let track = |min, max| TrackSizingFunction { min, max };
+        let expected: Vec<GridTemplateComponent<String>> = vec![
+            GridTemplateComponent::Single(track(
+                MinTrackSizingFunction::auto(),
+                MaxTrackSizingFunction::auto(),
+            )),
+            GridTemplateComponent::Single(track(
+                MinTrackSizingFunction::auto(),
+                MaxTrackSizingFunction::fr(1.0),
+            )),
+            GridTemplateComponent::Single(track(
+                MinTrackSizingFunction::length(100.0),
+                MaxTrackSizingFunction::fr(1.0),
+            )),
+            GridTemplateComponent::Repeat(GridTemplateRepetition {
+                count: RepetitionCount::Count(2),
+                tracks: vec![
+                    track(
+                        MinTrackSizingFunction::length(10.0),
+                        MaxTrackSizingFunction::fr(2.0),
+                    ),
+                    track(
+                        MinTrackSizingFunction::percent(0.5),
+                        MaxTrackSizingFunction::percent(0.5),
+                    ),
+                ],
+                line_names: vec![],
+            }),
+        ];
+        assert_eq!(template, expected);
+
+        let round_trip: Vec<GridTemplateComponent<String>> = template
+            .iter()
+            .cloned()
+            .map(|component| GridTemplateComponentDto::from(component).into())
+            .collect();
+        assert_eq!(round_trip, expected);
+    }
+
+    #[test]
+    fn test_grid_auto_tracks() {
+        use GridTrackDto::*;
+
+        let tracks = vec![
+            Auto,
+            Fr { value: 1.0 },
+            Minmax {
+                min: Box::new(Px { value: 100.0 }),
+                max: Box::new(Percent { value: 50.0 }),
+            },
+        ];
+        let auto_tracks = GridTrackDto::to_auto_tracks(tracks.clone()).unwrap();
+        assert_eq!(
+            auto_tracks[2].max,
+            MaxTrackSizingFunction::percent(0.5),
+            "percentages are scaled"
+        );
+        assert_eq!(GridTrackDto::from_auto_tracks(&auto_tracks), tracks);
+
+        let repeat = Repeat {
+            count: RepetitionCountDto::Count(2),
+            tracks: vec![Px { value: 10.0 }],
+            line_names: vec![],
+        };
+        let error = GridTrackDto::to_auto_tracks(vec![Auto, repeat]).unwrap_err();
+        assert!(error.contains("repeat()"), "{error}");
+    }
+
+    #[test]
+    fn test_parse_repeat_notation() {
+        use GridTrackDto::*;
+
+        let parse = |css: &str| {
+            let component = GridTemplateComponentDto::parse_repeat(css)?;
+            Ok::<_, String>(GridTrackDto::from_template::<String>(&[component.into()]))
+        };
+        assert_eq!(
+            parse("repeat(3, 1fr)").unwrap(),
+            vec![Repeat {
+                count: RepetitionCountDto::Count(3),
+                tracks: vec![Fr { value: 1.0 }],
+                line_names: vec![],
+            }]
+        );
+        assert_eq!(
+            parse(" repeat(auto-fill, minmax(100px, 1fr) 20 fit-content(50px)) ").unwrap(),
+            vec![Repeat {
+                count: RepetitionCountDto::AutoFill,
+                tracks: vec![
+                    Minmax {
+                        min: Box::new(Px { value: 100.0 }),
+                        max: Box::new(Fr { value: 1.0 }),
+                    },
+                    Px { value: 20.0 },
+                    FitContent {
+                        limit: Box::new(Px { value: 50.0 }),
+                    },
+                ],
+                line_names: vec![],
+            }]
+        );
+        assert!(matches!(
+            GridTemplateComponentDto::parse_repeat("repeat(auto-fit, auto min-content)"),
+            Ok(GridTemplateComponentDto::Repeat {
+                count: RepetitionCountDto::AutoFit,
+                ..
+            })
+        ));
+
+        for invalid in [
+            "1fr",
+            "repeat(0, 1fr)",
+            "repeat(2)",
+            "repeat(2, )",
+            "repeat(2, minmax(1fr))",
+            "repeat(2, [a] 1fr)",
+            "repeat(some, 1fr)",
+        ] {
+            assert!(parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_dimension_percent_scaling() {
+        // Every percentage DTO stores "50%" as the fraction 0.5
+        let size: Dimension = DimensionDto::Percent(50.0).into();
+        assert_eq!(size.into_raw().value(), 0.5);
+        assert!(matches!(
+            DimensionDto::from(size),
+            DimensionDto::Percent(50.0)
+        ));
+
+        let margin: LengthPercentageAuto = LengthPercentageAutoDto::Percent(50.0).into();
+        assert_eq!(margin.into_raw().value(), 0.5);
+        assert!(matches!(
+            LengthPercentageAutoDto::from(margin),
+            LengthPercentageAutoDto::Percent(50.0)
+        ));
+    }
+
+    #[test]
+    fn test_raw_compact_length_keeps_unmapped_tags() {
+        let raw = RawCompactLengthDto::from(CompactLength::max_content());
+        assert_eq!(raw.tag as usize, CompactLength::MAX_CONTENT_TAG);
+
+        let raw = RawCompactLengthDto::from(Dimension::percent(0.5).into_raw());
+        assert_eq!(raw.tag as usize, CompactLength::PERCENT_TAG);
+        assert_eq!(raw.value, 0.5);
+    }
 }
 
 // =============================================================================
@@ -626,6 +1180,223 @@ where
     }
 }
 
+impl<T, U> From<Rect<U>> for RectDto<T>
+where
+    U: Into<T>,
+{
+    fn from(v: Rect<U>) -> Self {
+        RectDto {
+            left: v.left.into(),
+            right: v.right.into(),
+            top: v.top.into(),
+            bottom: v.bottom.into(),
+        }
+    }
+}
+
+// =============================================================================
+// Box Model DTOs
+// =============================================================================
+
+/// Data Transfer Object for a positioned rectangle in pixels
+///
+/// @example
+/// ```json
+/// { "x": 10, "y": 10, "width": 80, "height": 30 }
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct LayoutRectDto {
+    /// Horizontal offset in pixels
+    pub x: f32,
+    /// Vertical offset in pixels
+    pub y: f32,
+    /// Width in pixels
+    pub width: f32,
+    /// Height in pixels
+    pub height: f32,
+}
+
+/// Data Transfer Object for the computed CSS box model of a node
+///
+/// Built from a computed [`Layout`]: the margin, border and padding edges
+/// plus the resulting content box.
+///
+/// @example
+/// ```json
+/// {
+///   "margin": { "left": 0, "right": 0, "top": 0, "bottom": 0 },
+///   "border": { "left": 1, "right": 1, "top": 1, "bottom": 1 },
+///   "padding": { "left": 10, "right": 10, "top": 10, "bottom": 10 },
+///   "content": { "x": 11, "y": 11, "width": 78, "height": 28 }
+/// }
+/// ```
+#[derive(Serialize, Debug, Clone)]
+pub struct BoxModelDto {
+    /// Computed margin widths
+    pub margin: RectDto<f32>,
+    /// Computed border widths
+    pub border: RectDto<f32>,
+    /// Computed padding widths
+    pub padding: RectDto<f32>,
+    /// The content box, relative to the parent like `Layout.x` / `Layout.y`
+    pub content: LayoutRectDto,
+}
+
+impl From<&Layout> for BoxModelDto {
+    fn from(layout: &Layout) -> Self {
+        BoxModelDto {
+            margin: layout.margin.into(),
+            border: layout.border.into(),
+            padding: layout.padding.into(),
+            content: LayoutRectDto {
+                x: layout.content_box_x(),
+                y: layout.content_box_y(),
+                width: layout.content_box_width(),
+                height: layout.content_box_height(),
+            },
+        }
+    }
+}
+
+/// Data Transfer Object for a node's border box in DOMRect shape
+///
+/// Serialize with `serialize_with_node_ids` so that `node_id` becomes a `bigint`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DomRectDto {
+    /// The node the rectangle belongs to
+    pub node_id: u64,
+    /// Left edge in absolute coordinates
+    pub x: f32,
+    /// Top edge in absolute coordinates
+    pub y: f32,
+    /// Width in pixels
+    pub width: f32,
+    /// Height in pixels
+    pub height: f32,
+    /// Top edge (same as `y`)
+    pub top: f32,
+    /// Right edge (`x + width`)
+    pub right: f32,
+    /// Bottom edge (`y + height`)
+    pub bottom: f32,
+    /// Left edge (same as `x`)
+    pub left: f32,
+}
+
+impl DomRectDto {
+    /// Builds the rectangle of a node at an absolute `location` with `size`
+    pub fn new(node_id: u64, location: Point<f32>, size: Size<f32>) -> Self {
+        DomRectDto {
+            node_id,
+            x: location.x,
+            y: location.y,
+            width: size.width,
+            height: size.height,
+            top: location.y,
+            right: location.x + size.width,
+            bottom: location.y + size.height,
+            left: location.x,
+        }
+    }
+}
+
+/// Data Transfer Object for a container's gap resolved to pixels
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ResolvedGapDto {
+    /// Space between rows (from `gap.height`)
+    pub row: f32,
+    /// Space between columns (from `gap.width`)
+    pub column: f32,
+}
+
+/// Data Transfer Object for the axes on which `auto` margins took up space
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct AutoMarginAppliedDto {
+    /// An `auto` left or right margin resolved to a non-zero size
+    pub horizontal: bool,
+    /// An `auto` top or bottom margin resolved to a non-zero size
+    pub vertical: bool,
+}
+
+/// Data Transfer Object for the self-alignment a grid item is laid out with
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedGridAlignmentDto {
+    /// Inline-axis alignment (AlignSelf enum discriminant)
+    pub justify_self: u8,
+    /// Block-axis alignment (AlignSelf enum discriminant)
+    pub align_self: u8,
+}
+
+// =============================================================================
+// Measure Result DTO
+// =============================================================================
+
+/// Data Transfer Object for the value returned by a measure function
+///
+/// `baseline` is optional, so plain `Size<number>` results remain valid.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct MeasuredSizeDto {
+    pub width: f32,
+    pub height: f32,
+    #[serde(default)]
+    pub baseline: Option<f32>,
+}
+
+// =============================================================================
+// Scroll Offset DTO
+// =============================================================================
+
+/// Data Transfer Object for a scroll container's scroll position
+///
+/// @example
+/// ```json
+/// { "x": 0, "y": 120 }
+/// ```
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct ScrollOffsetDto {
+    pub x: f32,
+    pub y: f32,
+}
+
+// =============================================================================
+// Slow Layout DTO
+// =============================================================================
+
+/// Data Transfer Object describing a layout run that exceeded its time budget
+///
+/// Serialize with `serialize_with_node_ids` so that `node` becomes a `bigint`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SlowLayoutInfoDto {
+    /// The root node the layout was computed for
+    pub node: u64,
+    /// How long the layout took, in milliseconds
+    pub duration_ms: f64,
+    /// The configured threshold, in milliseconds
+    pub threshold_ms: f64,
+    /// Number of nodes in the laid-out subtree
+    pub node_count: u32,
+}
+
+// =============================================================================
+// Tree Problem DTO
+// =============================================================================
+
+/// Data Transfer Object describing a structural problem in a tree
+///
+/// Serialize with `serialize_with_node_ids` so that `node` becomes a `bigint`.
+#[derive(Serialize, Debug, Clone)]
+pub struct TreeProblemDto {
+    /// The kind of problem, e.g. `"cycle"`
+    pub kind: &'static str,
+    /// The node the problem was found at
+    pub node: u64,
+    /// A human-readable description
+    pub message: String,
+}
+
 // =============================================================================
 // Available Space DTOs
 // =============================================================================
@@ -729,6 +1500,15 @@ impl From<AvailableSizeDto> for Size<AvailableSpace> {
     }
 }
 
+impl From<Size<AvailableSpace>> for AvailableSizeDto {
+    fn from(s: Size<AvailableSpace>) -> Self {
+        AvailableSizeDto {
+            width: s.width.into(),
+            height: s.height.into(),
+        }
+    }
+}
+
 impl From<AvailableSpaceDto> for AvailableSpace {
     fn from(s: AvailableSpaceDto) -> Self {
         match s {
@@ -782,6 +1562,22 @@ pub struct DetailedGridItemsInfoDto {
     pub column_end: u16,
 }
 
+/// DTO for the start lines chosen by the grid auto-placement algorithm
+///
+/// Line numbers are 1-indexed CSS grid lines of the explicit grid.
+#[derive(Serialize)]
+pub struct GridAutoPlacementDto {
+    pub row: i32,
+    pub column: i32,
+}
+
+/// DTO for the total number of resolved tracks (explicit + implicit) in a grid
+#[derive(Serialize)]
+pub struct GridDimensionsDto {
+    pub columns: u32,
+    pub rows: u32,
+}
+
 // =============================================================================
 // Grid Placement DTOs
 // =============================================================================
@@ -949,8 +1745,8 @@ impl From<GridPlacementDto> for GridPlacement {
             GridPlacementDto::Auto => GridPlacement::Auto,
             GridPlacementDto::Line(idx) => GridPlacement::from_line_index(idx),
             GridPlacementDto::Span(span) => GridPlacement::from_span(span),
-            GridPlacementDto::NamedLine(idx, s) => GridPlacement::NamedLine(s.into(), idx), // NamedLine variant
-            GridPlacementDto::NamedSpan(n, s) => GridPlacement::NamedSpan(s.into(), n),
+            GridPlacementDto::NamedLine(idx, s) => GridPlacement::NamedLine(s, idx), // NamedLine variant
+            GridPlacementDto::NamedSpan(n, s) => GridPlacement::NamedSpan(s, n),
         }
     }
 }
@@ -1088,8 +1884,8 @@ impl Serialize for MaxTrackSizingFunctionDto {
             Self::Length(v) => serializer.serialize_f32(*v),
             Self::Percent(v) => serializer.serialize_str(&format!("{}%", v)),
             Self::Fraction(v) => serializer.serialize_str(&format!("{}fr", v)),
-            Self::FitContent(_) => serializer.serialize_str("fit-content"),
-            Self::FitContentPercent(_) => serializer.serialize_str("fit-content"),
+            Self::FitContent(v) => serializer.serialize_str(&format!("fit-content({}px)", v)),
+            Self::FitContentPercent(v) => serializer.serialize_str(&format!("fit-content({}%)", v)),
             Self::Auto => serializer.serialize_str("auto"),
             Self::MinContent => serializer.serialize_str("min-content"),
             Self::MaxContent => serializer.serialize_str("max-content"),
@@ -1135,6 +1931,17 @@ impl<'de> Deserialize<'de> for MaxTrackSizingFunctionDto {
                     "min-content" => Ok(MaxTrackSizingFunctionDto::MinContent),
                     "max-content" => Ok(MaxTrackSizingFunctionDto::MaxContent),
                     "fit-content" => Ok(MaxTrackSizingFunctionDto::FitContent(0.0)),
+                    s if s.starts_with("fit-content(") && s.ends_with(')') => {
+                        let limit = &s["fit-content(".len()..s.len() - 1];
+                        if let Some(num) = limit.strip_suffix('%') {
+                            let num = num.parse::<f32>().map_err(E::custom)?;
+                            Ok(MaxTrackSizingFunctionDto::FitContentPercent(num))
+                        } else {
+                            let num = limit.strip_suffix("px").unwrap_or(limit);
+                            let num = num.parse::<f32>().map_err(E::custom)?;
+                            Ok(MaxTrackSizingFunctionDto::FitContent(num))
+                        }
+                    }
                     s if s.ends_with("fr") => {
                         let num = s[..s.len() - 2].parse::<f32>().map_err(E::custom)?;
                         Ok(MaxTrackSizingFunctionDto::Fraction(num))
@@ -1151,7 +1958,7 @@ impl<'de> Deserialize<'de> for MaxTrackSizingFunctionDto {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum RepetitionCountDto {
     Count(u16),
     AutoFill,
@@ -1231,7 +2038,11 @@ pub struct GridTemplateAreaDto {
     pub column_end: u16,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Data Transfer Object for a grid template component
+///
+/// Serializes to the object form. Deserializes from the object form or from
+/// CSS `repeat()` notation (see [`GridTemplateComponentDto::parse_repeat`]).
+#[derive(Serialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum GridTemplateComponentDto {
     Single(TrackSizingFunctionDto),
@@ -1244,6 +2055,40 @@ pub enum GridTemplateComponentDto {
     },
 }
 
+impl<'de> Deserialize<'de> for GridTemplateComponentDto {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Input {
+            Css(String),
+            Single(TrackSizingFunctionDto),
+            Repeat {
+                count: RepetitionCountDto,
+                tracks: Vec<TrackSizingFunctionDto>,
+                #[serde(default, rename = "lineNames")]
+                line_names: Vec<Vec<String>>,
+            },
+        }
+
+        Ok(match Input::deserialize(deserializer)? {
+            Input::Css(css) => Self::parse_repeat(&css).map_err(de::Error::custom)?,
+            Input::Single(track) => Self::Single(track),
+            Input::Repeat {
+                count,
+                tracks,
+                line_names,
+            } => Self::Repeat {
+                count,
+                tracks,
+                line_names,
+            },
+        })
+    }
+}
+
 use taffy::style::{
     CheapCloneStr, GridTemplateArea, GridTemplateComponent, GridTemplateRepetition,
     MaxTrackSizingFunction, MinTrackSizingFunction, RepetitionCount, TrackSizingFunction,
@@ -1285,7 +2130,7 @@ impl From<MinTrackSizingFunction> for MinTrackSizingFunctionDto {
         let raw = val.into_raw();
         match raw.tag() {
             CompactLength::LENGTH_TAG => MinTrackSizingFunctionDto::Length(raw.value()),
-            CompactLength::PERCENT_TAG => MinTrackSizingFunctionDto::Percent(raw.value()),
+            CompactLength::PERCENT_TAG => MinTrackSizingFunctionDto::Percent(raw.value() * 100.0),
             CompactLength::AUTO_TAG => MinTrackSizingFunctionDto::Auto,
             CompactLength::MIN_CONTENT_TAG => MinTrackSizingFunctionDto::MinContent,
             CompactLength::MAX_CONTENT_TAG => MinTrackSizingFunctionDto::MaxContent,
@@ -1298,7 +2143,7 @@ impl From<MinTrackSizingFunctionDto> for MinTrackSizingFunction {
     fn from(val: MinTrackSizingFunctionDto) -> Self {
         match val {
             MinTrackSizingFunctionDto::Length(v) => MinTrackSizingFunction::length(v),
-            MinTrackSizingFunctionDto::Percent(v) => MinTrackSizingFunction::percent(v),
+            MinTrackSizingFunctionDto::Percent(v) => MinTrackSizingFunction::percent(v / 100.0),
             MinTrackSizingFunctionDto::Auto => MinTrackSizingFunction::auto(),
             MinTrackSizingFunctionDto::MinContent => MinTrackSizingFunction::min_content(),
             MinTrackSizingFunctionDto::MaxContent => MinTrackSizingFunction::max_content(),
@@ -1312,11 +2157,11 @@ impl From<MaxTrackSizingFunction> for MaxTrackSizingFunctionDto {
         let raw = val.into_raw();
         match raw.tag() {
             CompactLength::LENGTH_TAG => MaxTrackSizingFunctionDto::Length(raw.value()),
-            CompactLength::PERCENT_TAG => MaxTrackSizingFunctionDto::Percent(raw.value()),
+            CompactLength::PERCENT_TAG => MaxTrackSizingFunctionDto::Percent(raw.value() * 100.0),
             CompactLength::FR_TAG => MaxTrackSizingFunctionDto::Fraction(raw.value()),
             CompactLength::FIT_CONTENT_PX_TAG => MaxTrackSizingFunctionDto::FitContent(raw.value()),
             CompactLength::FIT_CONTENT_PERCENT_TAG => {
-                MaxTrackSizingFunctionDto::FitContentPercent(raw.value())
+                MaxTrackSizingFunctionDto::FitContentPercent(raw.value() * 100.0)
             }
             CompactLength::AUTO_TAG => MaxTrackSizingFunctionDto::Auto,
             CompactLength::MIN_CONTENT_TAG => MaxTrackSizingFunctionDto::MinContent,
@@ -1330,11 +2175,11 @@ impl From<MaxTrackSizingFunctionDto> for MaxTrackSizingFunction {
     fn from(val: MaxTrackSizingFunctionDto) -> Self {
         match val {
             MaxTrackSizingFunctionDto::Length(v) => MaxTrackSizingFunction::length(v),
-            MaxTrackSizingFunctionDto::Percent(v) => MaxTrackSizingFunction::percent(v),
+            MaxTrackSizingFunctionDto::Percent(v) => MaxTrackSizingFunction::percent(v / 100.0),
             MaxTrackSizingFunctionDto::Fraction(v) => MaxTrackSizingFunction::fr(v),
             MaxTrackSizingFunctionDto::FitContent(v) => MaxTrackSizingFunction::fit_content_px(v),
             MaxTrackSizingFunctionDto::FitContentPercent(v) => {
-                MaxTrackSizingFunction::fit_content_percent(v)
+                MaxTrackSizingFunction::fit_content_percent(v / 100.0)
             }
             MaxTrackSizingFunctionDto::Auto => MaxTrackSizingFunction::auto(),
             MaxTrackSizingFunctionDto::MinContent => MaxTrackSizingFunction::min_content(),
@@ -1438,3 +2283,330 @@ where
         }
     }
 }
+
+// =============================================================================
+// Grid repeat() Notation
+// =============================================================================
+
+impl GridTemplateComponentDto {
+    /// Parses CSS `repeat()` notation, e.g. `repeat(3, 1fr)` or
+    /// `repeat(auto-fill, minmax(100px, 1fr))`
+    ///
+    /// The count is a positive integer, `auto-fill` or `auto-fit`. Each track
+    /// is a lone track size (`100px`, `100`, `50%`, `1fr`, `auto`,
+    /// `min-content`, `max-content` or `fit-content(...)`) or a
+    /// `minmax(min, max)` pair, separated by whitespace. Line names
+    /// (`[name]`) are not supported.
+    pub fn parse_repeat(css: &str) -> Result<Self, String> {
+        let args = css
+            .trim()
+            .strip_prefix("repeat(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(|| format!("Expected repeat(count, tracks), got: {css}"))?;
+        let [count, tracks] = split_top_level(args, |c| c == ',')[..] else {
+            return Err(format!("Expected repeat(count, tracks), got: {css}"));
+        };
+        let count = match count {
+            "auto-fill" => RepetitionCountDto::AutoFill,
+            "auto-fit" => RepetitionCountDto::AutoFit,
+            count => match count.parse::<u16>() {
+                Ok(n) if n > 0 => RepetitionCountDto::Count(n),
+                _ => return Err(format!("Invalid repeat() count: {count}")),
+            },
+        };
+        let tracks = split_top_level(tracks, char::is_whitespace)
+            .into_iter()
+            .map(parse_track)
+            .collect::<Result<Vec<_>, _>>()?;
+        if tracks.is_empty() {
+            return Err(format!("repeat() has no tracks: {css}"));
+        }
+        Ok(Self::Repeat {
+            count,
+            tracks,
+            line_names: Vec::new(),
+        })
+    }
+}
+
+/// Splits `s` at separators outside parentheses, dropping empty parts
+fn split_top_level(s: &str, is_separator: fn(char) -> bool) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if depth == 0 && is_separator(c) => {
+                parts.push(s[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// Parses one track of a `repeat()` track list
+fn parse_track(token: &str) -> Result<TrackSizingFunctionDto, String> {
+    if let Some(args) = token
+        .strip_prefix("minmax(")
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        let [min, max] = split_top_level(args, |c| c == ',')[..] else {
+            return Err(format!("Expected minmax(min, max), got: {token}"));
+        };
+        return Ok(TrackSizingFunctionDto {
+            min: parse_track_value(min)?,
+            max: parse_track_value(max)?,
+        });
+    }
+
+    // A lone track size means minmax(size, size), except that flexible and
+    // fit-content sizes have an automatic minimum
+    let max: MaxTrackSizingFunctionDto = parse_track_value(token)?;
+    let min = match max {
+        MaxTrackSizingFunctionDto::Fraction(_)
+        | MaxTrackSizingFunctionDto::FitContent(_)
+        | MaxTrackSizingFunctionDto::FitContentPercent(_) => MinTrackSizingFunctionDto::Auto,
+        _ => parse_track_value(token)?,
+    };
+    Ok(TrackSizingFunctionDto { min, max })
+}
+
+/// Parses a min or max track sizing function, also accepting `px` lengths
+fn parse_track_value<T: for<'de> Deserialize<'de>>(value: &str) -> Result<T, String> {
+    use serde::de::IntoDeserializer;
+    use serde::de::value::Error;
+
+    let length = value.strip_suffix("px").unwrap_or(value);
+    let result = match length.parse::<f32>() {
+        Ok(px) => T::deserialize(IntoDeserializer::<Error>::into_deserializer(px)),
+        Err(_) => T::deserialize(IntoDeserializer::<Error>::into_deserializer(value)),
+    };
+    result.map_err(|e| e.to_string())
+}
+
+// =============================================================================
+// Structured Grid Track DTOs
+// =============================================================================
+
+/// Data Transfer Object for a grid track in the structured `GridTrack` schema
+///
+/// Every track is an object tagged by `kind`. A single-value track has the CSS
+/// meaning of a lone track size: `{ kind: "px", value: 100 }` is
+/// `minmax(100px, 100px)` and `{ kind: "fr", value: 1 }` is `minmax(auto, 1fr)`.
+/// Any other min/max pair is a `minmax` track whose bounds are single-value
+/// tracks. Percentages use the same `0`-`100` scale as `"50%"` strings.
+///
+/// @example
+/// ```json
+/// { "kind": "px", "value": 100 }
+/// { "kind": "fr", "value": 1 }
+/// { "kind": "fit-content", "limit": { "kind": "percent", "value": 50 } }
+/// { "kind": "minmax", "min": { "kind": "px", "value": 100 }, "max": { "kind": "fr", "value": 1 } }
+/// { "kind": "repeat", "count": "auto-fill", "tracks": [{ "kind": "px", "value": 80 }] }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum GridTrackDto {
+    /// A fixed size in pixels
+    Px { value: f32 },
+    /// A percentage of the grid container's size
+    Percent { value: f32 },
+    /// A share of the remaining free space
+    Fr { value: f32 },
+    /// Sized by the track's items, stretching into free space
+    Auto,
+    /// The largest min-content contribution of the track's items
+    MinContent,
+    /// The largest max-content contribution of the track's items
+    MaxContent,
+    /// `fit-content(limit)`, where `limit` is a `px` or `percent` track
+    FitContent { limit: Box<GridTrackDto> },
+    /// `minmax(min, max)`
+    Minmax {
+        min: Box<GridTrackDto>,
+        max: Box<GridTrackDto>,
+    },
+    /// `repeat(count, tracks)`; `tracks` may not contain other repeats
+    Repeat {
+        count: RepetitionCountDto,
+        tracks: Vec<GridTrackDto>,
+        #[serde(default, rename = "lineNames")]
+        line_names: Vec<Vec<String>>,
+    },
+}
+
+impl GridTrackDto {
+    /// Converts a track used as the minimum of a `minmax` pair
+    fn min_sizing(&self) -> Option<MinTrackSizingFunction> {
+        Some(match self {
+            Self::Px { value } => MinTrackSizingFunction::length(*value),
+            Self::Percent { value } => MinTrackSizingFunction::percent(*value / 100.0),
+            Self::Auto => MinTrackSizingFunction::auto(),
+            Self::MinContent => MinTrackSizingFunction::min_content(),
+            Self::MaxContent => MinTrackSizingFunction::max_content(),
+            _ => return None,
+        })
+    }
+
+    /// Converts a track used as the maximum of a `minmax` pair
+    fn max_sizing(&self) -> Option<MaxTrackSizingFunction> {
+        Some(match self {
+            Self::Px { value } => MaxTrackSizingFunction::length(*value),
+            Self::Percent { value } => MaxTrackSizingFunction::percent(*value / 100.0),
+            Self::Fr { value } => MaxTrackSizingFunction::fr(*value),
+            Self::Auto => MaxTrackSizingFunction::auto(),
+            Self::MinContent => MaxTrackSizingFunction::min_content(),
+            Self::MaxContent => MaxTrackSizingFunction::max_content(),
+            Self::FitContent { limit } => match **limit {
+                Self::Px { value } => MaxTrackSizingFunction::fit_content_px(value),
+                Self::Percent { value } => {
+                    MaxTrackSizingFunction::fit_content_percent(value / 100.0)
+                }
+                _ => return None,
+            },
+            _ => return None,
+        })
+    }
+
+    /// Converts a non-repeated track, or returns `None` if it is invalid
+    fn to_sizing(&self) -> Option<TrackSizingFunction> {
+        let (min, max) = match self {
+            Self::Minmax { min, max } => (min.min_sizing()?, max.max_sizing()?),
+            Self::Fr { .. } | Self::FitContent { .. } => {
+                (MinTrackSizingFunction::auto(), self.max_sizing()?)
+            }
+            Self::Repeat { .. } => return None,
+            _ => (self.min_sizing()?, self.max_sizing()?),
+        };
+        Some(TrackSizingFunction { min, max })
+    }
+
+    /// Converts a minimum sizing function to its single-value track
+    fn from_min(val: MinTrackSizingFunction) -> Self {
+        match MinTrackSizingFunctionDto::from(val) {
+            MinTrackSizingFunctionDto::Length(value) => Self::Px { value },
+            MinTrackSizingFunctionDto::Percent(value) => Self::Percent { value },
+            MinTrackSizingFunctionDto::Auto => Self::Auto,
+            MinTrackSizingFunctionDto::MinContent => Self::MinContent,
+            MinTrackSizingFunctionDto::MaxContent => Self::MaxContent,
+        }
+    }
+
+    /// Converts a maximum sizing function to its single-value track
+    fn from_max(val: MaxTrackSizingFunction) -> Self {
+        match MaxTrackSizingFunctionDto::from(val) {
+            MaxTrackSizingFunctionDto::Length(value) => Self::Px { value },
+            MaxTrackSizingFunctionDto::Percent(value) => Self::Percent { value },
+            MaxTrackSizingFunctionDto::Fraction(value) => Self::Fr { value },
+            MaxTrackSizingFunctionDto::FitContent(value) => Self::FitContent {
+                limit: Box::new(Self::Px { value }),
+            },
+            MaxTrackSizingFunctionDto::FitContentPercent(value) => Self::FitContent {
+                limit: Box::new(Self::Percent { value }),
+            },
+            MaxTrackSizingFunctionDto::Auto => Self::Auto,
+            MaxTrackSizingFunctionDto::MinContent => Self::MinContent,
+            MaxTrackSizingFunctionDto::MaxContent => Self::MaxContent,
+        }
+    }
+
+    /// Converts a track sizing function, using a single-value kind where possible
+    fn from_sizing(val: TrackSizingFunction) -> Self {
+        let min = Self::from_min(val.min);
+        let max = Self::from_max(val.max);
+        match (&min, &max) {
+            (Self::Auto, Self::Fr { .. } | Self::FitContent { .. }) => max,
+            _ if min == max => min,
+            _ => Self::Minmax {
+                min: Box::new(min),
+                max: Box::new(max),
+            },
+        }
+    }
+
+    /// Converts a grid template to structured tracks
+    pub fn from_template<S: CheapCloneStr>(template: &[GridTemplateComponent<S>]) -> Vec<Self>
+    where
+        String: From<S>,
+    {
+        template
+            .iter()
+            .cloned()
+            .map(|component| match component {
+                GridTemplateComponent::Single(track) => Self::from_sizing(track),
+                GridTemplateComponent::Repeat(rep) => Self::Repeat {
+                    count: rep.count.into(),
+                    tracks: rep.tracks.into_iter().map(Self::from_sizing).collect(),
+                    line_names: rep
+                        .line_names
+                        .into_iter()
+                        .map(|names| names.into_iter().map(String::from).collect())
+                        .collect(),
+                },
+            })
+            .collect()
+    }
+
+    /// Converts structured tracks to a grid template, or describes the first
+    /// invalid track
+    pub fn to_template<S: CheapCloneStr + From<String>>(
+        tracks: Vec<Self>,
+    ) -> Result<Vec<GridTemplateComponent<S>>, String> {
+        tracks
+            .into_iter()
+            .map(|track| match track {
+                Self::Repeat {
+                    count,
+                    tracks,
+                    line_names,
+                } => Ok(GridTemplateComponent::Repeat(GridTemplateRepetition {
+                    count: count.into(),
+                    tracks: tracks
+                        .iter()
+                        .map(|track| {
+                            track
+                                .to_sizing()
+                                .ok_or_else(|| format!("Invalid track: {track:?}"))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                    line_names: line_names
+                        .into_iter()
+                        .map(|names| names.into_iter().map(S::from).collect())
+                        .collect(),
+                })),
+                track => track
+                    .to_sizing()
+                    .map(GridTemplateComponent::Single)
+                    .ok_or_else(|| format!("Invalid track: {track:?}")),
+            })
+            .collect()
+    }
+
+    /// Converts implicit (`grid-auto-*`) tracks to structured tracks
+    pub fn from_auto_tracks(tracks: &[TrackSizingFunction]) -> Vec<Self> {
+        tracks.iter().cloned().map(Self::from_sizing).collect()
+    }
+
+    /// Converts structured tracks to implicit (`grid-auto-*`) tracks
+    ///
+    /// Implicit tracks cannot repeat, so `repeat` tracks are rejected.
+    pub fn to_auto_tracks(tracks: Vec<Self>) -> Result<Vec<TrackSizingFunction>, String> {
+        tracks
+            .iter()
+            .map(|track| match track {
+                Self::Repeat { .. } => {
+                    Err("repeat() is not allowed in implicit (grid-auto-*) tracks".to_string())
+                }
+                track => track
+                    .to_sizing()
+                    .ok_or_else(|| format!("Invalid track: {track:?}")),
+            })
+            .collect()
+    }
+}
@@ -114,6 +114,12 @@ export type Size<T> = {
  *
  * @returns - The measured size of the content in pixels
  *
+ * @remarks
+ * Measurements are cached until the node is marked dirty. If the result depends
+ * on state outside the tree (e.g. a font that has just loaded), store a `version`
+ * on the node's context and bump it to force re-measurement; see
+ * `computeLayoutWithMeasure()` for the full contract.
+ *
  * @example
  * ```typescript
  * import init, { TaffyTree, Style, type MeasureFunction, type Size } from 'taffy-js';
@@ -163,7 +169,19 @@ export type MeasureFunction = (
   node: bigint,
   context: any,
   style: Style,
-) => Size<number>;
+) => MeasuredSize;
+
+/**
+ * Result of a `MeasureFunction`.
+ *
+ * @property baseline - Optional distance in pixels from the top of the content
+ *   to its first baseline, read back with `TaffyTree.measuredBaseline()`. Taffy
+ *   0.9 does not use it for `AlignItems.Baseline`.
+ */
+export type MeasuredSize = Size<number> & {
+  /** Distance from the top of the content to its first baseline, in pixels */
+  baseline?: number;
+};
 
 /**
  * Dimension type supporting length, percentage, or auto values.
@@ -173,6 +191,8 @@ export type MeasureFunction = (
  * @remarks
  * - `number`: Fixed size in pixels
  * - `"{number}%"`: Percentage of parent's size (0-100)
+ * - `"{number}vw"` / `"{number}vh"`: Percentage of the viewport set with
+ *   `setViewport()`, resolved to pixels when the style is set
  * - `"auto"`: Size determined by content or layout algorithm
  *
  * @example
@@ -200,7 +220,35 @@ export type MeasureFunction = (
  * style.size = fixedSize;
  * ```
  */
-export type Dimension = number | `${number}%` | "auto";
+export type Dimension = number | `${number}%` | `${number}vw` | `${number}vh` | "auto";
+
+/**
+ * Raw tag and value of an internal Taffy `CompactLength`.
+ *
+ * Returned by `Style.rawWidth()` for tooling that needs to tell apart values
+ * that the {@link Dimension} mapping cannot represent.
+ *
+ * @remarks
+ * Tag constants:
+ * - `0`: calc() (only when Taffy's `calc` feature is enabled)
+ * - `1`: length in pixels
+ * - `2`: percentage, as a fraction (`0.5` is 50%)
+ * - `3`: auto
+ * - `4`: fr
+ * - `7`: min-content
+ * - `15`: max-content
+ * - `23`: fit-content with a pixel limit
+ * - `31`: fit-content with a percentage limit
+ *
+ * @property tag - The `CompactLength` tag
+ * @property value - The numeric payload (unscaled)
+ */
+export type RawCompactLength = {
+  /** The `CompactLength` tag */
+  tag: number;
+  /** The numeric payload (unscaled) */
+  value: number;
+};
 
 /**
  * Length or percentage value (no auto support).
@@ -210,6 +258,8 @@ export type Dimension = number | `${number}%` | "auto";
  * @remarks
  * - `number`: Fixed size in pixels
  * - `"{number}%"`: Percentage of parent's size (0-100)
+ * - `"{number}vw"` / `"{number}vh"`: Percentage of the viewport set with
+ *   `setViewport()`, resolved to pixels when the style is set
  *
  * @example
  * ```typescript
@@ -233,7 +283,7 @@ export type Dimension = number | `${number}%` | "auto";
  * style.gap = gap;
  * ```
  */
-export type LengthPercentage = number | `${number}%`;
+export type LengthPercentage = number | `${number}%` | `${number}vw` | `${number}vh`;
 
 /**
  * Length, percentage, or auto value.
@@ -243,6 +293,8 @@ export type LengthPercentage = number | `${number}%`;
  * @remarks
  * - `number`: Fixed size in pixels
  * - `"{number}%"`: Percentage of parent's size (0-100)
+ * - `"{number}vw"` / `"{number}vh"`: Percentage of the viewport set with
+ *   `setViewport()`, resolved to pixels when the style is set
  * - `"auto"`: Automatic value (behavior depends on property)
  *
  * @example
@@ -262,7 +314,7 @@ export type LengthPercentage = number | `${number}%`;
  * style.margin = centerMargin;
  * ```
  */
-export type LengthPercentageAuto = number | `${number}%` | "auto";
+export type LengthPercentageAuto = number | `${number}%` | `${number}vw` | `${number}vh` | "auto";
 
 /**
  * Point with x and y coordinates/values.
@@ -345,6 +397,238 @@ export type Rect<T> = {
   bottom: T;
 };
 
+/**
+ * Information about a layout run that exceeded its time budget.
+ *
+ * Passed to the callback registered with `setSlowLayoutThreshold()`.
+ *
+ * @property node - The root node the layout was computed for
+ * @property durationMs - How long the layout took, in milliseconds
+ * @property thresholdMs - The configured threshold, in milliseconds
+ * @property nodeCount - Number of nodes in the laid-out subtree
+ */
+export type SlowLayoutInfo = {
+  /** The root node the layout was computed for */
+  node: bigint;
+  /** How long the layout took, in milliseconds */
+  durationMs: number;
+  /** The configured threshold, in milliseconds */
+  thresholdMs: number;
+  /** Number of nodes in the laid-out subtree */
+  nodeCount: number;
+};
+
+/**
+ * A structural problem found by `TaffyTree.validateTree()`.
+ *
+ * - `"cycle"`: the node is its own descendant
+ * - `"multiple-parents"`: the node is listed as a child of several nodes
+ * - `"parent-mismatch"`: the node's parent does not list it as a child, or it
+ *   is listed as a child but has no parent
+ * - `"orphan"`: the node's parent has been removed
+ * - `"dangling-child"`: the node lists a removed node as a child
+ *
+ * @property kind - The kind of problem
+ * @property node - The node the problem was found at
+ * @property message - A human-readable description
+ */
+export type TreeProblem = {
+  /** The kind of problem */
+  kind:
+    | "cycle"
+    | "multiple-parents"
+    | "parent-mismatch"
+    | "orphan"
+    | "dangling-child";
+  /** The node the problem was found at */
+  node: bigint;
+  /** A human-readable description */
+  message: string;
+};
+
+/**
+ * Declarative description of a subtree, passed to `TaffyTree.buildTree()`.
+ *
+ * @property style - Style properties by name, as accepted by `setStyleProperty()`,
+ *   applied over the tree's default style (see `setDefaultStyle()`)
+ * @property context - Optional context value, as passed to `setNodeContext()`
+ * @property children - Child specs, in order
+ *
+ * @example
+ * ```typescript
+ * const spec: TreeSpec = {
+ *   style: { display: Display.Flex, gap: { width: 8, height: 0 } },
+ *   children: [
+ *     { style: { flexGrow: 1 } },
+ *     { style: { size: { width: 100, height: 40 } }, context: { text: "OK" } },
+ *   ],
+ * };
+ * ```
+ */
+export type TreeSpec = {
+  /** Style properties by name, applied over the tree's default style */
+  style?: Record<string, unknown>;
+  /** Optional context value, as passed to `setNodeContext()` */
+  context?: any;
+  /** Child specs, in order */
+  children?: TreeSpec[];
+};
+
+/**
+ * A node of the subtree returned by `TaffyTree.toNestedObject()`.
+ *
+ * @property id - The node ID
+ * @property style - Properties that differ from their defaults, in the shape
+ *   accepted by `setStyleProperty()` and `TreeSpec.style`
+ * @property layout - Position relative to the parent, and size, in pixels
+ * @property children - The child nodes, in order
+ */
+export type NestedNode = {
+  /** The node ID */
+  id: bigint;
+  /** Properties that differ from their defaults */
+  style: Record<string, unknown>;
+  /** Position relative to the parent, and size, in pixels */
+  layout: LayoutRect;
+  /** The child nodes, in order */
+  children: NestedNode[];
+};
+
+/**
+ * Positioned rectangle in pixels.
+ *
+ * @property x - The horizontal offset
+ * @property y - The vertical offset
+ * @property width - The width
+ * @property height - The height
+ */
+export type LayoutRect = {
+  /** The horizontal offset in pixels */
+  x: number;
+  /** The vertical offset in pixels */
+  y: number;
+  /** The width in pixels */
+  width: number;
+  /** The height in pixels */
+  height: number;
+};
+
+/**
+ * Computed CSS box model of a node.
+ *
+ * Returned by `boxModel()`. Edge widths are in pixels; the content box is
+ * positioned relative to the node's parent, like `Layout.x` / `Layout.y`.
+ *
+ * @example
+ * ```typescript
+ * import { TaffyTree, Style, type BoxModel } from 'taffy-js';
+ *
+ * const tree = new TaffyTree();
+ * const style = new Style();
+ * style.size = { width: 100, height: 50 };
+ * style.padding = { left: 10, right: 10, top: 10, bottom: 10 };
+ * const node = tree.newLeaf(style);
+ * tree.computeLayout(node, { width: 800, height: 600 });
+ *
+ * const box: BoxModel = tree.boxModel(node);
+ * console.log(box.padding.left, box.content.width); // 10, 80
+ * ```
+ */
+export type BoxModel = {
+  /** Computed margin widths */
+  margin: Rect<number>;
+  /** Computed border widths */
+  border: Rect<number>;
+  /** Computed padding widths */
+  padding: Rect<number>;
+  /** The content box */
+  content: LayoutRect;
+};
+
+/**
+ * A node's border box in DOMRect shape, in absolute coordinates.
+ *
+ * Returned by `domRects()`. Coordinates are relative to the root of the
+ * node's tree, like `layoutToCssPosition()`.
+ *
+ * @property nodeId - The node the rectangle belongs to
+ * @property x - Left edge
+ * @property y - Top edge
+ * @property width - Width in pixels
+ * @property height - Height in pixels
+ * @property top - Top edge (same as `y`)
+ * @property right - Right edge (`x + width`)
+ * @property bottom - Bottom edge (`y + height`)
+ * @property left - Left edge (same as `x`)
+ */
+export type NodeDomRect = {
+  /** The node the rectangle belongs to */
+  nodeId: bigint;
+  /** Left edge */
+  x: number;
+  /** Top edge */
+  y: number;
+  /** Width in pixels */
+  width: number;
+  /** Height in pixels */
+  height: number;
+  /** Top edge (same as `y`) */
+  top: number;
+  /** Right edge (`x + width`) */
+  right: number;
+  /** Bottom edge (`y + height`) */
+  bottom: number;
+  /** Left edge (same as `x`) */
+  left: number;
+};
+
+/**
+ * Gap of a container resolved to pixels.
+ *
+ * Returned by `resolvedGap()`. Percentages are resolved against the
+ * container's computed content box.
+ *
+ * @property row - Space between rows (from `gap.height`)
+ * @property column - Space between columns (from `gap.width`)
+ */
+export type ResolvedGap = {
+  /** Space between rows in pixels */
+  row: number;
+  /** Space between columns in pixels */
+  column: number;
+};
+
+/**
+ * Which axes of a node have `auto` margins that absorbed free space.
+ *
+ * Returned by `autoMarginApplied()`.
+ *
+ * @property horizontal - `true` if an `auto` left or right margin resolved to a non-zero size
+ * @property vertical - `true` if an `auto` top or bottom margin resolved to a non-zero size
+ */
+export type AutoMarginApplied = {
+  /** Whether an `auto` left or right margin took up space */
+  horizontal: boolean;
+  /** Whether an `auto` top or bottom margin took up space */
+  vertical: boolean;
+};
+
+/**
+ * Self-alignment a grid item is laid out with.
+ *
+ * Returned by `resolvedGridAlignment()`. Both values are concrete, never
+ * `AlignSelf.Auto`.
+ *
+ * @property justifySelf - Alignment along the inline (horizontal) axis
+ * @property alignSelf - Alignment along the block (vertical) axis
+ */
+export type ResolvedGridAlignment = {
+  /** Inline-axis (horizontal) alignment */
+  justifySelf: AlignSelf;
+  /** Block-axis (vertical) alignment */
+  alignSelf: AlignSelf;
+};
+
 /**
  * Detailed layout information (for grid layouts).
  *
@@ -439,6 +723,38 @@ export type DetailedGridItemsInfo = {
   columnEnd: number;
 };
 
+/**
+ * Position chosen for an auto-placed grid item.
+ *
+ * Returned by `autoPlacement()`. Both values are 1-indexed start lines of the
+ * explicit grid, as they would be written in `grid-row-start` / `grid-column-start`.
+ *
+ * @property row - The resolved row start line
+ * @property column - The resolved column start line
+ */
+export type GridAutoPlacement = {
+  /** The resolved row start line (1-indexed) */
+  row: number;
+  /** The resolved column start line (1-indexed) */
+  column: number;
+};
+
+/**
+ * Resolved track counts of a grid container.
+ *
+ * Returned by `gridDimensions()`. Counts include both explicit tracks and
+ * implicit tracks created by auto-placement or out-of-range line numbers.
+ *
+ * @property columns - The total number of column tracks
+ * @property rows - The total number of row tracks
+ */
+export type GridDimensions = {
+  /** The total number of column tracks */
+  columns: number;
+  /** The total number of row tracks */
+  rows: number;
+};
+
 /**
  * Grid placement type for positioning grid items.
  *
@@ -530,7 +846,7 @@ export type MinTrackSizingFunction = number | `${number}%` | "auto" | "min-conte
  *
  * Defines the maximum size of a grid track.
  */
-export type MaxTrackSizingFunction = number | `${number}%` | `${number}fr` | "auto" | "min-content" | "max-content" | "fit-content";
+export type MaxTrackSizingFunction = number | `${number}%` | `${number}fr` | "auto" | "min-content" | "max-content" | "fit-content" | `fit-content(${number}px)` | `fit-content(${number}%)`;
 
 /**
  * Track sizing function (min/max pair).
@@ -551,9 +867,54 @@ export type GridTemplateRepetition = {
 /**
  * Grid track sizing definition.
  *
- * Can be a single track sizing function or a repetition of tracks.
+ * Can be a single track sizing function or a repetition of tracks. A
+ * repetition may also be written in CSS `repeat()` notation, such as
+ * `"repeat(3, 1fr)"` or `"repeat(auto-fill, minmax(100px, 1fr))"`. Getters
+ * always return repetitions in the object form.
+ */
+export type GridTemplateComponent =
+  | TrackSizingFunction
+  | GridTemplateRepetition
+  | `repeat(${string})`;
+
+/**
+ * Structured grid track.
+ *
+ * Each track is tagged by `kind`. A single-value track has the CSS meaning of
+ * a lone track size, so `{ kind: "fr", value: 1 }` is `minmax(auto, 1fr)` and
+ * `{ kind: "px", value: 100 }` is `minmax(100px, 100px)`. Any other pair is a
+ * `minmax` track. Getters always return the single-value form where one exists.
+ *
+ * @remarks
+ * - `px`: Fixed size in pixels.
+ * - `percent`: Percentage of the container size, `0`-`100`.
+ * - `fr`: Share of the free space. Not valid as a `minmax` minimum.
+ * - `auto` / `min-content` / `max-content`: Content-based sizes.
+ * - `fit-content`: `fit-content(limit)` with a `px` or `percent` limit. Not
+ *   valid inside `minmax`.
+ * - `minmax`: Explicit min/max pair of single-value tracks.
+ * - `repeat`: `repeat(count, tracks)`. Only valid at the top level.
+ *
+ * @example
+ * ```typescript
+ * const style = new Style();
+ * style.gridTemplateColumnTracks = [
+ *   { kind: "px", value: 100 },
+ *   { kind: "minmax", min: { kind: "min-content" }, max: { kind: "fr", value: 1 } },
+ *   { kind: "repeat", count: "auto-fill", tracks: [{ kind: "px", value: 80 }] },
+ * ];
+ * ```
  */
-export type GridTemplateComponent = TrackSizingFunction | GridTemplateRepetition;
+export type GridTrack =
+  | { kind: "px"; value: number }
+  | { kind: "percent"; value: number }
+  | { kind: "fr"; value: number }
+  | { kind: "auto" }
+  | { kind: "min-content" }
+  | { kind: "max-content" }
+  | { kind: "fit-content"; limit: { kind: "px" | "percent"; value: number } }
+  | { kind: "minmax"; min: GridTrack; max: GridTrack }
+  | { kind: "repeat"; count: RepetitionCount; tracks: GridTrack[]; lineNames?: string[][] };
 
 /**
  * Named grid area definition.
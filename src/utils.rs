@@ -5,7 +5,9 @@
 //! ## Functions
 //!
 //! - [`serialize`]: Converts Rust types to `JsValue` using serde-wasm-bindgen
+//! - [`serialize_with_node_ids`]: Like `serialize`, but emits node IDs as `bigint`
 //! - [`log`]: Outputs debug messages to the browser console
+//! - [`now`]: Reads the high-resolution timer (`performance.now()`)
 //!
 //! ## Usage
 //!
@@ -52,6 +54,21 @@ pub fn serialize<T: Serialize + ?Sized>(val: &T) -> JsValue {
     serde_wasm_bindgen::to_value(val).unwrap_or(JsValue::NULL)
 }
 
+/// Serializes a Rust value to a JavaScript value, emitting 64-bit integers as `bigint`
+///
+/// Use this for DTOs that contain node IDs (`u64`), so that they come out as the
+/// same `bigint` values used everywhere else in the API. Any other integer field
+/// in such a DTO should use a 32-bit type to stay a plain `number`.
+///
+/// @param val - A reference to the value to serialize
+///
+/// @returns - A `JsValue` representing the serialized data, or `null` if serialization fails
+pub fn serialize_with_node_ids<T: Serialize + ?Sized>(val: &T) -> JsValue {
+    let serializer =
+        serde_wasm_bindgen::Serializer::new().serialize_large_number_types_as_bigints(true);
+    val.serialize(&serializer).unwrap_or(JsValue::NULL)
+}
+
 // =============================================================================
 // Console Logging
 // =============================================================================
@@ -79,3 +96,16 @@ extern "C" {
     #[wasm_bindgen(js_namespace = console)]
     pub fn log(s: &str);
 }
+
+// =============================================================================
+// Timing
+// =============================================================================
+
+#[wasm_bindgen]
+extern "C" {
+    /// Returns a high-resolution timestamp in milliseconds
+    ///
+    /// This function is a direct binding to JavaScript's `performance.now()`.
+    #[wasm_bindgen(js_namespace = performance)]
+    pub fn now() -> f64;
+}
@@ -417,6 +417,42 @@ describe("Block Layout Computation", () => {
     });
   });
 
+  describe("Block with Percentage Border", () => {
+    it("resolves percentage borders against the parent's width", () => {
+      const tree = new TaffyTree();
+
+      const rootStyle = new Style();
+      rootStyle.display = Display.Block;
+      rootStyle.size = { width: 200, height: 200 };
+
+      const root = tree.newLeaf(rootStyle);
+
+      const childStyle = new Style();
+      childStyle.border = { left: "10%", right: 0, top: "5%", bottom: 0 };
+      const child = tree.newLeaf(childStyle);
+
+      tree.addChild(root, child);
+
+      tree.computeLayout(root, { width: 200, height: 200 });
+
+      // Both horizontal and vertical percentages resolve against the width (200)
+      const childLayout = tree.getLayout(child);
+      expect(childLayout.borderLeft).toBe(20);
+      expect(childLayout.borderTop).toBe(10);
+      expect(childLayout.borderRight).toBe(0);
+      expect(childLayout.width).toBe(200);
+      expect(childLayout.height).toBe(10);
+
+      // Round-trips through the style getter unchanged
+      expect(childStyle.border.left).toBe("10%");
+      expect(childStyle.border.top).toBe("5%");
+
+      tree.free();
+      rootStyle.free();
+      childStyle.free();
+    });
+  });
+
   describe("Block with Scrollbar Width", () => {
     it("validates Block with scrollbarWidth affects content", () => {
       const tree = new TaffyTree();
@@ -3,9 +3,11 @@ import { setupTaffy } from "./utils";
 import {
   TaffyTree,
   Style,
+  TaffyError,
   Display,
   GridAutoFlow,
   AlignItems,
+  AlignSelf,
   JustifyContent,
 } from "../src/index";
 
@@ -64,6 +66,62 @@ describe("Grid Style Properties", () => {
 
       expect(style.gridTemplateColumns.length).toBe(2);
     });
+
+    it("gridTemplateColumns: round-trips a mixed track list", () => {
+      const style = new Style();
+      const tracks = [
+        { min: 100, max: 100 },
+        { min: "50%", max: "50%" },
+        { min: "auto", max: "2fr" },
+        { min: "min-content", max: "fit-content(25%)" },
+        { count: 3, tracks: [{ min: "auto", max: "1fr" }], lineNames: [] },
+      ];
+
+      style.gridTemplateColumns = tracks as any;
+      expect(style.gridTemplateColumns).toEqual(tracks);
+      expect(style.gridTemplateColumnTracks[1]).toEqual({
+        kind: "percent",
+        value: 50,
+      });
+
+      style.gridTemplateColumns = [{ min: "50", max: "1fr" }] as any;
+      expect(style.gridTemplateColumns).toEqual(tracks);
+    });
+
+    it("gridTemplateColumns: resolves percentage tracks", () => {
+      const tree = new TaffyTree();
+      const items = [tree.newLeaf(new Style()), tree.newLeaf(new Style())];
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      gridStyle.size = { width: 200, height: 100 };
+      gridStyle.gridTemplateColumns = [
+        { min: "25%", max: "25%" },
+        { min: "auto", max: "1fr" },
+      ];
+      const grid = tree.newWithChildren(gridStyle, BigUint64Array.from(items));
+      tree.computeLayout(grid, { width: 200, height: 100 });
+
+      expect(tree.getLayout(items[0]).width).toBe(50);
+      expect(tree.getLayout(items[1]).x).toBe(50);
+
+      tree.free();
+    });
+
+    it("gridTemplateRows: reproduces the tracks that were set", () => {
+      const style = new Style();
+      const rows = [
+        { min: "auto", max: "auto" },
+        { min: "auto", max: "1fr" },
+        { min: 100, max: "1fr" },
+        { count: 2, tracks: [{ min: "auto", max: "2fr" }], lineNames: [] },
+      ];
+
+      style.gridTemplateRows = rows as any;
+      expect(style.gridTemplateRows).toEqual(rows);
+
+      style.gridTemplateRows = [{ min: "1fr", max: "auto" }] as any;
+      expect(style.gridTemplateRows).toEqual(rows);
+    });
   });
 
   describe("Grid Auto Tracks", () => {
@@ -98,6 +156,35 @@ describe("Grid Style Properties", () => {
 
       expect(style.gridAutoRows.length).toBe(2);
     });
+
+    it("gridAutoRowTracks: sets and gets structured tracks", () => {
+      const style = new Style();
+      const tracks = [
+        { kind: "min-content" },
+        { kind: "fr", value: 1 },
+        {
+          kind: "minmax",
+          min: { kind: "px", value: 100 },
+          max: { kind: "percent", value: 50 },
+        },
+      ];
+
+      style.gridAutoRowTracks = tracks as any;
+      expect(style.gridAutoRowTracks).toEqual(tracks);
+      expect(style.gridAutoRows[1]).toEqual({ min: "auto", max: "1fr" });
+      expect(style.gridAutoRows[2]).toEqual({ min: 100, max: "50%" });
+    });
+
+    it("gridAutoColumnTracks: rejects repeat() tracks", () => {
+      const style = new Style();
+      style.gridAutoColumnTracks = [{ kind: "px", value: 40 }];
+
+      style.gridAutoColumnTracks = [
+        { kind: "px", value: 80 },
+        { kind: "repeat", count: 2, tracks: [{ kind: "fr", value: 1 }] },
+      ] as any;
+      expect(style.gridAutoColumnTracks).toEqual([{ kind: "px", value: 40 }]);
+    });
   });
 
   describe("Grid Repetition", () => {
@@ -144,6 +231,58 @@ describe("Grid Style Properties", () => {
 
       expect(style.gridTemplateColumns.length).toBe(1);
     });
+
+    it("gridTemplateColumns: accepts CSS repeat() notation", () => {
+      const style = new Style();
+
+      style.gridTemplateColumns = ["repeat(3, 1fr)"];
+      expect(style.gridTemplateColumns).toEqual([
+        { count: 3, tracks: [{ min: "auto", max: "1fr" }], lineNames: [] },
+      ]);
+
+      style.gridTemplateColumns = [
+        { min: 50, max: 50 },
+        "repeat(auto-fill, minmax(100px, 1fr) 20px)",
+      ];
+      expect(style.gridTemplateColumns).toEqual([
+        { min: 50, max: 50 },
+        {
+          count: "auto-fill",
+          tracks: [
+            { min: 100, max: "1fr" },
+            { min: 20, max: 20 },
+          ],
+          lineNames: [],
+        },
+      ]);
+    });
+
+    it("gridTemplateRows: ignores invalid repeat() notation", () => {
+      const style = new Style();
+      style.gridTemplateRows = ["repeat(2, 40px)"];
+
+      style.gridTemplateRows = ["repeat(0, 1fr)"];
+      style.gridTemplateRows = ["repeat(2, [a] 1fr)"];
+      expect(style.gridTemplateRows).toEqual([
+        { count: 2, tracks: [{ min: 40, max: 40 }], lineNames: [] },
+      ]);
+    });
+
+    it("lays out repeat() columns like the expanded tracks", () => {
+      const tree = new TaffyTree();
+      const items = Array.from({ length: 3 }, () => tree.newLeaf(new Style()));
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      gridStyle.size = { width: 300, height: 100 };
+      gridStyle.gridTemplateColumns = ["repeat(3, 1fr)"];
+      const grid = tree.newWithChildren(gridStyle, BigUint64Array.from(items));
+      tree.computeLayout(grid, { width: 300, height: 100 });
+
+      const xs = items.map((item) => tree.getLayout(item).x);
+      expect(xs).toEqual([0, 100, 200]);
+
+      tree.free();
+    });
   });
 
   describe("Grid Placement", () => {
@@ -283,6 +422,38 @@ describe("Grid Style Properties", () => {
       expect(style.gridRow.start).toBe("auto");
       expect(style.gridRow.end).toBe(3);
     });
+
+    it("gridRow/gridColumn: handle auto on both ends", () => {
+      const style = new Style();
+      style.gridRow = { start: 2, end: 3 };
+      style.gridColumn = { start: { span: 2 }, end: 4 };
+
+      style.gridRow = { start: "auto", end: "auto" };
+      style.gridColumn = { start: "auto", end: "auto" };
+      expect(style.gridRow).toEqual({ start: "auto", end: "auto" });
+      expect(style.gridColumn).toEqual({ start: "auto", end: "auto" });
+    });
+
+    it("gridRow/gridColumn: place items by lines and spans", () => {
+      const tree = new TaffyTree();
+      const itemStyle = new Style();
+      itemStyle.gridRow = { start: 2, end: { span: 2 } };
+      itemStyle.gridColumn = { start: 1, end: 3 };
+      const item = tree.newLeaf(itemStyle);
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      gridStyle.size = { width: 300, height: 300 };
+      gridStyle.gridTemplateColumns = ["repeat(3, 1fr)"];
+      gridStyle.gridTemplateRows = ["repeat(3, 1fr)"];
+      const grid = tree.newWithChildren(gridStyle, BigUint64Array.from([item]));
+      tree.computeLayout(grid, { width: 300, height: 300 });
+
+      const layout = tree.getLayout(item);
+      expect([layout.x, layout.y]).toEqual([0, 100]);
+      expect([layout.width, layout.height]).toEqual([200, 200]);
+
+      tree.free();
+    });
   });
 
   describe("Grid Line Names", () => {
@@ -385,6 +556,60 @@ describe("Grid Style Properties", () => {
       expect(header.rowStart).toBe(1);
       expect(header.columnEnd).toBe(4);
     });
+
+    it("setGridArea: places items into named areas", () => {
+      const tree = new TaffyTree();
+      const header = new Style();
+      header.setGridArea("header");
+      expect(header.gridRow).toEqual({
+        start: { line: 1, ident: "header-start" },
+        end: { line: 1, ident: "header-end" },
+      });
+      expect(header.gridColumn).toEqual(header.gridRow);
+      const main = new Style();
+      main.setGridArea("main");
+
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      gridStyle.size = { width: 200, height: 100 };
+      gridStyle.gridTemplateColumns = ["repeat(2, 1fr)"];
+      gridStyle.gridTemplateRows = ["repeat(2, 1fr)"];
+      gridStyle.gridTemplateAreas = [
+        {
+          name: "header",
+          rowStart: 1,
+          rowEnd: 2,
+          columnStart: 1,
+          columnEnd: 3,
+        },
+        { name: "main", rowStart: 2, rowEnd: 3, columnStart: 2, columnEnd: 3 },
+      ];
+      const mainNode = tree.newLeaf(main);
+      const headerNode = tree.newLeaf(header);
+      const grid = tree.newWithChildren(
+        gridStyle,
+        BigUint64Array.from([mainNode, headerNode]),
+      );
+      tree.computeLayout(grid, { width: 200, height: 100 });
+
+      const h = tree.getLayout(headerNode);
+      expect([h.x, h.y, h.width, h.height]).toEqual([0, 0, 200, 50]);
+      const m = tree.getLayout(mainNode);
+      expect([m.x, m.y, m.width, m.height]).toEqual([100, 50, 100, 50]);
+
+      tree.free();
+    });
+
+    it("setGridArea: ignores invalid area names", () => {
+      const style = new Style();
+      style.gridRow = { start: 1, end: 2 };
+
+      for (const name of ["", "two words", "1st", "span"]) {
+        style.setGridArea(name);
+      }
+      expect(style.gridRow).toEqual({ start: 1, end: 2 });
+      expect(style.gridColumn).toEqual({ start: "auto", end: "auto" });
+    });
   });
 
   describe("Grid Auto Flow", () => {
@@ -408,6 +633,23 @@ describe("Grid Style Properties", () => {
         expect(style.gridAutoFlow).toBe(variant);
       }
     });
+
+    it("gridAutoFlow: round-trips all variants through a node", () => {
+      const tree = new TaffyTree();
+      const node = tree.newLeaf(new Style());
+
+      for (const variant of [
+        GridAutoFlow.Row,
+        GridAutoFlow.Column,
+        GridAutoFlow.RowDense,
+        GridAutoFlow.ColumnDense,
+      ]) {
+        tree.setStyleProperty(node, "gridAutoFlow", variant);
+        expect(tree.getStyle(node).gridAutoFlow).toBe(variant);
+      }
+
+      tree.free();
+    });
   });
 });
 
@@ -749,6 +991,86 @@ describe("Grid Layout Computation", () => {
         style.free();
       }
     });
+
+    it("resolves percentage gaps against the matching axis", () => {
+      const tree = new TaffyTree();
+
+      const rootStyle = new Style();
+      rootStyle.display = Display.Grid;
+      rootStyle.size = { width: 200, height: 400 };
+      // Column gap: 5% of 200 = 10, row gap: 10% of 400 = 40
+      rootStyle.gap = { width: "5%", height: "10%" };
+      rootStyle.gridTemplateColumns = [
+        { min: 50, max: 50 },
+        { min: 50, max: 50 },
+      ] as any;
+      rootStyle.gridTemplateRows = [
+        { min: 50, max: 50 },
+        { min: 50, max: 50 },
+      ] as any;
+
+      const root = tree.newLeaf(rootStyle);
+      const children: bigint[] = [];
+      for (let i = 0; i < 4; i++) {
+        const child = tree.newLeaf(new Style());
+        children.push(child);
+        tree.addChild(root, child);
+      }
+
+      tree.computeLayout(root, { width: 200, height: 400 });
+
+      // Auto-placement fills row 1 first: children[1] is in column 2
+      const layout0 = tree.getLayout(children[0]);
+      const layout1 = tree.getLayout(children[1]);
+      expect(layout1.x - (layout0.x + layout0.width)).toBeCloseTo(10, 0);
+      expect(layout1.y).toBe(layout0.y);
+
+      // children[2] starts row 2
+      const layout2 = tree.getLayout(children[2]);
+      expect(layout2.y - (layout0.y + layout0.height)).toBeCloseTo(40, 0);
+      expect(layout2.x).toBe(layout0.x);
+
+      tree.free();
+      rootStyle.free();
+    });
+
+    it("uses the same width/height mapping as flexbox", () => {
+      const tree = new TaffyTree();
+
+      // Only a row gap: no space between columns
+      const rootStyle = new Style();
+      rootStyle.display = Display.Grid;
+      rootStyle.size = { width: 100, height: 100 };
+      rootStyle.gap = { width: 0, height: 30 };
+      rootStyle.gridTemplateColumns = [
+        { min: 50, max: 50 },
+        { min: 50, max: 50 },
+      ] as any;
+      rootStyle.gridTemplateRows = [
+        { min: 20, max: 20 },
+        { min: 20, max: 20 },
+      ] as any;
+
+      const root = tree.newLeaf(rootStyle);
+      const children: bigint[] = [];
+      for (let i = 0; i < 4; i++) {
+        const child = tree.newLeaf(new Style());
+        children.push(child);
+        tree.addChild(root, child);
+      }
+
+      tree.computeLayout(root, { width: 100, height: 100 });
+
+      const layout0 = tree.getLayout(children[0]);
+      const layout1 = tree.getLayout(children[1]);
+      const layout2 = tree.getLayout(children[2]);
+      expect(layout1.x).toBe(50);
+      expect(layout2.y - (layout0.y + layout0.height)).toBe(30);
+      expect(layout1.y).toBe(layout0.y);
+
+      tree.free();
+      rootStyle.free();
+    });
   });
 
   describe("Grid Alignment", () => {
@@ -779,6 +1101,120 @@ describe("Grid Layout Computation", () => {
       rootStyle.free();
       childStyle.free();
     });
+
+    it("aligns items on the inline axis with justifyItems", () => {
+      const tree = new TaffyTree();
+      const childStyle = new Style();
+      childStyle.size = { width: 20, height: 20 };
+      const child = tree.newLeaf(childStyle);
+      const rootStyle = new Style();
+      rootStyle.display = Display.Grid;
+      rootStyle.size = { width: 100, height: 100 };
+      rootStyle.gridTemplateColumns = [{ min: "auto", max: "1fr" }];
+      rootStyle.justifyItems = AlignItems.Center;
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+
+      tree.computeLayout(root, { width: 100, height: 100 });
+      expect(tree.getLayout(child).x).toBe(40);
+
+      // Clearing it falls back to stretching, which a fixed width disables
+      rootStyle.justifyItems = undefined;
+      tree.setStyle(root, rootStyle);
+      tree.computeLayout(root, { width: 100, height: 100 });
+      expect(tree.getLayout(child).x).toBe(0);
+
+      tree.free();
+    });
+
+    it("overrides justifyItems per item with justifySelf", () => {
+      const tree = new TaffyTree();
+      const childStyle = new Style();
+      childStyle.size = { width: 20, height: 20 };
+      childStyle.justifySelf = AlignSelf.Start;
+      const child = tree.newLeaf(childStyle);
+      const rootStyle = new Style();
+      rootStyle.display = Display.Grid;
+      rootStyle.size = { width: 100, height: 100 };
+      rootStyle.gridTemplateColumns = [{ min: "auto", max: "1fr" }];
+      rootStyle.justifyItems = AlignItems.End;
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+
+      tree.computeLayout(root, { width: 100, height: 100 });
+      expect(tree.getStyle(child).justifySelf).toBe(AlignSelf.Start);
+      expect(tree.getLayout(child).x).toBe(0);
+
+      // Auto and unset both defer to the container's justifyItems
+      for (const value of [AlignSelf.Auto, undefined]) {
+        childStyle.justifySelf = value;
+        tree.setStyle(child, childStyle);
+        tree.computeLayout(root, { width: 100, height: 100 });
+        expect(tree.getStyle(child).justifySelf).toBe(AlignSelf.Auto);
+        expect(tree.getLayout(child).x).toBe(80);
+      }
+
+      tree.free();
+    });
+
+    it("resolves the concrete self-alignment of grid items", () => {
+      const tree = new TaffyTree();
+      const itemStyle = new Style();
+      const item = tree.newLeaf(itemStyle);
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      const grid = tree.newWithChildren(
+        gridStyle,
+        BigUint64Array.from([item]),
+      );
+
+      // Unsized items stretch on both axes
+      expect(tree.resolvedGridAlignment(item)).toEqual({
+        justifySelf: AlignSelf.Stretch,
+        alignSelf: AlignSelf.Stretch,
+      });
+
+      // A definite size aligns to the start instead
+      itemStyle.size = { width: 50, height: "auto" };
+      tree.setStyle(item, itemStyle);
+      expect(tree.resolvedGridAlignment(item)).toEqual({
+        justifySelf: AlignSelf.Start,
+        alignSelf: AlignSelf.Stretch,
+      });
+
+      // An aspect ratio keeps the item from stretching vertically
+      itemStyle.aspectRatio = 2;
+      tree.setStyle(item, itemStyle);
+      expect(tree.resolvedGridAlignment(item)!.alignSelf).toBe(
+        AlignSelf.Start,
+      );
+
+      // The container's items alignment applies, and the item's own wins
+      gridStyle.justifyItems = AlignItems.Center;
+      gridStyle.alignItems = AlignItems.End;
+      tree.setStyle(grid, gridStyle);
+      itemStyle.alignSelf = AlignSelf.Baseline;
+      tree.setStyle(item, itemStyle);
+      expect(tree.resolvedGridAlignment(item)).toEqual({
+        justifySelf: AlignSelf.Center,
+        alignSelf: AlignSelf.Baseline,
+      });
+
+      // Not a grid item
+      gridStyle.display = Display.Flex;
+      tree.setStyle(grid, gridStyle);
+      expect(tree.resolvedGridAlignment(item)).toBeUndefined();
+      expect(tree.resolvedGridAlignment(grid)).toBeUndefined();
+
+      tree.remove(item);
+      expect(() => tree.resolvedGridAlignment(item)).toThrow(TaffyError);
+
+      tree.free();
+    });
   });
 
   describe("Grid Scrollbar Width", () => {
@@ -893,4 +1329,114 @@ describe("Grid Layout Computation", () => {
       child2Style.free();
     });
   });
+
+  describe("Grid Auto Placement Inspection", () => {
+    it("reports resolved start lines for auto-placed items", () => {
+      const tree = new TaffyTree();
+
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      gridStyle.gridTemplateColumns = [
+        { min: 50, max: 50 },
+        { min: 50, max: 50 },
+      ] as any;
+
+      const explicitStyle = new Style();
+      explicitStyle.gridRow = { start: 1, end: 2 };
+      explicitStyle.gridColumn = { start: 1, end: 2 };
+      const explicit = tree.newLeaf(explicitStyle);
+
+      const a = tree.newLeaf(new Style());
+      const b = tree.newLeaf(new Style());
+
+      const spanStyle = new Style();
+      spanStyle.gridColumn = { start: { span: 2 }, end: "auto" };
+      const spanning = tree.newLeaf(spanStyle);
+
+      const grid = tree.newWithChildren(
+        gridStyle,
+        BigUint64Array.from([explicit, a, b, spanning]),
+      );
+      tree.computeLayout(grid, { width: 100, height: 100 });
+
+      expect(tree.autoPlacement(explicit)).toBeNull();
+      expect(tree.autoPlacement(a)).toEqual({ row: 1, column: 2 });
+      expect(tree.autoPlacement(b)).toEqual({ row: 2, column: 1 });
+      expect(tree.autoPlacement(spanning)).toEqual({ row: 3, column: 1 });
+
+      tree.free();
+    });
+
+    it("returns null for non-grid items", () => {
+      const tree = new TaffyTree();
+      const flexStyle = new Style();
+      flexStyle.display = Display.Flex;
+      const child = tree.newLeaf(new Style());
+      const root = tree.newWithChildren(
+        flexStyle,
+        BigUint64Array.from([child]),
+      );
+      tree.computeLayout(root, { width: 100, height: 100 });
+
+      expect(tree.autoPlacement(child)).toBeNull();
+      expect(tree.autoPlacement(root)).toBeNull();
+
+      tree.remove(child);
+      expect(() => tree.autoPlacement(child)).toThrow(TaffyError);
+
+      tree.free();
+    });
+  });
+
+  describe("Grid Dimensions", () => {
+    it("counts explicit and implicit tracks", () => {
+      const tree = new TaffyTree();
+
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      gridStyle.gridTemplateColumns = [
+        { min: 50, max: 50 },
+        { min: 50, max: 50 },
+      ] as any;
+      gridStyle.gridTemplateRows = [{ min: 20, max: 20 }] as any;
+
+      // An item placed past the explicit columns creates implicit columns 3 and 4
+      const farStyle = new Style();
+      farStyle.gridRow = { start: 1, end: 2 };
+      farStyle.gridColumn = { start: 4, end: 5 };
+      const items = [tree.newLeaf(farStyle)];
+      // Five auto-placed items fill row 1 (columns 1-3), then an implicit row 2
+      for (let i = 0; i < 5; i++) {
+        items.push(tree.newLeaf(new Style()));
+      }
+
+      const grid = tree.newWithChildren(gridStyle, BigUint64Array.from(items));
+      expect(tree.gridDimensions(grid)).toBeNull();
+
+      tree.computeLayout(grid, { width: 200, height: 100 });
+      expect(tree.gridDimensions(grid)).toEqual({ columns: 4, rows: 2 });
+
+      tree.free();
+    });
+
+    it("returns null for non-grid nodes", () => {
+      const tree = new TaffyTree();
+      const flexStyle = new Style();
+      flexStyle.display = Display.Flex;
+      const child = tree.newLeaf(new Style());
+      const root = tree.newWithChildren(
+        flexStyle,
+        BigUint64Array.from([child]),
+      );
+      tree.computeLayout(root, { width: 100, height: 100 });
+
+      expect(tree.gridDimensions(root)).toBeNull();
+      expect(tree.gridDimensions(child)).toBeNull();
+
+      tree.remove(child);
+      expect(() => tree.gridDimensions(child)).toThrow(TaffyError);
+
+      tree.free();
+    });
+  });
 });
@@ -8,6 +8,7 @@ import {
   AlignItems,
   JustifyContent,
   GridAutoFlow,
+  interpolateLayout,
 } from "../src/index";
 
 describe("Layout Computation", () => {
@@ -344,3 +345,58 @@ describe("Block Layout Computation", () => {
     childStyle.free();
   });
 });
+
+describe("Layout Interpolation", () => {
+  beforeAll(async () => {
+    await setupTaffy();
+  });
+
+  it("blends a pinned snapshot with the current layout", () => {
+    const tree = new TaffyTree();
+    const style = new Style();
+    style.size = { width: 100, height: 50 };
+    style.margin = { left: 0, right: 0, top: 0, bottom: 0 };
+    const node = tree.newLeaf(style);
+    tree.computeLayout(node, { width: 800, height: 600 });
+    tree.pinLayout(node);
+
+    style.size = { width: 200, height: 150 };
+    style.margin = { left: 20, right: 0, top: 10, bottom: 0 };
+    tree.setStyle(node, style);
+    tree.computeLayout(node, { width: 800, height: 600 });
+    const from = tree.pinnedLayout(node)!;
+    const to = tree.getLayout(node);
+
+    const mid = interpolateLayout(from, to, 0.5);
+    expect(mid.width).toBe(150);
+    expect(mid.height).toBe(100);
+    expect(mid.x).toBe(10);
+    expect(mid.y).toBe(5);
+    expect(mid.marginLeft).toBe(10);
+
+    expect(interpolateLayout(from, to, 0).width).toBe(100);
+    expect(interpolateLayout(from, to, 1).width).toBe(200);
+
+    tree.free();
+  });
+
+  it("clamps t to [0, 1]", () => {
+    const tree = new TaffyTree();
+    const style = new Style();
+    style.size = { width: 100, height: 100 };
+    const node = tree.newLeaf(style);
+    tree.computeLayout(node, { width: 800, height: 600 });
+    tree.pinLayout(node);
+    style.size = { width: 300, height: 100 };
+    tree.setStyle(node, style);
+    tree.computeLayout(node, { width: 800, height: 600 });
+    const from = tree.pinnedLayout(node)!;
+    const to = tree.getLayout(node);
+
+    expect(interpolateLayout(from, to, -1).width).toBe(100);
+    expect(interpolateLayout(from, to, 2).width).toBe(300);
+    expect(interpolateLayout(from, to, NaN).width).toBe(100);
+
+    tree.free();
+  });
+});
@@ -0,0 +1,260 @@
+import { describe, it, expect, beforeAll } from "vitest";
+import { setupTaffy } from "./utils";
+import {
+  TaffyTree,
+  Style,
+  Position,
+  AlignItems,
+  Display,
+} from "../src/index";
+
+/**
+ * Percentages are authored as strings (`"50%"`), stored by Taffy as fractions
+ * (`0.5`) and read back as the same strings. These tests pin both halves of
+ * that convention for every percentage-accepting property: the getter
+ * round-trip, and the resolved pixel value after layout.
+ */
+describe("Percentage Resolution", () => {
+  beforeAll(async () => {
+    await setupTaffy();
+  });
+
+  /** Lays out `childStyle` inside a 400 x 200 flex container */
+  function layoutChild(childStyle: Style) {
+    const tree = new TaffyTree();
+    const child = tree.newLeaf(childStyle);
+    const rootStyle = new Style();
+    rootStyle.alignItems = AlignItems.Start;
+    rootStyle.size = { width: 400, height: 200 };
+    const root = tree.newWithChildren(rootStyle, BigUint64Array.from([child]));
+    tree.computeLayout(root, { width: 800, height: 600 });
+    const layout = tree.getLayout(child);
+    tree.free();
+    return layout;
+  }
+
+  describe("Size Properties", () => {
+    it("size: resolves against the parent's size in each axis", () => {
+      const style = new Style();
+      style.flexShrink = 0;
+      style.size = { width: "50%", height: "50%" };
+      expect(style.size).toEqual({ width: "50%", height: "50%" });
+
+      const layout = layoutChild(style);
+      expect(layout.width).toBe(200);
+      expect(layout.height).toBe(100);
+    });
+
+    it("minSize: floors the size at a percentage of the parent", () => {
+      const style = new Style();
+      style.size = { width: 10, height: 10 };
+      style.minSize = { width: "50%", height: "25%" };
+      expect(style.minSize).toEqual({ width: "50%", height: "25%" });
+
+      const layout = layoutChild(style);
+      expect(layout.width).toBe(200);
+      expect(layout.height).toBe(50);
+    });
+
+    it("maxSize: caps the size at a percentage of the parent", () => {
+      const style = new Style();
+      style.size = { width: 300, height: 300 };
+      style.maxSize = { width: "25%", height: "25%" };
+      expect(style.maxSize).toEqual({ width: "25%", height: "25%" });
+
+      const layout = layoutChild(style);
+      expect(layout.width).toBe(100);
+      expect(layout.height).toBe(50);
+    });
+
+    it("flexBasis: resolves against the container's main size", () => {
+      const style = new Style();
+      style.flexShrink = 0;
+      style.flexBasis = "50%";
+      expect(style.flexBasis).toBe("50%");
+
+      expect(layoutChild(style).width).toBe(200);
+    });
+
+    it("keeps fractional percentages exact", () => {
+      const style = new Style();
+      style.flexShrink = 0;
+      style.size = { width: "12.5%", height: "0%" };
+      expect(style.size).toEqual({ width: "12.5%", height: "0%" });
+
+      const layout = layoutChild(style);
+      expect(layout.width).toBe(50);
+      expect(layout.height).toBe(0);
+    });
+  });
+
+  describe("Rect Properties", () => {
+    it("margin: resolves every side against the parent's width", () => {
+      const style = new Style();
+      style.size = { width: 10, height: 10 };
+      style.margin = { left: "10%", right: 0, top: "10%", bottom: "auto" };
+      expect(style.margin).toEqual({
+        left: "10%",
+        right: 0,
+        top: "10%",
+        bottom: "auto",
+      });
+
+      const layout = layoutChild(style);
+      expect(layout.x).toBe(40);
+      expect(layout.y).toBe(40);
+      expect(layout.marginLeft).toBe(40);
+      expect(layout.marginTop).toBe(40);
+    });
+
+    it("padding: resolves every side against the parent's width", () => {
+      const style = new Style();
+      style.padding = { left: "5%", right: "5%", top: "5%", bottom: "5%" };
+      expect(style.padding).toEqual({
+        left: "5%",
+        right: "5%",
+        top: "5%",
+        bottom: "5%",
+      });
+
+      const layout = layoutChild(style);
+      expect(layout.paddingLeft).toBe(20);
+      expect(layout.paddingRight).toBe(20);
+      expect(layout.paddingTop).toBe(20);
+      expect(layout.paddingBottom).toBe(20);
+      expect(layout.width).toBe(40);
+      expect(layout.height).toBe(40);
+    });
+
+    it("border: resolves every side against the parent's width", () => {
+      const style = new Style();
+      style.border = { left: "2.5%", right: 0, top: "2.5%", bottom: 0 };
+      expect(style.border).toEqual({
+        left: "2.5%",
+        right: 0,
+        top: "2.5%",
+        bottom: 0,
+      });
+
+      const layout = layoutChild(style);
+      expect(layout.borderLeft).toBe(10);
+      expect(layout.borderTop).toBe(10);
+    });
+
+    it("inset: resolves each side against the matching parent axis", () => {
+      const style = new Style();
+      style.position = Position.Absolute;
+      style.size = { width: 10, height: 10 };
+      style.inset = { left: "25%", right: "auto", top: "50%", bottom: "auto" };
+      expect(style.inset).toEqual({
+        left: "25%",
+        right: "auto",
+        top: "50%",
+        bottom: "auto",
+      });
+
+      let layout = layoutChild(style);
+      expect(layout.x).toBe(100);
+      expect(layout.y).toBe(100);
+
+      style.inset = { left: "auto", right: "25%", top: "auto", bottom: "50%" };
+      layout = layoutChild(style);
+      expect(layout.x).toBe(290);
+      expect(layout.y).toBe(90);
+    });
+  });
+
+  describe("Absolute Insets", () => {
+    /**
+     * Lays out an absolute `childStyle` inside a 400 x 200 container with a
+     * 10px border and 20px padding, so the containing block (the padding
+     * box) is 380 x 180 and starts at (10, 10)
+     */
+    function layoutAbsolute(childStyle: Style, display: Display) {
+      const tree = new TaffyTree();
+      const child = tree.newLeaf(childStyle);
+      const rootStyle = new Style();
+      rootStyle.display = display;
+      rootStyle.size = { width: 400, height: 200 };
+      rootStyle.border = { left: 10, right: 10, top: 10, bottom: 10 };
+      rootStyle.padding = { left: 20, right: 20, top: 20, bottom: 20 };
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+      tree.computeLayout(root, { width: 800, height: 600 });
+      const layout = tree.getLayout(child);
+      tree.free();
+      return layout;
+    }
+
+    const displays = [Display.Flex, Display.Grid, Display.Block];
+
+    it("left/top resolve against the containing block's padding box", () => {
+      const style = new Style();
+      style.position = Position.Absolute;
+      style.size = { width: 10, height: 10 };
+      style.inset = { left: "10%", right: "auto", top: "10%", bottom: "auto" };
+
+      for (const display of displays) {
+        const layout = layoutAbsolute(style, display);
+        expect(layout.x).toBe(48);
+        expect(layout.y).toBe(28);
+      }
+    });
+
+    it("right/bottom resolve against the padding box from the far edge", () => {
+      const style = new Style();
+      style.position = Position.Absolute;
+      style.size = { width: 10, height: 10 };
+      style.inset = { left: "auto", right: "10%", top: "auto", bottom: "10%" };
+
+      for (const display of displays) {
+        const layout = layoutAbsolute(style, display);
+        expect(layout.x).toBe(342);
+        expect(layout.y).toBe(162);
+      }
+    });
+
+    it("setInset() scales percentages like the inset property", () => {
+      const style = new Style();
+      style.position = Position.Absolute;
+      style.size = { width: 10, height: 10 };
+      style.setInset("10%", "auto", "auto", "10%");
+      expect(style.inset).toEqual({
+        left: "10%",
+        right: "auto",
+        top: "10%",
+        bottom: "auto",
+      });
+
+      const layout = layoutAbsolute(style, Display.Flex);
+      expect(layout.x).toBe(48);
+      expect(layout.y).toBe(28);
+    });
+  });
+
+  describe("Gap", () => {
+    it("gap: resolves against the container's own content box", () => {
+      const tree = new TaffyTree();
+      const itemStyle = new Style();
+      itemStyle.size = { width: 50, height: 50 };
+      const first = tree.newLeaf(itemStyle);
+      const second = tree.newLeaf(itemStyle);
+      const rootStyle = new Style();
+      rootStyle.size = { width: 400, height: 200 };
+      rootStyle.gap = { width: "5%", height: "10%" };
+      expect(rootStyle.gap).toEqual({ width: "5%", height: "10%" });
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([first, second]),
+      );
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      expect(tree.getLayout(second).x).toBe(70);
+      expect(tree.resolvedGap(root)).toEqual({ row: 20, column: 20 });
+
+      tree.free();
+    });
+  });
+});
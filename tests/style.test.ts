@@ -1,7 +1,8 @@
-import { describe, it, expect, beforeAll } from "vitest";
+import { describe, it, expect, beforeAll, afterEach } from "vitest";
 import { setupTaffy } from "./utils";
 import {
   Style,
+  TaffyTree,
   Display,
   Position,
   FlexDirection,
@@ -14,6 +15,14 @@ import {
   BoxSizing,
   TextAlign,
   GridAutoFlow,
+  enumName,
+  getEnums,
+  px,
+  percent,
+  auto,
+  setViewport,
+  clearViewport,
+  type GridTrack,
 } from "../src/index";
 
 describe("Style Class Properties", () => {
@@ -76,6 +85,22 @@ describe("Style Class Properties", () => {
       expect(style.overflow.x).toBe(Overflow.Clip);
       expect(style.overflow.y).toBe(Overflow.Visible);
     });
+
+    it("overflow: every variant survives a set/get cycle on both axes", () => {
+      const style = new Style();
+      const variants = [
+        Overflow.Visible,
+        Overflow.Clip,
+        Overflow.Hidden,
+        Overflow.Scroll,
+      ];
+      for (const x of variants) {
+        for (const y of variants) {
+          style.overflow = { x, y };
+          expect(style.overflow).toEqual({ x, y });
+        }
+      }
+    });
   });
 
   describe("Flexbox Properties", () => {
@@ -219,6 +244,38 @@ describe("Style Class Properties", () => {
       style.justifySelf = undefined;
       expect(style.justifySelf).toBe(AlignSelf.Auto);
     });
+
+    it("alignSelf: keeps Stretch and Auto distinct", () => {
+      const style = new Style();
+
+      style.alignSelf = AlignSelf.Stretch;
+      expect(style.alignSelf).toBe(AlignSelf.Stretch);
+      style.alignSelf = AlignSelf.Auto;
+      expect(style.alignSelf).toBe(AlignSelf.Auto);
+
+      // Auto defers to the parent's alignItems instead of stretching
+      const tree = new TaffyTree();
+      const childStyle = new Style();
+      childStyle.size = { width: 20, height: "auto" };
+      childStyle.alignSelf = AlignSelf.Auto;
+      const child = tree.newLeaf(childStyle);
+      const rootStyle = new Style();
+      rootStyle.size = { width: 100, height: 100 };
+      rootStyle.alignItems = AlignItems.Center;
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+      tree.computeLayout(root, { width: 100, height: 100 });
+      expect(tree.getLayout(child).height).toBe(0);
+
+      childStyle.alignSelf = AlignSelf.Stretch;
+      tree.setStyle(child, childStyle);
+      tree.computeLayout(root, { width: 100, height: 100 });
+      expect(tree.getLayout(child).height).toBe(100);
+
+      tree.free();
+    });
   });
 
   describe("Sizing and Spacing", () => {
@@ -260,6 +317,19 @@ describe("Style Class Properties", () => {
       expect(style.maxSize.height).toBe("100%");
     });
 
+    it("px, percent and auto build dimensions with explicit units", () => {
+      expect(px(200)).toBe(200);
+      expect(percent(50)).toBe("50%");
+      expect(percent(12.5)).toBe("12.5%");
+      expect(auto()).toBe("auto");
+
+      const style = new Style();
+      style.size = { width: px(200), height: percent(50) };
+      expect(style.size).toEqual({ width: 200, height: "50%" });
+      style.minSize = { width: auto(), height: percent(100) };
+      expect(style.minSize).toEqual({ width: "auto", height: "100%" });
+    });
+
     it("aspectRatio: sets and gets correctly", () => {
       const style = new Style();
       expect(style.aspectRatio).toBeUndefined();
@@ -271,6 +341,26 @@ describe("Style Class Properties", () => {
       expect(style.aspectRatio).toBeUndefined();
     });
 
+    it("aspectRatio: clears with undefined and null alike", () => {
+      const style = new Style();
+      style.aspectRatio = 2;
+      style.aspectRatio = null as any;
+      expect(style.aspectRatio).toBeUndefined();
+      expect(style.setProperties()).toEqual([]);
+
+      style.aspectRatio = 2;
+      style.aspectRatio = undefined;
+      expect(style.aspectRatio).toBeUndefined();
+      expect(style.setProperties()).toEqual([]);
+
+      // Invalid ratios leave the current value in place
+      style.aspectRatio = 1.5;
+      for (const invalid of [NaN, Infinity, 0, -1, "2" as any]) {
+        style.aspectRatio = invalid;
+        expect(style.aspectRatio).toBe(1.5);
+      }
+    });
+
     it("gap: sets and gets correctly (LengthPercentage)", () => {
       const style = new Style();
       expect(style.gap.width).toBe(0);
@@ -342,6 +432,17 @@ describe("Style Class Properties", () => {
       expect(i.top).toBe("10%");
       expect(i.bottom).toBe(0);
     });
+
+    it("rawWidth exposes the underlying CompactLength tag and value", () => {
+      const style = new Style();
+      expect(style.rawWidth()).toEqual({ tag: 3, value: 0 });
+
+      style.size = { width: 120, height: "auto" };
+      expect(style.rawWidth()).toEqual({ tag: 1, value: 120 });
+
+      style.size = { width: "50%", height: "auto" };
+      expect(style.rawWidth()).toEqual({ tag: 2, value: 0.5 });
+    });
   });
 
   describe("Block Layout Properties", () => {
@@ -757,6 +858,67 @@ describe("Style Class Properties", () => {
         expect(style.gridAutoFlow).toBe(variant);
       }
     });
+
+    it("gridTemplateColumnTracks: round-trips every track kind", () => {
+      const style = new Style();
+      expect(style.gridTemplateColumnTracks).toEqual([]);
+
+      const tracks: GridTrack[] = [
+        { kind: "px", value: 100 },
+        { kind: "percent", value: 50 },
+        { kind: "fr", value: 1 },
+        { kind: "auto" },
+        { kind: "min-content" },
+        { kind: "max-content" },
+        { kind: "fit-content", limit: { kind: "px", value: 120 } },
+        { kind: "fit-content", limit: { kind: "percent", value: 25 } },
+        {
+          kind: "minmax",
+          min: { kind: "px", value: 100 },
+          max: { kind: "fr", value: 2 },
+        },
+        {
+          kind: "repeat",
+          count: "auto-fill",
+          tracks: [{ kind: "px", value: 80 }],
+          lineNames: [["a"], ["b"]],
+        },
+      ];
+      style.gridTemplateColumnTracks = tracks;
+      expect(style.gridTemplateColumnTracks).toEqual(tracks);
+      expect(style.gridTemplateColumns.length).toBe(tracks.length);
+    });
+
+    it("gridTemplateRowTracks: reads templates set in the min/max form", () => {
+      const style = new Style();
+      style.gridTemplateRows = [
+        { min: "auto", max: "1fr" },
+        { min: 10, max: 10 },
+        { min: "min-content", max: "1fr" },
+      ];
+      expect(style.gridTemplateRowTracks).toEqual([
+        { kind: "fr", value: 1 },
+        { kind: "px", value: 10 },
+        {
+          kind: "minmax",
+          min: { kind: "min-content" },
+          max: { kind: "fr", value: 1 },
+        },
+      ]);
+    });
+
+    it("gridTemplateRowTracks: ignores invalid tracks", () => {
+      const style = new Style();
+      style.gridTemplateRowTracks = [{ kind: "px", value: 10 }];
+      style.gridTemplateRowTracks = [
+        {
+          kind: "minmax",
+          min: { kind: "fr", value: 1 },
+          max: { kind: "auto" },
+        },
+      ];
+      expect(style.gridTemplateRowTracks).toEqual([{ kind: "px", value: 10 }]);
+    });
   });
 
   describe("Block Layout Edge Cases", () => {
@@ -911,4 +1073,240 @@ describe("Style Class Properties", () => {
       expect(style.gridRow.start).toBe(1);
     });
   });
+
+  describe("Convenience Methods", () => {
+    it("makeFullOverlay: sets absolute position and zero inset", () => {
+      const style = new Style();
+      style.inset = { left: 5, right: "auto", top: "10%", bottom: "auto" };
+
+      style.makeFullOverlay();
+
+      expect(style.position).toBe(Position.Absolute);
+      expect(style.inset).toEqual({ left: 0, right: 0, top: 0, bottom: 0 });
+    });
+
+    it("setInset expands one to four values like the CSS shorthand", () => {
+      const style = new Style();
+
+      style.setInset(5);
+      expect(style.inset).toEqual({ left: 5, right: 5, top: 5, bottom: 5 });
+
+      style.setInset(10, "5%");
+      expect(style.inset).toEqual({
+        left: "5%",
+        right: "5%",
+        top: 10,
+        bottom: 10,
+      });
+
+      style.setInset(1, 2, 3);
+      expect(style.inset).toEqual({ left: 2, right: 2, top: 1, bottom: 3 });
+
+      style.setInset(0, "auto", "auto", 4);
+      expect(style.inset).toEqual({
+        left: 4,
+        right: "auto",
+        top: 0,
+        bottom: "auto",
+      });
+    });
+
+    it("setGridGap takes the row gap first, like the CSS shorthand", () => {
+      const style = new Style();
+
+      style.setGridGap(10, 20);
+      expect(style.gap).toEqual({ width: 20, height: 10 });
+
+      style.setGridGap(8, "2%");
+      expect(style.gap).toEqual({ width: "2%", height: 8 });
+
+      style.setGridGap(4);
+      expect(style.gap).toEqual({ width: 4, height: 4 });
+
+      // Invalid values leave the gap unchanged
+      style.setGridGap(1, "auto" as any);
+      expect(style.gap).toEqual({ width: 4, height: 4 });
+      expect(style.setProperties()).toEqual(["gap"]);
+    });
+
+    it("clearAspectRatio: removes the aspect ratio", () => {
+      const style = new Style();
+      style.aspectRatio = 16 / 9;
+      expect(style.setProperties()).toEqual(["aspectRatio"]);
+
+      style.clearAspectRatio();
+      expect(style.aspectRatio).toBeUndefined();
+      expect(style.setProperties()).toEqual([]);
+
+      // Clearing an unset ratio is a no-op
+      style.clearAspectRatio();
+      expect(style.aspectRatio).toBeUndefined();
+    });
+
+    it("setInset leaves the inset unchanged for invalid values", () => {
+      const style = new Style();
+      style.setInset(7);
+      style.setInset(1, "invalid" as any);
+      expect(style.inset).toEqual({ left: 7, right: 7, top: 7, bottom: 7 });
+    });
+
+    it("clone: copies values without sharing them", () => {
+      const base = new Style();
+      base.display = Display.Flex;
+      base.gap = { width: 8, height: 4 };
+      const copy = base.clone();
+      expect(copy.display).toBe(Display.Flex);
+      expect(copy.gap).toEqual({ width: 8, height: 4 });
+      expect(copy.setProperties()).toEqual(base.setProperties());
+
+      copy.flexGrow = 2;
+      copy.gap = { width: 0, height: 0 };
+      expect(base.flexGrow).toBe(0);
+      expect(base.gap).toEqual({ width: 8, height: 4 });
+      expect(base.setProperties()).toEqual(["display", "gap"]);
+
+      base.display = Display.Grid;
+      expect(copy.display).toBe(Display.Flex);
+    });
+
+    it("toObject/fromObject: round-trips every property", () => {
+      const style = new Style();
+      style.display = Display.Flex;
+      style.flexDirection = FlexDirection.Column;
+      style.flexGrow = 2;
+      style.flexBasis = "25%";
+      style.size = { width: 120, height: "50%" };
+      style.margin = { left: 4, right: "auto", top: 0, bottom: "10%" };
+      style.gap = { width: 8, height: 6 };
+
+      const object = style.toObject();
+      expect(object.flexGrow).toBe(2);
+      expect(object.size).toEqual({ width: 120, height: "50%" });
+      // Defaults are included, unset optional properties are not
+      expect(object.flexShrink).toBe(1);
+      expect("alignItems" in object).toBe(false);
+
+      const copy = Style.fromObject(JSON.parse(JSON.stringify(object)));
+      expect(copy.toObject()).toEqual(object);
+      expect(copy.margin).toEqual(style.margin);
+      expect(copy.flexDirection).toBe(FlexDirection.Column);
+    });
+
+    it("fromObject: falls back to defaults for missing keys", () => {
+      const style = Style.fromObject({ flexGrow: 3, unknown: true });
+      expect(style.flexGrow).toBe(3);
+      expect(style.flexShrink).toBe(1);
+      expect(style.setProperties()).toEqual(["flexGrow"]);
+      expect(Style.fromObject({}).toObject()).toEqual(new Style().toObject());
+    });
+  });
+
+  describe("Viewport Units", () => {
+    afterEach(() => {
+      clearViewport();
+    });
+
+    it("resolves vw/vh against the configured viewport at set time", () => {
+      const style = new Style();
+
+      // Rejected (and ignored) while no viewport is configured
+      style.margin = { left: "10vw", right: 0, top: 0, bottom: 0 };
+      expect(style.margin.left).toBe(0);
+
+      setViewport(1000, 500);
+      style.size = { width: "50vw", height: "10vh" };
+      style.padding = { left: "1vw", right: "1vw", top: "2vh", bottom: "2vh" };
+      expect(style.size).toEqual({ width: 500, height: 50 });
+      expect(style.padding).toEqual({
+        left: 10,
+        right: 10,
+        top: 10,
+        bottom: 10,
+      });
+
+      // Resolved eagerly: changing the viewport doesn't affect existing values
+      setViewport(2000, 1000);
+      expect(style.size).toEqual({ width: 500, height: 50 });
+    });
+  });
+
+  describe("Enum Names", () => {
+    it("maps enum values to their variant names", () => {
+      expect(enumName("JustifyContent", JustifyContent.SpaceBetween)).toBe(
+        "SpaceBetween",
+      );
+      expect(enumName("Display", Display.None)).toBe("None");
+      expect(enumName("AlignSelf", AlignSelf.Auto)).toBe("Auto");
+      expect(enumName("GridAutoFlow", GridAutoFlow.ColumnDense)).toBe(
+        "ColumnDense",
+      );
+    });
+
+    it("returns undefined for unknown enums or values", () => {
+      expect(enumName("Display", 42)).toBeUndefined();
+      expect(enumName("NotAnEnum", 0)).toBeUndefined();
+    });
+
+    it("exports every enum as a frozen name-to-value map", () => {
+      const enums = getEnums();
+      expect(enums.Display).toEqual({
+        Block: Display.Block,
+        Flex: Display.Flex,
+        Grid: Display.Grid,
+        None: Display.None,
+      });
+      expect(enums.JustifyContent.SpaceBetween).toBe(
+        JustifyContent.SpaceBetween,
+      );
+      expect(Object.keys(enums)).toContain("GridAutoFlow");
+      expect(Object.isFrozen(enums)).toBe(true);
+      expect(Object.isFrozen(enums.Display)).toBe(true);
+    });
+  });
+
+  describe("Property Presence", () => {
+    it("starts with no properties set", () => {
+      const style = new Style();
+      expect(style.setProperties()).toEqual([]);
+    });
+
+    it("reports explicitly set properties in declaration order", () => {
+      const style = new Style();
+      style.gap = { width: 8, height: 8 };
+      style.display = Display.Flex;
+      // Setting a default value still counts as set
+      style.flexShrink = 1;
+      expect(style.setProperties()).toEqual(["display", "flexShrink", "gap"]);
+    });
+
+    it("unsets optional properties cleared with undefined", () => {
+      const style = new Style();
+      style.alignItems = AlignItems.Center;
+      style.aspectRatio = 1.5;
+      expect(style.setProperties()).toEqual(["alignItems", "aspectRatio"]);
+
+      style.alignItems = undefined;
+      style.aspectRatio = undefined;
+      expect(style.setProperties()).toEqual([]);
+    });
+
+    it("ignores rejected values", () => {
+      const style = new Style();
+      style.margin = "invalid" as any;
+      expect(style.setProperties()).toEqual([]);
+    });
+
+    it("reports non-default properties of styles read from a tree", () => {
+      const tree = new TaffyTree();
+      const style = new Style();
+      style.display = Display.Grid;
+      style.position = Position.Relative;
+      style.size = { width: 100, height: "auto" };
+      const node = tree.newLeaf(style);
+
+      expect(tree.getStyle(node).setProperties()).toEqual(["display", "size"]);
+
+      tree.free();
+    });
+  });
 });
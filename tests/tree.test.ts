@@ -0,0 +1,2272 @@
+import { describe, it, expect, beforeAll } from "vitest";
+import { setupTaffy } from "./utils";
+import {
+  TaffyTree,
+  Style,
+  TaffyError,
+  Display,
+  FlexDirection,
+  FlexWrap,
+  AlignItems,
+  Position,
+  JustifyContent,
+  Overflow,
+} from "../src/index";
+
+describe("TaffyTree", () => {
+  beforeAll(async () => {
+    await setupTaffy();
+  });
+
+  describe("Child Management", () => {
+    it("inserts before and after a reference child", () => {
+      const tree = new TaffyTree();
+      const parent = tree.newLeaf(new Style());
+      const a = tree.newLeaf(new Style());
+      const b = tree.newLeaf(new Style());
+      tree.setChildren(parent, BigUint64Array.from([a, b]));
+
+      const beforeB = tree.newLeaf(new Style());
+      tree.insertBefore(parent, beforeB, b);
+      const afterB = tree.newLeaf(new Style());
+      tree.insertAfter(parent, afterB, b);
+      const first = tree.newLeaf(new Style());
+      tree.insertBefore(parent, first, a);
+
+      expect(Array.from(tree.children(parent))).toEqual([
+        first,
+        a,
+        beforeB,
+        b,
+        afterB,
+      ]);
+      expect(tree.childIndex(parent, b)).toBe(3);
+
+      tree.free();
+    });
+
+    it("throws when the reference child is not a child of the parent", () => {
+      const tree = new TaffyTree();
+      const parent = tree.newLeaf(new Style());
+      const stranger = tree.newLeaf(new Style());
+      const child = tree.newLeaf(new Style());
+
+      expect(() => tree.insertBefore(parent, child, stranger)).toThrow();
+      expect(() => tree.insertAfter(parent, child, stranger)).toThrow();
+      let error: unknown;
+      try {
+        tree.childIndex(parent, stranger);
+      } catch (e) {
+        error = e;
+      }
+      expect(error).toBeInstanceOf(TaffyError);
+      expect(tree.childCount(parent)).toBe(0);
+
+      tree.addChild(parent, stranger);
+      tree.remove(child);
+      expect(() => tree.insertBefore(parent, child, stranger)).toThrow(
+        TaffyError,
+      );
+      expect(() => tree.insertAfter(parent, child, stranger)).toThrow(
+        TaffyError,
+      );
+      tree.remove(parent);
+      expect(() => tree.childIndex(parent, stranger)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reads the first and last child", () => {
+      const tree = new TaffyTree();
+      const parent = tree.newLeaf(new Style());
+      expect(tree.firstChild(parent)).toBeUndefined();
+      expect(tree.lastChild(parent)).toBeUndefined();
+
+      const a = tree.newLeaf(new Style());
+      tree.addChild(parent, a);
+      expect(tree.firstChild(parent)).toBe(a);
+      expect(tree.lastChild(parent)).toBe(a);
+
+      const b = tree.newLeaf(new Style());
+      const c = tree.newLeaf(new Style());
+      tree.addChild(parent, b);
+      tree.addChild(parent, c);
+      expect(tree.firstChild(parent)).toBe(a);
+      expect(tree.lastChild(parent)).toBe(c);
+
+      tree.remove(parent);
+      expect(() => tree.firstChild(parent)).toThrow(TaffyError);
+      expect(() => tree.lastChild(parent)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("checks ancestor and descendant relationships", () => {
+      const tree = new TaffyTree();
+      const leaf = tree.newLeaf(new Style());
+      const sibling = tree.newLeaf(new Style());
+      const mid = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([leaf, sibling]),
+      );
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([mid]),
+      );
+      const detached = tree.newLeaf(new Style());
+
+      expect(tree.isAncestorOf(root, leaf)).toBe(true);
+      expect(tree.isAncestorOf(mid, leaf)).toBe(true);
+      expect(tree.isAncestorOf(leaf, root)).toBe(false);
+      expect(tree.isAncestorOf(sibling, leaf)).toBe(false);
+      expect(tree.isAncestorOf(leaf, leaf)).toBe(false);
+      expect(tree.isAncestorOf(root, detached)).toBe(false);
+
+      expect(tree.isDescendantOf(leaf, root)).toBe(true);
+      expect(tree.isDescendantOf(root, leaf)).toBe(false);
+      expect(tree.isDescendantOf(mid, mid)).toBe(false);
+
+      tree.free();
+    });
+
+    it("throws for relationship checks on removed nodes", () => {
+      const tree = new TaffyTree();
+      const a = tree.newLeaf(new Style());
+      const b = tree.newLeaf(new Style());
+      tree.remove(b);
+
+      expect(() => tree.isAncestorOf(a, b)).toThrow(TaffyError);
+      expect(() => tree.isDescendantOf(b, a)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports the maximum depth of a subtree", () => {
+      const tree = new TaffyTree();
+      const deep = tree.newLeaf(new Style());
+      const mid = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([deep]),
+      );
+      const shallow = tree.newLeaf(new Style());
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([shallow, mid]),
+      );
+
+      expect(tree.maxDepth(root)).toBe(2);
+      expect(tree.maxDepth(mid)).toBe(1);
+      expect(tree.maxDepth(deep)).toBe(0);
+
+      tree.remove(deep);
+      expect(() => tree.maxDepth(deep)).toThrow(TaffyError);
+      expect(tree.maxDepth(root)).toBe(1);
+
+      tree.free();
+    });
+
+    it("counts every descendant of a subtree", () => {
+      const tree = new TaffyTree();
+      const root = tree.buildTree({
+        children: [{ children: [{}, { children: [{}] }] }, {}],
+      });
+      const [branch, leaf] = tree.children(root);
+
+      expect(tree.descendantCount(root)).toBe(5);
+      expect(tree.descendantCount(branch)).toBe(3);
+      expect(tree.descendantCount(leaf)).toBe(0);
+      expect(tree.childCount(root)).toBe(2);
+
+      tree.remove(leaf);
+      expect(tree.descendantCount(root)).toBe(4);
+      expect(() => tree.descendantCount(leaf)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("lists descendants in pre-order", () => {
+      const tree = new TaffyTree();
+      const root = tree.buildTree({
+        children: [{ children: [{}, { children: [{}] }] }, {}],
+      });
+      const [branch, leaf] = tree.children(root);
+      const [first, nested] = tree.children(branch);
+      const [deep] = tree.children(nested);
+
+      const expected = [branch, first, nested, deep, leaf];
+      expect(Array.from(tree.descendants(root))).toEqual(expected);
+      expect(Array.from(tree.descendants(root, true))).toEqual([
+        root,
+        ...expected,
+      ]);
+      expect(tree.descendants(leaf)).toHaveLength(0);
+
+      tree.remove(leaf);
+      expect(() => tree.descendants(leaf)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("builds a subtree from a declarative spec", () => {
+      const tree = new TaffyTree();
+      const root = tree.buildTree({
+        style: {
+          size: { width: 300, height: 100 },
+          gap: { width: 30, height: 0 },
+        },
+        children: [
+          { style: { flexGrow: 1 } },
+          {
+            style: { flexGrow: 2, flexDirection: FlexDirection.Column },
+            context: { text: "hello" },
+            children: [{}, {}],
+          },
+        ],
+      });
+      expect(tree.totalNodeCount()).toBe(5);
+      const [first, second] = tree.children(root);
+      expect(tree.childCount(second)).toBe(2);
+      expect(tree.getStyle(second).flexDirection).toBe(FlexDirection.Column);
+      expect(tree.getNodeContext(second)).toEqual({ text: "hello" });
+
+      tree.computeLayout(root, { width: 300, height: 100 });
+      expect(tree.getLayout(first).width).toBe(90);
+      expect(tree.getLayout(second).width).toBe(180);
+
+      tree.free();
+    });
+
+    it("creates nodes from a default style template", () => {
+      const tree = new TaffyTree();
+      expect(tree.getStyle(tree.newLeafDefault()).display).toBe(Display.Flex);
+
+      const template = new Style();
+      template.display = Display.Grid;
+      template.size = { width: 10, height: 10 };
+      tree.setDefaultStyle(template);
+
+      // The template is copied
+      template.display = Display.Block;
+      const node = tree.newLeafDefault();
+      expect(tree.getStyle(node).display).toBe(Display.Grid);
+      expect(tree.getStyle(node).size).toEqual({ width: 10, height: 10 });
+
+      // newLeaf() without a style uses it too; with one, it is ignored
+      const plain = tree.newLeaf();
+      expect(tree.getStyle(plain).display).toBe(Display.Grid);
+      expect(tree.getStyle(plain).size).toEqual({ width: 10, height: 10 });
+      expect(tree.getStyle(tree.newLeaf(new Style())).display).toBe(
+        Display.Flex,
+      );
+
+      // buildTree() specs start from the template too
+      const root = tree.buildTree({ style: { display: Display.Block } });
+      expect(tree.getStyle(root).display).toBe(Display.Block);
+      expect(tree.getStyle(root).size).toEqual({ width: 10, height: 10 });
+
+      tree.clear();
+      expect(tree.getStyle(tree.newLeafDefault()).display).toBe(Display.Grid);
+
+      tree.free();
+    });
+
+    it("reports the path of a malformed spec node and builds nothing", () => {
+      const tree = new TaffyTree();
+      const build = (spec: any) => {
+        try {
+          tree.buildTree(spec);
+        } catch (e) {
+          expect(e).toBeInstanceOf(TaffyError);
+          return (e as TaffyError).message;
+        }
+        throw new Error("expected buildTree to throw");
+      };
+
+      expect(
+        build({ children: [{}, { children: [{ style: { colour: 1 } }] }] }),
+      ).toBe(
+        "Invalid tree spec at spec.children[1].children[0].style: " +
+          "Unknown style property: colour",
+      );
+      expect(build({ children: {} })).toBe(
+        "Invalid tree spec at spec.children: expected an array",
+      );
+      expect(build({ children: [42] })).toBe(
+        "Invalid tree spec at spec.children[0]: expected an object",
+      );
+      expect(tree.totalNodeCount()).toBe(0);
+
+      tree.free();
+    });
+
+    it("removes many nodes, skipping missing and duplicate ids", () => {
+      const tree = new TaffyTree();
+      const leaf = tree.newLeaf(new Style());
+      const mid = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([leaf]),
+      );
+      const kept = tree.newLeaf(new Style());
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([mid, kept]),
+      );
+      const stray = tree.newLeaf(new Style());
+      tree.remove(stray);
+
+      const removed = tree.removeMany(
+        BigUint64Array.from([mid, leaf, stray, leaf]),
+      );
+      expect(removed).toBe(2);
+      expect(tree.totalNodeCount()).toBe(2);
+      expect(Array.from(tree.children(root))).toEqual([kept]);
+      expect(tree.removeMany(new BigUint64Array())).toBe(0);
+      expect(() => tree.remove(stray)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("removes the descendants of each removed node", () => {
+      const tree = new TaffyTree();
+      const leaf = tree.newLeaf(new Style());
+      const mid = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([leaf]),
+      );
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([mid]),
+      );
+
+      expect(tree.removeMany(BigUint64Array.from([mid]))).toBe(2);
+      expect(tree.totalNodeCount()).toBe(1);
+      expect(tree.childCount(root)).toBe(0);
+      expect(tree.orphanNodes()).toHaveLength(1);
+      expect(() => tree.remove(leaf)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("lists parentless nodes, including detached children", () => {
+      const tree = new TaffyTree();
+      const child = tree.newLeaf(new Style());
+      const mid = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([child]),
+      );
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([mid]),
+      );
+      expect(Array.from(tree.orphanNodes())).toEqual([root]);
+
+      tree.remove(mid);
+      const orphans = Array.from(tree.orphanNodes());
+      expect(orphans).toHaveLength(2);
+      expect(orphans).toContain(root);
+      expect(orphans).toContain(child);
+
+      tree.clear();
+      expect(tree.orphanNodes()).toHaveLength(0);
+
+      tree.free();
+    });
+
+    it("validates tree structure", () => {
+      const tree = new TaffyTree();
+      const child = tree.newLeaf(new Style());
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([child]),
+      );
+      expect(tree.validateTree()).toEqual([]);
+
+      // Adding a child that already has a parent leaves it listed twice
+      const other = tree.newLeaf(new Style());
+      tree.addChild(other, child);
+      expect(tree.validateTree()).toEqual([
+        {
+          kind: "multiple-parents",
+          node: child,
+          message: `Node ${child} is a child of nodes ${root}, ${other}`,
+        },
+      ]);
+
+      // Removing it through its new parent leaves a dangling reference
+      tree.remove(child);
+      const problems = tree.validateTree();
+      expect(problems.map(({ kind, node }) => [kind, node])).toEqual([
+        ["dangling-child", root],
+      ]);
+
+      tree.free();
+    });
+
+    it("detects cycles", () => {
+      const tree = new TaffyTree();
+      const a = tree.newLeaf(new Style());
+      const b = tree.newLeaf(new Style());
+      tree.addChild(a, b);
+      tree.addChild(b, a);
+
+      const problems = tree.validateTree();
+      expect(problems).toHaveLength(1);
+      expect(problems[0].kind).toBe("cycle");
+      expect([a, b]).toContain(problems[0].node);
+
+      tree.free();
+    });
+
+    it("checks whether a node is a root", () => {
+      const tree = new TaffyTree();
+      const child = tree.newLeaf(new Style());
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([child]),
+      );
+
+      expect(tree.isRoot(root)).toBe(true);
+      expect(tree.isRoot(child)).toBe(false);
+
+      tree.removeChild(root, child);
+      expect(tree.isRoot(child)).toBe(true);
+      expect(tree.isRoot(child)).toBe(tree.parent(child) === undefined);
+
+      tree.remove(child);
+      expect(() => tree.isRoot(child)).toThrow(TaffyError);
+
+      tree.free();
+    });
+  });
+
+  describe("Frozen Mode", () => {
+    it("rejects mutations while frozen and allows reads", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.size = { width: 100, height: 100 };
+      const root = tree.newLeaf(rootStyle);
+      const child = tree.newLeaf(new Style());
+      tree.addChild(root, child);
+      tree.computeLayout(root, { width: 100, height: 100 });
+
+      tree.freeze();
+      expect(tree.isFrozen()).toBe(true);
+      expect(() => tree.newLeaf(new Style())).toThrow(TaffyError);
+      expect(() => tree.addChild(root, child)).toThrow(TaffyError);
+      expect(() => tree.setStyle(child, new Style())).toThrow(TaffyError);
+      expect(() => tree.markDirty(child)).toThrow(TaffyError);
+      expect(() => tree.remove(child)).toThrow(TaffyError);
+      expect(() => tree.clear()).toThrow(TaffyError);
+      expect(() =>
+        tree.computeLayout(root, { width: 100, height: 100 }),
+      ).toThrow(TaffyError);
+
+      expect(tree.getLayout(root).width).toBe(100);
+      expect(Array.from(tree.children(root))).toEqual([child]);
+      expect(tree.totalNodeCount()).toBe(2);
+
+      tree.unfreeze();
+      expect(tree.isFrozen()).toBe(false);
+      tree.newLeaf(new Style());
+      expect(tree.totalNodeCount()).toBe(3);
+
+      tree.free();
+    });
+
+    it("computes the layout and then freezes the tree", () => {
+      const tree = new TaffyTree();
+      const style = new Style();
+      style.size = { width: 100, height: 40 };
+      const root = tree.newLeaf(style);
+
+      tree.computeAndFreeze(root, { width: 800, height: 600 });
+      expect(tree.isFrozen()).toBe(true);
+      expect(tree.getLayout(root).width).toBe(100);
+      expect(() =>
+        tree.computeLayout(root, { width: 800, height: 600 }),
+      ).toThrow(TaffyError);
+      expect(() =>
+        tree.computeAndFreeze(root, { width: 800, height: 600 }),
+      ).toThrow(TaffyError);
+
+      tree.unfreeze();
+      style.size = { width: 200, height: 40 };
+      tree.setStyle(root, style);
+      tree.computeAndFreeze(root, { width: 800, height: 600 });
+      expect(tree.getLayout(root).width).toBe(200);
+      expect(tree.isFrozen()).toBe(true);
+
+      tree.free();
+    });
+  });
+
+  describe("Layout Results", () => {
+    it("reports the box model in one call", () => {
+      const tree = new TaffyTree();
+      const style = new Style();
+      style.size = { width: 100, height: 50 };
+      style.margin = { left: 5, right: 6, top: 7, bottom: 8 };
+      style.border = { left: 1, right: 1, top: 2, bottom: 2 };
+      style.padding = { left: 10, right: 10, top: 4, bottom: 4 };
+      const root = tree.newLeaf(new Style());
+      const node = tree.newLeaf(style);
+      tree.addChild(root, node);
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      const layout = tree.getLayout(node);
+      const box = tree.boxModel(node);
+      expect(box.margin).toEqual({ left: 5, right: 6, top: 7, bottom: 8 });
+      expect(box.border).toEqual({ left: 1, right: 1, top: 2, bottom: 2 });
+      expect(box.padding).toEqual({ left: 10, right: 10, top: 4, bottom: 4 });
+      expect(box.content).toEqual({
+        x: layout.x + 11,
+        y: layout.y + 6,
+        width: 78,
+        height: 38,
+      });
+
+      tree.remove(node);
+      expect(() => tree.boxModel(node)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports resolved padding and border as objects", () => {
+      const tree = new TaffyTree();
+      const style = new Style();
+      style.padding = { left: "5%", right: 3, top: "5%", bottom: 0 };
+      style.border = { left: "2.5%", right: 0, top: 1, bottom: 2 };
+      const node = tree.newLeaf(style);
+      const rootStyle = new Style();
+      rootStyle.size = { width: 400, height: 200 };
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([node]));
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      const layout = tree.getLayout(node);
+      const padding = tree.resolvedPadding(node);
+      expect(padding).toEqual({ left: 20, right: 3, top: 20, bottom: 0 });
+      expect(padding.left).toBe(layout.paddingLeft);
+      expect(tree.resolvedBorder(node)).toEqual({
+        left: 10,
+        right: 0,
+        top: 1,
+        bottom: 2,
+      });
+
+      tree.remove(node);
+      expect(() => tree.resolvedPadding(node)).toThrow(TaffyError);
+      expect(() => tree.resolvedBorder(node)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports resolved auto margins", () => {
+      const tree = new TaffyTree();
+      const style = new Style();
+      style.size = { width: 40, height: 30 };
+      style.margin = { left: "auto", right: "auto", top: 5, bottom: "auto" };
+      const node = tree.newLeaf(style);
+      const rootStyle = new Style();
+      rootStyle.size = { width: 200, height: 100 };
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([node]));
+      tree.computeLayout(root, { width: 200, height: 100 });
+
+      const margin = tree.resolvedMargin(node);
+      expect(margin).toEqual({ left: 80, right: 80, top: 5, bottom: 65 });
+      expect(margin.left).toBe(tree.getLayout(node).marginLeft);
+
+      tree.remove(node);
+      expect(() => tree.resolvedMargin(node)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports whether auto margins took up space", () => {
+      const tree = new TaffyTree();
+      const centeredStyle = new Style();
+      centeredStyle.size = { width: 40, height: 100 };
+      centeredStyle.margin = { left: "auto", right: "auto", top: 0, bottom: 0 };
+      const centered = tree.newLeaf(centeredStyle);
+      const fullStyle = new Style();
+      fullStyle.size = { width: 200, height: 100 };
+      fullStyle.margin = { left: "auto", right: 0, top: "auto", bottom: 0 };
+      const full = tree.newLeaf(fullStyle);
+      const rootStyle = new Style();
+      rootStyle.flexDirection = FlexDirection.Column;
+      rootStyle.size = { width: 200, height: 200 };
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([centered, full]),
+      );
+      tree.computeLayout(root, { width: 200, height: 200 });
+
+      expect(tree.autoMarginApplied(centered)).toEqual({
+        horizontal: true,
+        vertical: false,
+      });
+      expect(tree.autoMarginApplied(full)).toEqual({
+        horizontal: false,
+        vertical: false,
+      });
+      expect(tree.autoMarginApplied(root)).toEqual({
+        horizontal: false,
+        vertical: false,
+      });
+
+      tree.remove(full);
+      expect(() => tree.autoMarginApplied(full)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("exports a nested node's layout as absolute CSS", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.flexDirection = FlexDirection.Column;
+      rootStyle.size = { width: 800, height: 600 };
+      rootStyle.padding = { left: 10, right: 10, top: 10, bottom: 10 };
+
+      const midStyle = new Style();
+      midStyle.display = Display.Flex;
+      midStyle.size = { width: 200, height: 100 };
+      midStyle.margin = { left: 5, right: 0, top: 0, bottom: 0 };
+      midStyle.padding = { left: 7, right: 7, top: 7, bottom: 7 };
+
+      const leafStyle = new Style();
+      leafStyle.size = { width: 30, height: 20 };
+
+      const leaf = tree.newLeaf(leafStyle);
+      const mid = tree.newWithChildren(midStyle, BigUint64Array.from([leaf]));
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([mid]));
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      expect(tree.layoutToCssPosition(leaf)).toBe(
+        "position: absolute; left: 22px; top: 17px; width: 30px; height: 20px;",
+      );
+      expect(tree.layoutToCssPosition(root)).toBe(
+        "position: absolute; left: 0px; top: 0px; width: 800px; height: 600px;",
+      );
+
+      tree.remove(leaf);
+      expect(() => tree.layoutToCssPosition(leaf)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports which axes overflow", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.size = { width: 100, height: 100 };
+
+      const childStyle = new Style();
+      childStyle.size = { width: 300, height: 50 };
+      childStyle.flexShrink = 0;
+
+      const child = tree.newLeaf(childStyle);
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+      tree.computeLayout(root, { width: 100, height: 100 });
+
+      expect(tree.overflowAxes(root)).toEqual({ x: true, y: false });
+      expect(tree.overflowAxes(child)).toEqual({ x: false, y: false });
+
+      tree.remove(child);
+      expect(() => tree.overflowAxes(child)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("expresses a layout in an ancestor's coordinate space", () => {
+      const tree = new TaffyTree();
+      const paddedStyle = new Style();
+      paddedStyle.display = Display.Flex;
+      paddedStyle.padding = { left: 10, right: 10, top: 5, bottom: 5 };
+      const leafStyle = new Style();
+      leafStyle.size = { width: 30, height: 20 };
+
+      const leaf = tree.newLeaf(leafStyle);
+      const layer = tree.newWithChildren(
+        paddedStyle,
+        BigUint64Array.from([leaf]),
+      );
+      const root = tree.newWithChildren(
+        paddedStyle,
+        BigUint64Array.from([layer]),
+      );
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      const inParent = tree.layoutRelativeTo(leaf, layer);
+      expect(inParent.x).toBe(tree.getLayout(leaf).x);
+      expect(inParent.y).toBe(tree.getLayout(leaf).y);
+
+      const inRoot = tree.layoutRelativeTo(leaf, root);
+      expect(inRoot.x).toBe(20);
+      expect(inRoot.y).toBe(10);
+      expect(inRoot.width).toBe(30);
+      expect(inRoot.height).toBe(20);
+
+      tree.free();
+    });
+
+    it("throws when the ancestor is not an ancestor of the node", () => {
+      const tree = new TaffyTree();
+      const leaf = tree.newLeaf(new Style());
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([leaf]),
+      );
+      const stranger = tree.newLeaf(new Style());
+      tree.computeLayout(root, { width: 100, height: 100 });
+
+      expect(() => tree.layoutRelativeTo(leaf, stranger)).toThrow(TaffyError);
+      expect(() => tree.layoutRelativeTo(root, leaf)).toThrow(TaffyError);
+      expect(() => tree.layoutRelativeTo(leaf, leaf)).toThrow(TaffyError);
+
+      tree.remove(stranger);
+      expect(() => tree.layoutRelativeTo(leaf, stranger)).toThrow(TaffyError);
+      tree.remove(leaf);
+      expect(() => tree.layoutRelativeTo(leaf, root)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("subtracts ancestor scroll offsets for viewport positions", () => {
+      const tree = new TaffyTree();
+      const paddedStyle = new Style();
+      paddedStyle.padding = { left: 10, right: 10, top: 5, bottom: 5 };
+      const leafStyle = new Style();
+      leafStyle.size = { width: 30, height: 20 };
+
+      const leaf = tree.newLeaf(leafStyle);
+      const list = tree.newWithChildren(
+        paddedStyle,
+        BigUint64Array.from([leaf]),
+      );
+      const root = tree.newWithChildren(
+        paddedStyle,
+        BigUint64Array.from([list]),
+      );
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      const unscrolled = tree.viewportLayout(leaf, undefined);
+      expect(unscrolled.x).toBe(20);
+      expect(unscrolled.y).toBe(10);
+
+      const scrolled = tree.viewportLayout(
+        leaf,
+        new Map([
+          [list, { x: 0, y: 50 }],
+          [root, { x: 5, y: 0 }],
+        ]),
+      );
+      expect(scrolled.x).toBe(15);
+      expect(scrolled.y).toBe(-40);
+      expect(scrolled.width).toBe(30);
+
+      // A container's own scroll position does not move it
+      const own = new Map([[list, { x: 0, y: 50 }]]);
+      expect(tree.viewportLayout(list, own).y).toBe(5);
+
+      tree.remove(leaf);
+      expect(() => tree.viewportLayout(leaf, undefined)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("exports a subtree as DOMRect-like objects in pre-order", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.padding = { left: 10, right: 10, top: 10, bottom: 10 };
+      const boxStyle = new Style();
+      boxStyle.display = Display.Flex;
+      boxStyle.size = { width: 100, height: 50 };
+      boxStyle.padding = { left: 5, right: 5, top: 5, bottom: 5 };
+      const leafStyle = new Style();
+      leafStyle.size = { width: 20, height: 20 };
+
+      const leaf = tree.newLeaf(leafStyle);
+      const first = tree.newWithChildren(boxStyle, BigUint64Array.from([leaf]));
+      const second = tree.newLeaf(boxStyle);
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([first, second]),
+      );
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      const rects = tree.domRects(root);
+      expect(rects.map((r) => r.nodeId)).toEqual([root, first, leaf, second]);
+      expect(rects[2]).toEqual({
+        nodeId: leaf,
+        x: 15,
+        y: 15,
+        width: 20,
+        height: 20,
+        top: 15,
+        right: 35,
+        bottom: 35,
+        left: 15,
+      });
+      expect(rects[3].x).toBe(110);
+      expect(rects[3].right).toBe(210);
+
+      // Subtrees keep absolute coordinates
+      const sub = tree.domRects(first);
+      expect(sub.map((r) => r.nodeId)).toEqual([first, leaf]);
+      expect(sub[1].x).toBe(15);
+
+      tree.remove(first);
+      expect(() => tree.domRects(first)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("keeps pinned layout snapshots across recomputes", () => {
+      const tree = new TaffyTree();
+      const childStyle = new Style();
+      childStyle.flexGrow = 1;
+      const child = tree.newLeaf(childStyle);
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.size = { width: "100%", height: "auto" };
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      expect(tree.pinnedLayout(child)).toBeUndefined();
+      tree.pinLayout(child);
+      const copy = tree.getLayout(child);
+
+      tree.computeLayout(root, { width: 400, height: 600 });
+      expect(tree.getLayout(child).width).toBe(400);
+      expect(tree.pinnedLayout(child)!.width).toBe(800);
+      expect(copy.width).toBe(800);
+
+      // Pinning again replaces the snapshot
+      tree.pinLayout(child);
+      expect(tree.pinnedLayout(child)!.width).toBe(400);
+
+      tree.remove(child);
+      expect(tree.pinnedLayout(child)).toBeUndefined();
+      expect(() => tree.pinLayout(child)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("resolves percentage gaps against the content box", () => {
+      const tree = new TaffyTree();
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      gridStyle.size = { width: 200, height: 400 };
+      gridStyle.padding = { left: 10, right: 10, top: 10, bottom: 10 };
+      gridStyle.gap = { width: "5%", height: "10%" };
+      const grid = tree.newLeaf(gridStyle);
+      tree.computeLayout(grid, { width: 800, height: 600 });
+
+      // Content box is 180 x 380
+      expect(gridStyle.gap).toEqual({ width: "5%", height: "10%" });
+      expect(tree.resolvedGap(grid)).toEqual({ row: 38, column: 9 });
+
+      const plain = tree.newLeaf(new Style());
+      const blockStyle = new Style();
+      blockStyle.display = Display.Block;
+      blockStyle.gap = { width: 10, height: 10 };
+      const block = tree.newLeaf(blockStyle);
+      tree.computeLayout(plain, { width: 800, height: 600 });
+      tree.computeLayout(block, { width: 800, height: 600 });
+      expect(tree.resolvedGap(plain)).toEqual({ row: 0, column: 0 });
+      expect(tree.resolvedGap(block)).toEqual({ row: 0, column: 0 });
+
+      tree.remove(block);
+      expect(() => tree.resolvedGap(block)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports the flex basis of flex items before growing", () => {
+      const tree = new TaffyTree();
+      const percentStyle = new Style();
+      percentStyle.flexBasis = "25%";
+      percentStyle.flexGrow = 1;
+      const percent = tree.newLeaf(percentStyle);
+      const sizedStyle = new Style();
+      sizedStyle.size = { width: 150, height: "auto" };
+      const sized = tree.newLeaf(sizedStyle);
+      const leafStyle = new Style();
+      leafStyle.size = { width: 120, height: 10 };
+      const content = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([tree.newLeaf(leafStyle)]),
+      );
+      const paddedStyle = new Style();
+      paddedStyle.flexBasis = 0;
+      paddedStyle.padding = { left: 10, right: 10, top: 0, bottom: 0 };
+      const padded = tree.newLeaf(paddedStyle);
+      const absoluteStyle = new Style();
+      absoluteStyle.position = Position.Absolute;
+      const absolute = tree.newLeaf(absoluteStyle);
+
+      const rootStyle = new Style();
+      rootStyle.size = { width: 800, height: 100 };
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([percent, sized, content, padded, absolute]),
+      );
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      expect(tree.resolvedFlexBasis(percent)).toBe(200);
+      expect(tree.getLayout(percent).width).toBeGreaterThan(200);
+      expect(tree.resolvedFlexBasis(sized)).toBe(150);
+      expect(tree.resolvedFlexBasis(content)).toBe(120);
+      expect(tree.resolvedFlexBasis(padded)).toBe(20);
+      expect(tree.resolvedFlexBasis(absolute)).toBeNull();
+      expect(tree.resolvedFlexBasis(root)).toBeNull();
+
+      tree.free();
+    });
+
+    it("resolves the flex basis along a column main axis", () => {
+      const tree = new TaffyTree();
+      const itemStyle = new Style();
+      itemStyle.size = { width: 50, height: 30 };
+      const item = tree.newLeaf(itemStyle);
+      const rootStyle = new Style();
+      rootStyle.flexDirection = FlexDirection.Column;
+      rootStyle.size = { width: 100, height: 400 };
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([item]));
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.resolvedFlexBasis(item)).toBe(30);
+
+      // Children of non-flex containers are not flex items
+      rootStyle.display = Display.Block;
+      tree.setStyle(root, rootStyle);
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.resolvedFlexBasis(item)).toBeNull();
+
+      tree.remove(item);
+      expect(() => tree.resolvedFlexBasis(item)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("exports a subtree as nested style and layout objects", () => {
+      const tree = new TaffyTree();
+      const root = tree.buildTree({
+        style: {
+          size: { width: 400, height: 200 },
+          padding: { left: 10, right: 10, top: 5, bottom: 5 },
+        },
+        children: [
+          { style: { size: { width: 100, height: "50%" } } },
+          { style: { display: Display.Grid } },
+        ],
+      });
+      tree.computeLayout(root, { width: 800, height: 600 });
+      const [sized, grid] = tree.children(root);
+
+      const nested = tree.toNestedObject(root);
+      expect(nested.id).toBe(root);
+      expect(nested.style).toEqual({
+        size: { width: 400, height: 200 },
+        padding: { left: 10, right: 10, top: 5, bottom: 5 },
+      });
+      expect(nested.layout).toEqual({ x: 0, y: 0, width: 400, height: 200 });
+      expect(nested.children.map((child) => child.id)).toEqual([sized, grid]);
+
+      const [first, second] = nested.children;
+      expect(first.style).toEqual({ size: { width: 100, height: "50%" } });
+      expect(first.layout).toEqual({ x: 10, y: 5, width: 100, height: 95 });
+      expect(first.children).toEqual([]);
+      expect(second.style).toEqual({ display: Display.Grid });
+      expect(second.layout.x).toBe(110);
+
+      // The style object can be fed back into buildTree()
+      const copy = tree.buildTree({ style: first.style });
+      expect(tree.getStyle(copy).size).toEqual(first.style.size);
+
+      tree.remove(copy);
+      expect(() => tree.toNestedObject(copy)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports which dimensions are sized by content", () => {
+      const tree = new TaffyTree();
+      const root = tree.buildTree({
+        children: [
+          {},
+          { style: { flexGrow: 1 } },
+          { style: { alignSelf: AlignItems.Start } },
+          {
+            style: {
+              position: Position.Absolute,
+              inset: { left: 0, right: 0, top: 0, bottom: "auto" },
+            },
+          },
+          { style: { size: { width: 100, height: "auto" }, aspectRatio: 2 } },
+          { style: { display: Display.Grid }, children: [{}] },
+        ],
+      });
+      const [plain, grown, aligned, absolute, ratio, grid] =
+        tree.children(root);
+      const [gridItem] = tree.children(grid);
+
+      const both = { width: true, height: true };
+      expect(tree.isContentSized(root)).toEqual(both);
+      expect(tree.isContentSized(plain)).toEqual({
+        width: true,
+        height: false,
+      });
+      expect(tree.isContentSized(grown).width).toBe(false);
+      expect(tree.isContentSized(aligned)).toEqual(both);
+      expect(tree.isContentSized(absolute)).toEqual({
+        width: false,
+        height: true,
+      });
+      const neither = { width: false, height: false };
+      expect(tree.isContentSized(ratio)).toEqual(neither);
+      expect(tree.isContentSized(gridItem)).toEqual(neither);
+
+      tree.remove(gridItem);
+      expect(() => tree.isContentSized(gridItem)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("applies absolute layouts to element styles", () => {
+      const tree = new TaffyTree();
+      const root = tree.buildTree({
+        style: { padding: { left: 10, right: 10, top: 5, bottom: 5 } },
+        children: [
+          {
+            style: { padding: { left: 20, right: 0, top: 0, bottom: 0 } },
+            children: [{ style: { size: { width: 30, height: 40 } } }],
+          },
+        ],
+      });
+      tree.computeLayout(root, { width: 800, height: 600 });
+      const [wrapper] = tree.children(root);
+      const [leaf] = tree.children(wrapper);
+
+      const rootElement = { style: {} as Record<string, string> };
+      const leafElement = { style: {} as Record<string, string> };
+      const elements = new Map([
+        [root, rootElement],
+        [leaf, leafElement],
+      ]);
+      const applied = tree.applyToDom(root, elements as any);
+
+      expect(applied).toBe(2);
+      expect(leafElement.style).toEqual({
+        width: "30px",
+        height: "40px",
+        transform: "translate(30px, 5px)",
+      });
+      expect(rootElement.style.transform).toBe("translate(0px, 0px)");
+
+      tree.remove(leaf);
+      expect(() => tree.applyToDom(leaf, elements as any)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports the scroll extent of scroll containers", () => {
+      const tree = new TaffyTree();
+      const childStyle = new Style();
+      childStyle.flexShrink = 0;
+      childStyle.size = { width: 200, height: 50 };
+      const child = tree.newLeaf(childStyle);
+      const scrollerStyle = new Style();
+      scrollerStyle.size = { width: 100, height: 100 };
+      scrollerStyle.padding = { left: 10, right: 10, top: 10, bottom: 10 };
+      scrollerStyle.border = { left: 3, right: 3, top: 3, bottom: 3 };
+      scrollerStyle.overflow = { x: Overflow.Scroll, y: Overflow.Scroll };
+      scrollerStyle.scrollbarWidth = 5;
+      const scroller = tree.newWithChildren(
+        scrollerStyle,
+        BigUint64Array.from([child]),
+      );
+      tree.computeLayout(scroller, { width: 800, height: 600 });
+
+      // 10 + 200 + 10 wide; the 50px tall content fits the 89px viewport
+      expect(tree.scrollBounds(scroller)).toEqual({ width: 220, height: 89 });
+      expect(tree.scrollBounds(child)).toEqual({ width: 0, height: 0 });
+
+      tree.remove(child);
+      expect(() => tree.scrollBounds(child)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("tracks whether a node has ever been laid out", () => {
+      const tree = new TaffyTree();
+      const child = tree.newLeaf(new Style());
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([child]),
+      );
+      expect(tree.hasLayout(root)).toBe(false);
+      expect(tree.hasLayout(child)).toBe(false);
+
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.hasLayout(root)).toBe(true);
+      expect(tree.hasLayout(child)).toBe(true);
+
+      // New nodes have no layout until the next layout run
+      const late = tree.newLeaf(new Style());
+      tree.addChild(root, late);
+      expect(tree.hasLayout(late)).toBe(false);
+      expect(tree.hasLayout(root)).toBe(true);
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.hasLayout(late)).toBe(true);
+
+      tree.remove(late);
+      expect(() => tree.hasLayout(late)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("returns undefined from tryGetLayout before the first layout", () => {
+      const tree = new TaffyTree();
+      const style = new Style();
+      style.size = { width: 40, height: 30 };
+      const node = tree.newLeaf(style);
+
+      expect(tree.tryGetLayout(node)).toBeUndefined();
+      expect(tree.getLayout(node).width).toBe(0);
+
+      tree.computeLayout(node, { width: 800, height: 600 });
+      expect(tree.tryGetLayout(node)?.width).toBe(40);
+      expect(tree.tryGetLayout(node)?.height).toBe(30);
+
+      tree.remove(node);
+      expect(() => tree.tryGetLayout(node)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reads many layouts at once with getLayoutBatch", () => {
+      const tree = new TaffyTree();
+      const childStyle = new Style();
+      childStyle.size = { width: 40, height: 30 };
+      childStyle.border = { left: 1, right: 2, top: 3, bottom: 4 };
+      childStyle.padding = { left: 5, right: 6, top: 7, bottom: 8 };
+      const a = tree.newLeaf(childStyle);
+      const b = tree.newLeaf(childStyle);
+      const rootStyle = new Style();
+      rootStyle.padding = { left: 10, right: 10, top: 10, bottom: 10 };
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([a, b]));
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      const nodes = BigUint64Array.from([b, root, a]);
+      const batch = tree.getLayoutBatch(nodes);
+      expect(batch).toBeInstanceOf(Float32Array);
+      expect(batch.length).toBe(nodes.length * 12);
+      nodes.forEach((node, i) => {
+        const layout = tree.getLayout(node);
+        expect(Array.from(batch.subarray(i * 12, i * 12 + 12))).toEqual([
+          layout.x,
+          layout.y,
+          layout.width,
+          layout.height,
+          layout.borderLeft,
+          layout.borderRight,
+          layout.borderTop,
+          layout.borderBottom,
+          layout.paddingLeft,
+          layout.paddingRight,
+          layout.paddingTop,
+          layout.paddingBottom,
+        ]);
+      });
+      expect(batch[12]).toBe(0); // root x
+      expect(batch[0]).toBe(50); // b follows a's 40px after the 10px padding
+
+      expect(tree.getLayoutBatch(new BigUint64Array())).toHaveLength(0);
+
+      tree.remove(b);
+      expect(() => tree.getLayoutBatch(nodes)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("derives sizes from the aspect ratio or content", () => {
+      const tree = new TaffyTree();
+      const videoStyle = new Style();
+      videoStyle.aspectRatio = 2;
+      const video = tree.newLeaf(videoStyle);
+      expect(tree.aspectSizedFor(video, 100)).toEqual({
+        width: 100,
+        height: 50,
+      });
+      expect(tree.aspectSizedFor(video, undefined, 30)).toEqual({
+        width: 60,
+        height: 30,
+      });
+      expect(tree.aspectSizedFor(video, 10, 10)).toEqual({
+        width: 10,
+        height: 10,
+      });
+
+      const childStyle = new Style();
+      childStyle.size = { width: 40, height: 30 };
+      const child = tree.newLeaf(childStyle);
+      const box = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([child]),
+      );
+      expect(() => tree.aspectSizedFor(box)).toThrow(TaffyError);
+
+      tree.computeLayout(box, { width: 200, height: 200 });
+      expect(tree.aspectSizedFor(box)).toEqual({ width: 40, height: 30 });
+      expect(tree.aspectSizedFor(box, 80)).toEqual({ width: 80, height: 30 });
+
+      tree.remove(box);
+      expect(() => tree.aspectSizedFor(box, 80)).toThrow(TaffyError);
+
+      tree.free();
+    });
+  });
+
+  describe("Layout Computation", () => {
+    it("re-measures nodes whose context.version changed", () => {
+      const tree = new TaffyTree();
+      const context = { text: "Hello", version: 1 };
+      const other = { text: "World", version: 1 };
+      const a = tree.newLeafWithContext(new Style(), context);
+      const b = tree.newLeafWithContext(new Style(), other);
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([a, b]),
+      );
+
+      const measured: bigint[] = [];
+      const measure = (
+        _known: any,
+        _available: any,
+        node: bigint,
+        ctx: any,
+      ) => {
+        measured.push(node);
+        return { width: ctx.text.length * 10, height: 10 };
+      };
+      const space = { width: 500, height: 500 };
+
+      tree.computeLayoutWithMeasure(root, space, measure);
+      expect(measured).toContain(a);
+      expect(measured).toContain(b);
+
+      // Nothing changed: everything is served from the cache
+      measured.length = 0;
+      tree.computeLayoutWithMeasure(root, space, measure);
+      expect(measured).toEqual([]);
+
+      // Bumping the version re-measures only that node
+      context.version++;
+      tree.computeLayoutWithMeasure(root, space, measure);
+      expect(measured).toContain(a);
+      expect(measured).not.toContain(b);
+
+      tree.free();
+    });
+
+    it("throws when measuring from a removed root", () => {
+      const tree = new TaffyTree();
+      const root = tree.newLeafWithContext(new Style(), { version: 1 });
+      tree.remove(root);
+
+      expect(() =>
+        tree.computeLayoutWithMeasure(
+          root,
+          { width: 100, height: 100 },
+          () => ({ width: 0, height: 0 }),
+        ),
+      ).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports layouts that exceed the slow-layout threshold", () => {
+      const tree = new TaffyTree();
+      const leaf = tree.newLeafWithContext(new Style(), {});
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([leaf]),
+      );
+
+      const reports: any[] = [];
+      tree.setSlowLayoutThreshold(1, (info) => reports.push(info));
+
+      const slowMeasure = () => {
+        const start = performance.now();
+        while (performance.now() - start < 5) {
+          // busy-wait to make the layout slow
+        }
+        return { width: 10, height: 10 };
+      };
+      tree.computeLayoutWithMeasure(
+        root,
+        { width: 100, height: 100 },
+        slowMeasure,
+      );
+
+      expect(reports.length).toBe(1);
+      expect(reports[0].node).toBe(root);
+      expect(reports[0].nodeCount).toBe(2);
+      expect(reports[0].thresholdMs).toBe(1);
+      expect(reports[0].durationMs).toBeGreaterThan(1);
+
+      // Layout still completed
+      expect(tree.getLayout(leaf).width).toBe(10);
+
+      tree.clearSlowLayoutThreshold();
+      tree.markDirty(leaf);
+      tree.computeLayoutWithMeasure(
+        root,
+        { width: 100, height: 100 },
+        slowMeasure,
+      );
+      expect(reports.length).toBe(1);
+
+      tree.free();
+    });
+
+    it("profiles measure time per subtree when enabled", () => {
+      const tree = new TaffyTree();
+      const root = tree.buildTree({
+        children: [
+          { children: [{ context: { slow: true } }] },
+          { context: { slow: false } },
+        ],
+      });
+      const [branch, fast] = tree.children(root);
+      const [slow] = tree.children(branch);
+      const measure = (_k: any, _a: any, _n: bigint, ctx: any) => {
+        const start = performance.now();
+        while (ctx.slow && performance.now() - start < 2) {
+          // busy-wait to make this node's measurement slow
+        }
+        return { width: 10, height: 10 };
+      };
+      const space = { width: 100, height: 100 };
+
+      // Disabled by default
+      tree.computeLayoutWithMeasure(root, space, measure);
+      expect(tree.layoutProfile(root).size).toBe(0);
+
+      tree.enableProfiling();
+      tree.invalidateLayout(root);
+      tree.computeLayoutWithMeasure(root, space, measure);
+      const profile = tree.layoutProfile(root);
+      expect(profile.size).toBe(4);
+      expect(profile.get(slow)).toBeGreaterThan(2);
+      expect(profile.get(branch)).toBeGreaterThanOrEqual(profile.get(slow)!);
+      expect(profile.get(fast)).toBeLessThan(profile.get(slow)!);
+      expect(profile.get(root)).toBeGreaterThanOrEqual(profile.get(branch)!);
+      expect(tree.layoutProfile(branch).size).toBe(2);
+
+      tree.disableProfiling();
+      expect(tree.layoutProfile(root).size).toBe(0);
+
+      tree.remove(fast);
+      expect(() => tree.layoutProfile(fast)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("exports the profiled layout as folded stacks", () => {
+      const tree = new TaffyTree();
+      const root = tree.buildTree({
+        children: [{ children: [{ context: {} }] }],
+      });
+      const [branch] = tree.children(root);
+      const [leaf] = tree.children(branch);
+      const measure = () => {
+        const start = performance.now();
+        while (performance.now() - start < 2) {
+          // busy-wait so the leaf's measurement shows up
+        }
+        return { width: 10, height: 10 };
+      };
+      const space = { width: 100, height: 100 };
+
+      // Empty until a profiled layout has run
+      tree.computeLayoutWithMeasure(root, space, measure);
+      expect(tree.layoutFlamegraph(root)).toBe("");
+
+      tree.enableProfiling();
+      tree.invalidateLayout(root);
+      tree.computeLayoutWithMeasure(root, space, measure);
+      const lines = tree.layoutFlamegraph(root).trim().split("\n");
+      const leafLine = lines.find((line) =>
+        line.startsWith(`${root};${branch};${leaf} `),
+      );
+      expect(leafLine).toBeDefined();
+      expect(Number(leafLine!.split(" ")[1])).toBeGreaterThanOrEqual(2000);
+      for (const line of lines) {
+        expect(line).toMatch(/^\d+(;\d+)* \d+$/);
+      }
+
+      tree.disableProfiling();
+      expect(tree.layoutFlamegraph(root)).toBe("");
+
+      tree.remove(leaf);
+      expect(() => tree.layoutFlamegraph(leaf)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("invalidates the cached layout of a whole subtree", () => {
+      const tree = new TaffyTree();
+      const leaf = tree.newLeafWithContext(new Style(), {});
+      const mid = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([leaf]),
+      );
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([mid]),
+      );
+      let calls = 0;
+      const measure = () => {
+        calls++;
+        return { width: 10, height: 10 };
+      };
+      tree.computeLayoutWithMeasure(root, { width: 100, height: 100 }, measure);
+
+      // markDirty(mid) leaves the leaf's cache in place
+      tree.markDirty(mid);
+      expect(tree.dirty(mid)).toBe(true);
+      expect(tree.dirty(leaf)).toBe(false);
+      tree.computeLayoutWithMeasure(root, { width: 100, height: 100 }, measure);
+
+      // invalidateLayout(mid) clears it, so the leaf is measured again
+      tree.invalidateLayout(mid);
+      expect(tree.dirty(leaf)).toBe(true);
+      expect(tree.dirty(root)).toBe(true);
+      calls = 0;
+      tree.computeLayoutWithMeasure(root, { width: 100, height: 100 }, measure);
+      expect(calls).toBeGreaterThan(0);
+
+      // Inside a batch, the subtree is marked when the batch ends
+      tree.beginBatch();
+      tree.invalidateLayout(mid);
+      expect(tree.dirty(leaf)).toBe(false);
+      tree.endBatch();
+      expect(tree.dirty(leaf)).toBe(true);
+      expect(tree.dirty(root)).toBe(true);
+
+      tree.remove(leaf);
+      expect(() => tree.invalidateLayout(leaf)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("lays out a subtree in isolation without touching its ancestors", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.flexDirection = FlexDirection.Column;
+      rootStyle.size = { width: 800, height: 600 };
+      rootStyle.padding = { left: 20, right: 20, top: 20, bottom: 20 };
+
+      const menuStyle = new Style();
+      menuStyle.display = Display.Flex;
+      menuStyle.padding = { left: 10, right: 10, top: 5, bottom: 5 };
+      const itemStyle = new Style();
+      itemStyle.size = { width: 120, height: 30 };
+
+      const item = tree.newLeaf(itemStyle);
+      const menu = tree.newWithChildren(menuStyle, BigUint64Array.from([item]));
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([menu]));
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.getLayout(menu).width).toBe(760);
+      expect(tree.getLayout(menu).x).toBe(20);
+
+      tree.computeSubtree(menu, {
+        width: "max-content",
+        height: "max-content",
+      });
+      const menuLayout = tree.getLayout(menu);
+      expect(menuLayout.width).toBe(140);
+      expect(menuLayout.height).toBe(40);
+      expect(menuLayout.x).toBe(0);
+      expect(menuLayout.y).toBe(0);
+      expect(tree.getLayout(item).x).toBe(10);
+      expect(tree.getLayout(root).width).toBe(800);
+      expect(tree.dirty(root)).toBe(true);
+
+      // A full layout restores the in-context result
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.getLayout(menu).width).toBe(760);
+      expect(tree.getLayout(menu).x).toBe(20);
+
+      tree.remove(menu);
+      expect(() =>
+        tree.computeSubtree(menu, { width: 800, height: 600 }),
+      ).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("sizes a tree to its content", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.padding = { left: 5, right: 5, top: 5, bottom: 5 };
+      const childStyle = new Style();
+      childStyle.size = { width: 120, height: 40 };
+
+      const a = tree.newLeaf(childStyle);
+      const b = tree.newLeaf(childStyle);
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([a, b]));
+      tree.computeLayoutContent(root);
+
+      expect(tree.getLayout(root).width).toBe(250);
+      expect(tree.getLayout(root).height).toBe(50);
+      expect(tree.getLayout(b).x).toBe(125);
+
+      tree.free();
+    });
+
+    it("visits every node in paint order after computing layout", () => {
+      const tree = new TaffyTree();
+      const boxStyle = new Style();
+      boxStyle.size = { width: 40, height: 40 };
+      const a1 = tree.newLeaf(boxStyle);
+      const a = tree.newWithChildren(new Style(), BigUint64Array.from([a1]));
+      const b = tree.newLeaf(boxStyle);
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.size = { width: 200, height: 100 };
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([a, b]));
+
+      const visited: bigint[] = [];
+      const widths = new Map<bigint, number>();
+      tree.computeLayoutWithVisitor(
+        root,
+        { width: 200, height: 100 },
+        (node, layout) => {
+          visited.push(node);
+          widths.set(node, layout.width);
+        },
+      );
+
+      expect(visited).toEqual([root, a, a1, b]);
+      expect(widths.get(root)).toBe(200);
+      expect(widths.get(b)).toBe(40);
+      expect(tree.getLayout(b).x).toBe(40);
+
+      tree.free();
+    });
+
+    it("stops visiting and rethrows when the visitor throws", () => {
+      const tree = new TaffyTree();
+      const child = tree.newLeaf(new Style());
+      const rootStyle = new Style();
+      rootStyle.size = { width: 100, height: 100 };
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+
+      let calls = 0;
+      expect(() =>
+        tree.computeLayoutWithVisitor(root, { width: 100, height: 100 }, () => {
+          calls++;
+          throw new Error("render failed");
+        }),
+      ).toThrow("render failed");
+      expect(calls).toBe(1);
+      expect(tree.getLayout(root).width).toBe(100);
+
+      tree.free();
+    });
+
+    it("counts measure function calls per node for the last layout", () => {
+      const tree = new TaffyTree();
+      const a = tree.newLeafWithContext(new Style(), { text: "a" });
+      const b = tree.newLeafWithContext(new Style(), { text: "b" });
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([a, b]),
+      );
+
+      const calls = new Map<bigint, number>();
+      const measure = (_known: any, _available: any, node: bigint) => {
+        calls.set(node, (calls.get(node) ?? 0) + 1);
+        return { width: 10, height: 10 };
+      };
+      tree.computeLayoutWithMeasure(root, { width: 100, height: 100 }, measure);
+
+      expect(tree.measureCallCount(a)).toBe(calls.get(a));
+      expect(tree.measureCallCount(a)).toBeGreaterThan(0);
+      expect(tree.measureCallCount(b)).toBe(calls.get(b));
+      expect(tree.measureCallCount(root)).toBe(0);
+
+      // Counts are reset by each layout
+      tree.computeLayout(root, { width: 100, height: 100 });
+      expect(tree.measureCallCount(a)).toBe(0);
+
+      tree.remove(a);
+      expect(() => tree.measureCallCount(a)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("records baselines returned by measure functions", () => {
+      const tree = new TaffyTree();
+      const text = tree.newLeafWithContext(new Style(), { ascent: 12 });
+      const box = tree.newLeafWithContext(new Style(), {});
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([text, box]),
+      );
+
+      tree.computeLayoutWithMeasure(
+        root,
+        { width: 100, height: 100 },
+        (_known: any, _available: any, _node: bigint, ctx: any) => ({
+          width: 40,
+          height: 16,
+          baseline: ctx.ascent,
+        }),
+      );
+      expect(tree.measuredBaseline(text)).toBe(12);
+      expect(tree.measuredBaseline(box)).toBeUndefined();
+      expect(tree.measuredBaseline(root)).toBeUndefined();
+      expect(tree.getLayout(text).height).toBe(16);
+
+      // Baselines are reset by each layout
+      tree.computeLayout(root, { width: 100, height: 100 });
+      expect(tree.measuredBaseline(text)).toBeUndefined();
+
+      tree.remove(text);
+      expect(() => tree.measuredBaseline(text)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("lays out at several available sizes in one call", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.flexWrap = FlexWrap.Wrap;
+      rootStyle.size = { width: "100%", height: "auto" };
+      const itemStyle = new Style();
+      itemStyle.size = { width: 200, height: 50 };
+      const items = [0, 1, 2].map(() => tree.newLeaf(itemStyle));
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from(items));
+
+      const layouts = tree.computeLayoutMulti(root, [
+        { width: 250, height: "max-content" },
+        { width: 450, height: "max-content" },
+        { width: 700, height: "max-content" },
+      ]);
+
+      expect(layouts.map((l) => l.width)).toEqual([250, 450, 700]);
+      expect(layouts.map((l) => l.height)).toEqual([150, 100, 50]);
+      // Only the last run stays committed
+      expect(tree.getLayout(root).height).toBe(50);
+
+      expect(tree.computeLayoutMulti(root, [])).toEqual([]);
+      expect(tree.getLayout(root).height).toBe(50);
+
+      tree.remove(root);
+      expect(() => tree.computeLayoutMulti(root, [])).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("reports whether the last layout changed the root size", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.size = { width: "100%", height: "auto" };
+      const childStyle = new Style();
+      childStyle.size = { width: 50, height: 50 };
+      const child = tree.newLeaf(childStyle);
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+      expect(tree.rootSizeChanged()).toBe(false);
+
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.rootSizeChanged()).toBe(true);
+
+      tree.computeLayout(root, { width: 800, height: 300 });
+      expect(tree.rootSizeChanged()).toBe(false);
+
+      tree.computeLayout(root, { width: 400, height: 300 });
+      expect(tree.rootSizeChanged()).toBe(true);
+
+      // Laying out a nested node is not a root layout
+      tree.computeSubtree(child, { width: 100, height: 100 });
+      expect(tree.rootSizeChanged()).toBe(true);
+      tree.computeLayout(root, { width: 400, height: 300 });
+      expect(tree.rootSizeChanged()).toBe(false);
+
+      tree.free();
+    });
+
+    it("reads the computed root size", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.size = { width: "100%", height: "auto" };
+      const childStyle = new Style();
+      childStyle.size = { width: 50, height: 50 };
+      const child = tree.newLeaf(childStyle);
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+      expect(tree.rootSize(root)).toEqual({ width: 0, height: 0 });
+
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.rootSize(root)).toEqual({ width: 800, height: 50 });
+      const layout = tree.getLayout(root);
+      expect(tree.rootSize(root)).toEqual({
+        width: layout.width,
+        height: layout.height,
+      });
+
+      tree.remove(root);
+      expect(() => tree.rootSize(root)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("measures min-content and max-content sizes without committing", () => {
+      const tree = new TaffyTree();
+      const rootStyle = new Style();
+      rootStyle.flexWrap = FlexWrap.Wrap;
+      rootStyle.size = { width: 150, height: "auto" };
+      const childStyle = new Style();
+      childStyle.size = { width: 100, height: 20 };
+      const a = tree.newLeaf(childStyle);
+      const b = tree.newLeaf(childStyle);
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([a, b]));
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.getLayout(root).height).toBe(40);
+
+      // The explicit width still applies
+      expect(tree.minContentSize(root)).toEqual({ width: 150, height: 40 });
+
+      rootStyle.size = { width: "auto", height: "auto" };
+      tree.setStyle(root, rootStyle);
+      expect(tree.minContentSize(root)).toEqual({ width: 100, height: 40 });
+      expect(tree.maxContentSize(root)).toEqual({ width: 200, height: 20 });
+      expect(tree.maxContentSize(a)).toEqual({ width: 100, height: 20 });
+
+      // The last committed layout is untouched
+      expect(tree.getLayout(root).width).toBe(150);
+      expect(tree.getLayout(b).y).toBe(20);
+
+      tree.remove(b);
+      expect(() => tree.minContentSize(b)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("measures nodes with their own context.measure function", () => {
+      const tree = new TaffyTree();
+      const icon = tree.newLeafWithContext(new Style(), {
+        size: 24,
+        measure: (_known: any, _available: any, _node: bigint, ctx: any) => ({
+          width: ctx.size,
+          height: ctx.size,
+        }),
+      });
+      const text = tree.newLeafWithContext(new Style(), { text: "Hello" });
+      const rootStyle = new Style();
+      rootStyle.alignItems = AlignItems.FlexStart;
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([icon, text]),
+      );
+
+      const space = { width: 500, height: 500 };
+      const fallback = (_known: any, _avail: any, _node: any, ctx: any) => ({
+        width: ctx.text.length * 10,
+        height: 10,
+      });
+      tree.computeLayoutWithMeasure(root, space, fallback);
+      expect(tree.getLayout(icon).width).toBe(24);
+      expect(tree.getLayout(icon).height).toBe(24);
+      expect(tree.getLayout(text).width).toBe(50);
+
+      // Replacing the context without a measurer falls back again
+      tree.setNodeContext(icon, { text: "Hi" });
+      tree.computeLayoutWithMeasure(root, space, fallback);
+      expect(tree.getLayout(icon).width).toBe(20);
+
+      tree.setNodeContext(text, {
+        measure: () => ({ width: 7, height: 7 }),
+      });
+      tree.computeLayoutWithMeasure(root, space, fallback);
+      expect(tree.getLayout(text).width).toBe(7);
+
+      tree.free();
+    });
+
+    it("remembers the available space of the last layout", () => {
+      const tree = new TaffyTree();
+      const root = tree.newLeaf(new Style());
+      expect(tree.lastAvailableSpace()).toBeUndefined();
+
+      tree.computeLayout(root, { width: 800, height: 600 });
+      expect(tree.lastAvailableSpace()).toEqual({ width: 800, height: 600 });
+
+      tree.computeLayout(root, { width: "min-content", height: "max-content" });
+      expect(tree.lastAvailableSpace()).toEqual({
+        width: "min-content",
+        height: "max-content",
+      });
+
+      tree.clear();
+      expect(tree.lastAvailableSpace()).toBeUndefined();
+
+      tree.free();
+    });
+  });
+
+  describe("Cloning", () => {
+    it("creates an independent copy with the same node ids", () => {
+      const tree = new TaffyTree();
+      const context = { text: "hello" };
+      const leaf = tree.newLeafWithContext(new Style(), context);
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.size = { width: "100%", height: "auto" };
+      const root = tree.newWithChildren(rootStyle, BigUint64Array.from([leaf]));
+      tree.computeLayout(root, { width: 800, height: 600 });
+
+      const copy = tree.clone();
+      expect(Array.from(copy.children(root))).toEqual([leaf]);
+      expect(copy.parent(leaf)).toBe(root);
+      expect(copy.getNodeContext(leaf)).toBe(context);
+      expect(copy.getLayout(root).width).toBe(800);
+
+      // Changes to the copy don't affect the original
+      copy.computeLayout(root, { width: 400, height: 600 });
+      copy.addChild(root, copy.newLeaf(new Style()));
+      expect(copy.getLayout(root).width).toBe(400);
+      expect(tree.getLayout(root).width).toBe(800);
+      expect(tree.childCount(root)).toBe(1);
+      expect(copy.childCount(root)).toBe(2);
+
+      copy.free();
+      tree.free();
+    });
+
+    it("starts unfrozen", () => {
+      const tree = new TaffyTree();
+      tree.newLeaf(new Style());
+      tree.freeze();
+
+      const copy = tree.clone();
+      expect(copy.isFrozen()).toBe(false);
+      expect(copy.totalNodeCount()).toBe(1);
+
+      copy.free();
+      tree.free();
+    });
+  });
+
+  describe("Style Updates", () => {
+    it("sets a single style property", () => {
+      const tree = new TaffyTree();
+      const childStyle = new Style();
+      childStyle.size = { width: 50, height: 50 };
+      const child = tree.newLeaf(childStyle);
+      const rootStyle = new Style();
+      rootStyle.display = Display.Flex;
+      rootStyle.size = { width: 200, height: 100 };
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child]),
+      );
+      tree.computeLayout(root, { width: 200, height: 100 });
+
+      tree.setStyleProperty(child, "flexGrow", 1);
+      tree.setStyleProperty(child, "margin", {
+        left: 10,
+        right: 0,
+        top: 0,
+        bottom: 0,
+      });
+      tree.setStyleProperty(root, "flexDirection", FlexDirection.Column);
+      expect(tree.getStyle(child).flexGrow).toBe(1);
+      expect(tree.getStyle(child).size).toEqual({ width: 50, height: 50 });
+      expect(tree.getStyle(root).flexDirection).toBe(FlexDirection.Column);
+
+      tree.computeLayout(root, { width: 200, height: 100 });
+      expect(tree.getLayout(child).x).toBe(10);
+      expect(tree.getLayout(child).height).toBe(100);
+
+      tree.free();
+    });
+
+    it("does not mark the node dirty when nothing changes", () => {
+      const tree = new TaffyTree();
+      const style = new Style();
+      style.flexGrow = 2;
+      const node = tree.newLeaf(style);
+      tree.computeLayout(node, { width: 100, height: 100 });
+
+      tree.setStyleProperty(node, "flexGrow", 2);
+      tree.setStyleProperty(node, "display", 42); // invalid, ignored
+      expect(tree.dirty(node)).toBe(false);
+
+      tree.setStyleProperty(node, "flexGrow", 3);
+      expect(tree.dirty(node)).toBe(true);
+
+      tree.free();
+    });
+
+    it("throws for unknown properties", () => {
+      const tree = new TaffyTree();
+      const node = tree.newLeaf(new Style());
+      expect(() => tree.setStyleProperty(node, "colour", 1)).toThrow(
+        TaffyError,
+      );
+      tree.free();
+    });
+
+    it("derives a layout cache key from subtree styles and structure", () => {
+      const tree = new TaffyTree();
+      const childStyle = new Style();
+      childStyle.size = { width: 50, height: 50 };
+      const child = tree.newLeaf(childStyle);
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([child]),
+      );
+
+      const key = tree.layoutCacheKey(root);
+      expect(typeof key).toBe("bigint");
+      expect(tree.layoutCacheKey(root)).toBe(key);
+
+      // Re-setting an equal style keeps the key
+      tree.setStyle(child, childStyle);
+      expect(tree.layoutCacheKey(root)).toBe(key);
+
+      // A descendant's style is part of the key
+      tree.setStyleProperty(child, "flexGrow", 1);
+      const grown = tree.layoutCacheKey(root);
+      expect(grown).not.toBe(key);
+
+      // So is the child list
+      const extra = tree.newLeaf(new Style());
+      tree.addChild(root, extra);
+      expect(tree.layoutCacheKey(root)).not.toBe(grown);
+      tree.removeChild(root, extra);
+      expect(tree.layoutCacheKey(root)).toBe(grown);
+
+      // -0 and 0 are the same input
+      tree.setStyleProperty(child, "flexGrow", 0);
+      const zero = tree.layoutCacheKey(root);
+      tree.setStyleProperty(child, "flexGrow", -0);
+      expect(tree.layoutCacheKey(root)).toBe(zero);
+
+      tree.remove(child);
+      tree.remove(root);
+      expect(() => tree.layoutCacheKey(root)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("lists absolutely positioned children in source order", () => {
+      const tree = new TaffyTree();
+      const absolute = { style: { position: Position.Absolute } };
+      const root = tree.buildTree({
+        children: [{}, absolute, {}, absolute, { children: [absolute] }],
+      });
+      const children = tree.children(root);
+
+      expect(tree.absoluteSiblings(root)).toEqual(
+        BigUint64Array.from([children[1], children[3]]),
+      );
+      expect(tree.absoluteSiblings(children[0]).length).toBe(0);
+
+      tree.setStyleProperty(children[1], "position", Position.Relative);
+      expect(tree.absoluteSiblings(root)).toEqual(
+        BigUint64Array.from([children[3]]),
+      );
+
+      tree.remove(children[0]);
+      expect(() => tree.absoluteSiblings(children[0])).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("finds the ancestor establishing a stacking context", () => {
+      const tree = new TaffyTree();
+      const root = tree.buildTree({
+        children: [
+          { children: [{}] },
+          {
+            style: { position: Position.Absolute },
+            children: [{ children: [{}] }],
+          },
+        ],
+      });
+      const [flow, overlay] = tree.children(root);
+      const [flowChild] = tree.children(flow);
+      const [overlayChild] = tree.children(overlay);
+      const [nested] = tree.children(overlayChild);
+
+      expect(tree.stackingContext(root)).toBeUndefined();
+      expect(tree.stackingContext(flow)).toBe(root);
+      expect(tree.stackingContext(flowChild)).toBe(root);
+      expect(tree.stackingContext(overlay)).toBe(root);
+      expect(tree.stackingContext(overlayChild)).toBe(overlay);
+      expect(tree.stackingContext(nested)).toBe(overlay);
+
+      tree.remove(nested);
+      expect(() => tree.stackingContext(nested)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("checks whether a node is absolutely positioned", () => {
+      const tree = new TaffyTree();
+      const style = new Style();
+      const node = tree.newLeaf(style);
+      expect(tree.isAbsolute(node)).toBe(false);
+
+      tree.setStyleProperty(node, "position", Position.Absolute);
+      expect(tree.isAbsolute(node)).toBe(true);
+
+      tree.remove(node);
+      expect(() => tree.isAbsolute(node)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("resolves display through hidden ancestors", () => {
+      const tree = new TaffyTree();
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      const leaf = tree.newLeaf(gridStyle);
+      const mid = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([leaf]),
+      );
+      const root = tree.newWithChildren(
+        new Style(),
+        BigUint64Array.from([mid]),
+      );
+      expect(tree.resolvedDisplay(leaf)).toBe(Display.Grid);
+      expect(tree.resolvedDisplay(mid)).toBe(Display.Flex);
+
+      // Hiding an ancestor hides the whole subtree
+      tree.setStyleProperty(root, "display", Display.None);
+      expect(tree.resolvedDisplay(leaf)).toBe(Display.None);
+      expect(tree.resolvedDisplay(mid)).toBe(Display.None);
+      expect(tree.getStyle(leaf).display).toBe(Display.Grid);
+
+      tree.remove(leaf);
+      expect(() => tree.resolvedDisplay(leaf)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("resolves unset justifyContent to the display's default", () => {
+      const tree = new TaffyTree();
+      const flex = tree.newLeaf(new Style());
+      const gridStyle = new Style();
+      gridStyle.display = Display.Grid;
+      const grid = tree.newLeaf(gridStyle);
+      expect(tree.getStyle(flex).justifyContent).toBeUndefined();
+      expect(tree.resolvedJustifyContent(flex)).toBe(JustifyContent.FlexStart);
+      expect(tree.resolvedJustifyContent(grid)).toBe(JustifyContent.Start);
+
+      tree.setStyleProperty(grid, "justifyContent", JustifyContent.Center);
+      expect(tree.resolvedJustifyContent(grid)).toBe(JustifyContent.Center);
+
+      tree.remove(flex);
+      expect(() => tree.resolvedJustifyContent(flex)).toThrow(TaffyError);
+
+      tree.free();
+    });
+
+    it("notifies style changes", () => {
+      const tree = new TaffyTree();
+      const node = tree.newLeaf(new Style());
+      const changed: bigint[] = [];
+      tree.onStyleChange((id) => changed.push(id));
+
+      tree.setStyle(node, new Style());
+      tree.setStyleProperty(node, "flexGrow", 1);
+      tree.setStyleProperty(node, "flexGrow", 1);
+      expect(changed).toEqual([node, node]);
+
+      tree.onStyleChange(undefined);
+      tree.setStyleProperty(node, "flexGrow", 2);
+      expect(changed).toHaveLength(2);
+
+      tree.free();
+    });
+
+    it("applies style changes made in a batch once it ends", () => {
+      const tree = new TaffyTree();
+      const child = tree.newLeaf(new Style());
+      const other = tree.newLeaf(new Style());
+      const rootStyle = new Style();
+      rootStyle.size = { width: 100, height: 100 };
+      const root = tree.newWithChildren(
+        rootStyle,
+        BigUint64Array.from([child, other]),
+      );
+      const space = { width: 100, height: 100 };
+      tree.computeLayout(root, space);
+      const changed: bigint[] = [];
+      tree.onStyleChange((id) => changed.push(id));
+
+      const result = tree.batchUpdate(() => {
+        expect(tree.isBatching()).toBe(true);
+        tree.setStyleProperty(child, "flexGrow", 1);
+        tree.setStyleProperty(child, "flexShrink", 0);
+        // Undone within the batch, so `other` stays clean
+        tree.setStyleProperty(other, "flexGrow", 3);
+        tree.setStyleProperty(other, "flexGrow", 0);
+
+        // Reads of the style see the pending changes; layout does not
+        expect(tree.getStyle(child).flexGrow).toBe(1);
+        expect(tree.dirty(child)).toBe(false);
+        return "done";
+      });
+
+      expect(result).toBe("done");
+      expect(tree.isBatching()).toBe(false);
+      expect(changed).toEqual([child]);
+      expect(tree.getStyle(child).flexShrink).toBe(0);
+      expect(tree.dirty(child)).toBe(true);
+      expect(tree.dirty(root)).toBe(true);
+      expect(tree.dirty(other)).toBe(false);
+
+      tree.computeLayout(root, space);
+      expect(tree.getLayout(child).width).toBe(100);
+
+      tree.free();
+    });
+
+    it("nests batches and ends them when the callback throws", () => {
+      const tree = new TaffyTree();
+      const node = tree.newLeaf(new Style());
+      tree.computeLayout(node, { width: 100, height: 100 });
+
+      tree.beginBatch();
+      tree.batchUpdate(() => tree.setStyleProperty(node, "flexGrow", 1));
+      // The inner batch joined the outer one
+      expect(tree.isBatching()).toBe(true);
+      expect(tree.dirty(node)).toBe(false);
+      tree.endBatch();
+      expect(tree.isBatching()).toBe(false);
+      expect(tree.getStyle(node).flexGrow).toBe(1);
+
+      expect(() =>
+        tree.batchUpdate(() => {
+          tree.setStyleProperty(node, "flexGrow", 2);
+          throw new Error("aborted");
+        }),
+      ).toThrow("aborted");
+      expect(tree.isBatching()).toBe(false);
+      expect(tree.getStyle(node).flexGrow).toBe(2);
+
+      // Ending without an open batch does nothing
+      tree.endBatch();
+      expect(tree.isBatching()).toBe(false);
+
+      // Removed nodes are rejected inside a batch too
+      tree.beginBatch();
+      tree.remove(node);
+      expect(() => tree.setStyle(node, new Style())).toThrow(TaffyError);
+      expect(() => tree.setStyleProperty(node, "flexGrow", 3)).toThrow(
+        TaffyError,
+      );
+      expect(() => tree.markDirty(node)).toThrow(TaffyError);
+      tree.endBatch();
+
+      tree.free();
+    });
+
+    it("refuses to freeze while a batch is open", () => {
+      const tree = new TaffyTree();
+      const node = tree.newLeaf(new Style());
+      const space = { width: 100, height: 100 };
+      tree.computeLayout(node, space);
+
+      tree.beginBatch();
+      tree.setStyleProperty(node, "flexGrow", 1);
+      expect(() => tree.freeze()).toThrow(TaffyError);
+      expect(() => tree.computeAndFreeze(node, space)).toThrow(TaffyError);
+      expect(tree.isFrozen()).toBe(false);
+      tree.endBatch();
+      expect(tree.isBatching()).toBe(false);
+      expect(tree.dirty(node)).toBe(true);
+      expect(tree.getStyle(node).flexGrow).toBe(1);
+
+      // Freezing inside batchUpdate() throws, and the batch is still closed
+      expect(() =>
+        tree.batchUpdate(() => {
+          tree.setStyleProperty(node, "flexGrow", 2);
+          tree.computeAndFreeze(node, space);
+        }),
+      ).toThrow(TaffyError);
+      expect(tree.isBatching()).toBe(false);
+      expect(tree.isFrozen()).toBe(false);
+      expect(tree.getStyle(node).flexGrow).toBe(2);
+
+      tree.free();
+    });
+
+    it("discards an open batch when the tree is cleared", () => {
+      const tree = new TaffyTree();
+      const node = tree.newLeaf(new Style());
+      tree.beginBatch();
+      tree.setStyleProperty(node, "flexGrow", 1);
+      tree.clear();
+      expect(tree.isBatching()).toBe(false);
+
+      // Later changes apply immediately
+      const next = tree.newLeaf(new Style());
+      tree.computeLayout(next, { width: 100, height: 100 });
+      tree.setStyleProperty(next, "flexGrow", 1);
+      expect(tree.dirty(next)).toBe(true);
+
+      tree.free();
+    });
+
+    it("hashes node styles", () => {
+      const tree = new TaffyTree();
+      const style = new Style();
+      style.size = { width: 50, height: 50 };
+      const a = tree.newLeaf(style);
+      const b = tree.newLeaf(style);
+
+      const hash = tree.styleHash(a);
+      expect(typeof hash).toBe("bigint");
+      expect(tree.styleHash(b)).toBe(hash);
+
+      tree.setStyleProperty(b, "flexGrow", 1);
+      expect(tree.styleHash(b)).not.toBe(hash);
+      tree.setStyleProperty(b, "flexGrow", 0);
+      expect(tree.styleHash(b)).toBe(hash);
+      tree.setStyleProperty(b, "flexGrow", -0);
+      expect(tree.styleHash(b)).toBe(hash);
+
+      tree.remove(a);
+      expect(() => tree.styleHash(a)).toThrow(TaffyError);
+
+      tree.free();
+    });
+  });
+});